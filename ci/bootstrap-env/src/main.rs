@@ -9,6 +9,7 @@ use dao_voting::{
     pre_propose::PreProposeInfo,
     threshold::PercentageThreshold,
     threshold::Threshold,
+    voting::VoteWeighting,
 };
 use serde::{Deserialize, Serialize};
 use std::env;
@@ -110,6 +111,9 @@ fn main() -> Result<()> {
                 },
                 close_proposal_on_execution_failure: false,
                 veto: None,
+                vote_weighting: VoteWeighting::Linear {},
+                objection_threshold: None,
+                review_period: None,
             })?,
             admin: Some(Admin::CoreModule {}),
             funds: vec![],
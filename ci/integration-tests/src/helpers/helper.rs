@@ -11,7 +11,7 @@ use dao_voting::{
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
     threshold::PercentageThreshold,
     threshold::Threshold,
-    voting::Vote,
+    voting::{Vote, VoteWeighting},
 };
 
 pub const DEPOSIT_AMOUNT: Uint128 = Uint128::new(1_000_000);
@@ -94,6 +94,9 @@ pub fn create_dao(
                     },
                 },
                 veto: None,
+                vote_weighting: VoteWeighting::Linear {},
+                objection_threshold: None,
+                review_period: None,
             })?,
             admin: Some(Admin::CoreModule {}),
             funds: vec![],
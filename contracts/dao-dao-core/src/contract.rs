@@ -1,30 +1,40 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    from_json, to_json_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo,
-    Order, Reply, Response, StdError, StdResult, SubMsg, WasmMsg,
+    from_json, to_json_binary, Addr, BankMsg, Binary, BlockInfo, Coin, CosmosMsg, Deps, DepsMut,
+    Empty, Env, IbcMsg, MessageInfo, Order, Reply, Response, StdError, StdResult, Storage, SubMsg,
+    Uint128, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
+use cw_denom::{validate_native_denom, CheckedDenom, UncheckedDenom};
 use cw_paginate_storage::{paginate_map, paginate_map_keys, paginate_map_values};
-use cw_storage_plus::Map;
-use cw_utils::{parse_reply_instantiate_data, Duration};
+use cw_storage_plus::{Bound, Map};
+use cw_utils::{parse_reply_instantiate_data, Duration, Expiration};
+use dao_hooks::dao_core::{core_changed_hook_msgs, CoreChangedHookMsg};
 use dao_interface::{
     msg::{ExecuteMsg, InitialItem, InstantiateMsg, MigrateMsg, QueryMsg},
     query::{
         AdminNominationResponse, Cw20BalanceResponse, DaoURIResponse, DumpStateResponse,
-        GetItemResponse, PauseInfoResponse, ProposalModuleCountResponse, SubDao,
+        GetItemResponse, PauseInfoResponse, PauseResponse, ProposalModuleCountResponse,
+        SpendLimitResponse, SubDao, SubDaoTreeNode, TreasurySnapshotResponse,
     },
     state::{
-        Admin, Config, ModuleInstantiateCallback, ModuleInstantiateInfo, ProposalModule,
-        ProposalModuleStatus,
+        Admin, Config, ExecutionPolicy, ItemNamespace, ItemValueType, ModuleInstantiateCallback,
+        ModuleInstantiateInfo, MsgType, PauseScope, ProposalModule, ProposalModuleStatus, Schedule,
+        SpendLimit,
     },
     voting,
 };
 
 use crate::error::ContractError;
 use crate::state::{
-    ACTIVE_PROPOSAL_MODULE_COUNT, ADMIN, CONFIG, CW20_LIST, CW721_LIST, ITEMS, NOMINATED_ADMIN,
-    PAUSED, PROPOSAL_MODULES, SUBDAO_LIST, TOTAL_PROPOSAL_MODULE_COUNT, VOTING_MODULE,
+    SpendLimitState, ACTIVE_PROPOSAL_MODULE_COUNT, ADMIN, CONFIG, CORE_HOOKS,
+    CW20_BALANCE_SNAPSHOTS, CW20_LIST, CW20_SPEND_LIMITS, CW721_LIST, ITEMS, ITEM_NAMESPACES,
+    NAMESPACED_ITEMS, NATIVE_BALANCE_SNAPSHOTS, NATIVE_LIST, NATIVE_SPEND_LIMITS, NOMINATED_ADMIN,
+    PAUSED, PAUSED_PROPOSAL_MODULES, PAUSER, PAUSER_COOLDOWN, PAUSER_MAX_PAUSE_DURATION,
+    PAUSER_PAUSES, PENDING_EXECUTION_POLICY, PROPOSAL_MODULES, SCHEDULES, SCHEDULE_COUNT,
+    SNAPSHOTTED_CW20S, SNAPSHOTTED_NATIVES, SUBDAO_LIST, TOTAL_PROPOSAL_MODULE_COUNT,
+    TREASURY_PAUSED, VOTING_MODULE, VOTING_MODULE_HISTORY,
 };
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-dao-core";
@@ -33,6 +43,16 @@ pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 const PROPOSAL_MODULE_REPLY_ID: u64 = 0;
 const VOTE_MODULE_INSTANTIATE_REPLY_ID: u64 = 1;
 const VOTE_MODULE_UPDATE_REPLY_ID: u64 = 2;
+const FAILED_CORE_HOOK_REPLY_ID: u64 = 3;
+const FAILED_SCHEDULE_REPLY_ID: u64 = 4;
+
+/// The maximum number of levels of nested SubDAOs walked by the
+/// `ListSubDaoTree` query.
+pub const MAX_SUBDAO_TREE_DEPTH: u32 = 5;
+
+/// The maximum number of `ListSubDaos` pages loaded from each SubDAO
+/// by the `ListSubDaoTree` query.
+pub const MAX_SUBDAO_PAGES: u32 = 10;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -101,30 +121,64 @@ pub fn instantiate(
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    // No actions can be performed while the DAO is paused.
-    if let Some(expiration) = PAUSED.may_load(deps.storage)? {
-        if !expiration.is_expired(&env.block) {
-            return Err(ContractError::Paused {});
-        }
+    // No actions can be performed while the DAO is paused, other
+    // than lifting the pause.
+    if !matches!(msg, ExecuteMsg::Unpause { .. }) && is_paused(PAUSED.may_load(deps.storage)?, &env)
+    {
+        return Err(ContractError::Paused {});
     }
 
+    // Funds sent along with the message are now in the treasury.
+    snapshot_treasury(
+        deps.branch(),
+        &env,
+        info.funds
+            .iter()
+            .map(|coin| CheckedDenom::Native(coin.denom.clone())),
+        &[],
+    )?;
+
     match msg {
-        ExecuteMsg::ExecuteAdminMsgs { msgs } => {
-            execute_admin_msgs(deps.as_ref(), info.sender, msgs)
-        }
+        ExecuteMsg::ExecuteAdminMsgs { msgs } => execute_admin_msgs(deps, env, info.sender, msgs),
         ExecuteMsg::ExecuteProposalHook { msgs } => {
-            execute_proposal_hook(deps.as_ref(), info.sender, msgs)
+            execute_proposal_hook(deps, env, info.sender, msgs)
+        }
+        ExecuteMsg::Pause { duration } => {
+            execute_pause(deps, env, info.sender, PauseScope::All {}, duration)
         }
-        ExecuteMsg::Pause { duration } => execute_pause(deps, env, info.sender, duration),
-        ExecuteMsg::Receive(_) => execute_receive_cw20(deps, info.sender),
-        ExecuteMsg::ReceiveNft(_) => execute_receive_cw721(deps, info.sender),
+        ExecuteMsg::PauseScoped { scope, duration } => {
+            execute_pause(deps, env, info.sender, scope, duration)
+        }
+        ExecuteMsg::Unpause { scope } => execute_unpause(deps, env, info.sender, scope),
+        ExecuteMsg::UpdatePauser {
+            pauser,
+            max_pause_duration,
+        } => execute_update_pauser(deps, env, info.sender, pauser, max_pause_duration),
+        ExecuteMsg::Receive(msg) => execute_receive_cw20(deps, env, info.sender, msg),
+        ExecuteMsg::ReceiveNft(msg) => execute_receive_cw721(deps, info.sender, msg),
         ExecuteMsg::RemoveItem { key } => execute_remove_item(deps, env, info.sender, key),
         ExecuteMsg::SetItem { key, value } => execute_set_item(deps, env, info.sender, key, value),
+        ExecuteMsg::UpdateItemNamespace {
+            namespace,
+            writers,
+            value_type,
+        } => execute_update_item_namespace(deps, env, info.sender, namespace, writers, value_type),
+        ExecuteMsg::RemoveItemNamespace { namespace } => {
+            execute_remove_item_namespace(deps, env, info.sender, namespace)
+        }
+        ExecuteMsg::SetNamespacedItem {
+            namespace,
+            key,
+            value,
+        } => execute_set_namespaced_item(deps, env, info.sender, namespace, key, value),
+        ExecuteMsg::RemoveNamespacedItem { namespace, key } => {
+            execute_remove_namespaced_item(deps, env, info.sender, namespace, key)
+        }
         ExecuteMsg::UpdateConfig { config } => {
             execute_update_config(deps, env, info.sender, config)
         }
@@ -134,12 +188,43 @@ pub fn execute(
         ExecuteMsg::UpdateCw721List { to_add, to_remove } => {
             execute_update_cw721_list(deps, env, info.sender, to_add, to_remove)
         }
+        ExecuteMsg::UpdateNativeList { to_add, to_remove } => {
+            execute_update_native_list(deps, env, info.sender, to_add, to_remove)
+        }
+        ExecuteMsg::ScheduleMsgs {
+            msgs,
+            start,
+            interval,
+            count,
+        } => execute_schedule_msgs(deps, env, info.sender, msgs, start, interval, count),
+        ExecuteMsg::CancelSchedule { id } => execute_cancel_schedule(deps, env, info.sender, id),
+        ExecuteMsg::Crank {} => execute_crank(deps, env),
+        ExecuteMsg::ExecuteSchedule { id } => execute_schedule(deps, env, info.sender, id),
+        ExecuteMsg::UpdateSpendLimits { to_set, to_remove } => {
+            execute_update_spend_limits(deps, env, info.sender, to_set, to_remove)
+        }
         ExecuteMsg::UpdateVotingModule { module } => {
-            execute_update_voting_module(env, info.sender, module)
+            execute_update_voting_module(deps.as_ref(), env, info.sender, module)
         }
         ExecuteMsg::UpdateProposalModules { to_add, to_disable } => {
             execute_update_proposal_modules(deps, env, info.sender, to_add, to_disable)
         }
+        ExecuteMsg::AddRestrictedProposalModule {
+            module,
+            execution_policy,
+        } => {
+            execute_add_restricted_proposal_module(deps, env, info.sender, module, execution_policy)
+        }
+        ExecuteMsg::UpdateProposalModuleExecutionPolicy {
+            address,
+            execution_policy,
+        } => execute_update_proposal_module_execution_policy(
+            deps,
+            env,
+            info.sender,
+            address,
+            execution_policy,
+        ),
         ExecuteMsg::NominateAdmin { admin } => {
             execute_nominate_admin(deps, env, info.sender, admin)
         }
@@ -150,32 +235,262 @@ pub fn execute(
         ExecuteMsg::UpdateSubDaos { to_add, to_remove } => {
             execute_update_sub_daos_list(deps, env, info.sender, to_add, to_remove)
         }
+        ExecuteMsg::AddCoreHook { address } => {
+            execute_add_core_hook(deps, env, info.sender, address)
+        }
+        ExecuteMsg::RemoveCoreHook { address } => {
+            execute_remove_core_hook(deps, env, info.sender, address)
+        }
+        ExecuteMsg::ExecuteOnSubDao { subdao, msgs } => {
+            execute_on_sub_dao(deps.as_ref(), env, info.sender, subdao, msgs)
+        }
     }
 }
 
+/// Returns true if a pause which ends at `expiration` is in effect.
+fn is_paused(expiration: Option<Expiration>, env: &Env) -> bool {
+    expiration.is_some_and(|expiration| !expiration.is_expired(&env.block))
+}
+
+fn is_pauser(deps: Deps, sender: &Addr) -> StdResult<bool> {
+    Ok(PAUSER.may_load(deps.storage)?.as_ref() == Some(sender))
+}
+
+/// Loads the address of the proposal module a pause applies to.
+fn paused_proposal_module(deps: Deps, address: &str) -> Result<Addr, ContractError> {
+    let addr = deps.api.addr_validate(address)?;
+    if !PROPOSAL_MODULES.has(deps.storage, addr.clone()) {
+        return Err(ContractError::ProposalModuleDoesNotExist { address: addr });
+    }
+    Ok(addr)
+}
+
+/// Loads the time the current pause of `scope` ends, if it has been
+/// paused.
+fn scope_pause(deps: Deps, scope: &PauseScope) -> Result<Option<Expiration>, ContractError> {
+    Ok(match scope {
+        PauseScope::All {} => PAUSED.may_load(deps.storage)?,
+        PauseScope::ProposalModule { address } => {
+            let addr = paused_proposal_module(deps, address)?;
+            PAUSED_PROPOSAL_MODULES.may_load(deps.storage, addr)?
+        }
+        PauseScope::Treasury {} => TREASURY_PAUSED.may_load(deps.storage)?,
+    })
+}
+
 pub fn execute_pause(
     deps: DepsMut,
     env: Env,
     sender: Addr,
+    scope: PauseScope,
     pause_duration: Duration,
 ) -> Result<Response, ContractError> {
-    // Only the core contract may call this method.
-    if sender != env.contract.address {
-        return Err(ContractError::Unauthorized {});
+    let until = pause_duration.after(&env.block);
+
+    // Only the core contract and the pauser may call this method.
+    if sender == env.contract.address {
+        // A pause imposed by the DAO may not be lifted by the pauser.
+        PAUSER_PAUSES.remove(deps.storage, scope_name(&scope));
+    } else {
+        if !is_pauser(deps.as_ref(), &sender)? {
+            return Err(ContractError::Unauthorized {});
+        }
+        // The core contract may pause indefinitely, the pauser only
+        // for up to its maximum pause duration.
+        let max = PAUSER_MAX_PAUSE_DURATION.load(deps.storage)?;
+        let within_max = match (pause_duration, max) {
+            (Duration::Height(duration), Duration::Height(max))
+            | (Duration::Time(duration), Duration::Time(max)) => duration <= max,
+            _ => false,
+        };
+        if !within_max {
+            return Err(ContractError::PauseTooLong { max });
+        }
+        // The pauser must wait its maximum pause duration between the
+        // end of a pause and the start of the next, so that it can not
+        // keep the DAO paused by pausing repeatedly.
+        if let Some(cooldown) = PAUSER_COOLDOWN.may_load(deps.storage)? {
+            if !cooldown.is_expired(&env.block) {
+                return Err(ContractError::PauserCooldown { until: cooldown });
+            }
+        }
+        // Replacing a pause imposed by the DAO could shorten it.
+        if is_paused(scope_pause(deps.as_ref(), &scope)?, &env) {
+            return Err(ContractError::PausedByDao {});
+        }
+        PAUSER_COOLDOWN.save(deps.storage, &(until + max)?)?;
+        PAUSER_PAUSES.save(deps.storage, scope_name(&scope), &until)?;
     }
 
-    let until = pause_duration.after(&env.block);
+    match &scope {
+        PauseScope::All {} => PAUSED.save(deps.storage, &until)?,
+        PauseScope::ProposalModule { address } => {
+            let addr = paused_proposal_module(deps.as_ref(), address)?;
+            PAUSED_PROPOSAL_MODULES.save(deps.storage, addr, &until)?
+        }
+        PauseScope::Treasury {} => TREASURY_PAUSED.save(deps.storage, &until)?,
+    }
 
-    PAUSED.save(deps.storage, &until)?;
+    let hooks = core_hooks(
+        deps.storage,
+        CoreChangedHookMsg::Paused {
+            scope: scope_name(&scope),
+            expiration: until,
+        },
+    )?;
 
     Ok(Response::new()
         .add_attribute("action", "execute_pause")
         .add_attribute("sender", sender)
-        .add_attribute("until", until.to_string()))
+        .add_attribute("scope", scope_name(&scope))
+        .add_attribute("until", until.to_string())
+        .add_submessages(hooks))
 }
 
-pub fn execute_admin_msgs(
+pub fn execute_unpause(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    scope: PauseScope,
+) -> Result<Response, ContractError> {
+    // The admin may lift a pause as, while the DAO is paused, the DAO
+    // itself is unable to.
+    if sender != env.contract.address && sender != ADMIN.load(deps.storage)? {
+        if !is_pauser(deps.as_ref(), &sender)? {
+            return Err(ContractError::Unauthorized {});
+        }
+        // The pauser may only lift its own pauses.
+        if !PAUSER_PAUSES.has(deps.storage, scope_name(&scope)) {
+            return Err(ContractError::PausedByDao {});
+        }
+    }
+    PAUSER_PAUSES.remove(deps.storage, scope_name(&scope));
+
+    match &scope {
+        PauseScope::All {} => PAUSED.remove(deps.storage),
+        PauseScope::ProposalModule { address } => {
+            let addr = paused_proposal_module(deps.as_ref(), address)?;
+            PAUSED_PROPOSAL_MODULES.remove(deps.storage, addr)
+        }
+        PauseScope::Treasury {} => TREASURY_PAUSED.remove(deps.storage),
+    }
+
+    let hooks = core_hooks(
+        deps.storage,
+        CoreChangedHookMsg::Unpaused {
+            scope: scope_name(&scope),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_unpause")
+        .add_attribute("sender", sender)
+        .add_attribute("scope", scope_name(&scope))
+        .add_submessages(hooks))
+}
+
+/// Prepares hooks notifying the DAO's core hook receivers of `msg`.
+fn core_hooks(storage: &dyn Storage, msg: CoreChangedHookMsg) -> StdResult<Vec<SubMsg>> {
+    core_changed_hook_msgs(CORE_HOOKS, storage, msg, FAILED_CORE_HOOK_REPLY_ID)
+}
+
+pub fn execute_add_core_hook(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    address: String,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let addr = deps.api.addr_validate(&address)?;
+    CORE_HOOKS.add_hook(deps.storage, addr.clone())?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_add_core_hook")
+        .add_attribute("address", addr))
+}
+
+pub fn execute_remove_core_hook(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    address: String,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let addr = deps.api.addr_validate(&address)?;
+    CORE_HOOKS.remove_hook(deps.storage, addr.clone())?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_remove_core_hook")
+        .add_attribute("address", addr))
+}
+
+fn scope_name(scope: &PauseScope) -> String {
+    match scope {
+        PauseScope::All {} => "all".to_string(),
+        PauseScope::ProposalModule { address } => format!("proposal_module:{address}"),
+        PauseScope::Treasury {} => "treasury".to_string(),
+    }
+}
+
+pub fn execute_update_pauser(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    pauser: Option<String>,
+    max_pause_duration: Option<Duration>,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let pauser = pauser.map(|h| deps.api.addr_validate(&h)).transpose()?;
+    match &pauser {
+        Some(pauser) => {
+            let max_pause_duration =
+                max_pause_duration.ok_or(ContractError::MissingMaxPauseDuration {})?;
+            PAUSER.save(deps.storage, pauser)?;
+            PAUSER_MAX_PAUSE_DURATION.save(deps.storage, &max_pause_duration)?;
+        }
+        None => {
+            PAUSER.remove(deps.storage);
+            PAUSER_MAX_PAUSE_DURATION.remove(deps.storage);
+        }
+    }
+    // The cooldown is measured in units of the previous maximum pause
+    // duration, which may have changed.
+    PAUSER_COOLDOWN.remove(deps.storage);
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_update_pauser")
+        .add_attribute(
+            "pauser",
+            pauser
+                .map(|a| a.into_string())
+                .unwrap_or_else(|| "None".to_string()),
+        ))
+}
+
+/// Errors if treasury outflows are paused and there are `outflows`.
+fn check_treasury_pause(
     deps: Deps,
+    env: &Env,
+    outflows: &[(CheckedDenom, Uint128)],
+) -> Result<(), ContractError> {
+    if is_paused(TREASURY_PAUSED.may_load(deps.storage)?, env) && !outflows.is_empty() {
+        return Err(ContractError::TreasuryPaused {});
+    }
+    Ok(())
+}
+
+pub fn execute_admin_msgs(
+    deps: DepsMut,
+    env: Env,
     sender: Addr,
     msgs: Vec<CosmosMsg<Empty>>,
 ) -> Result<Response, ContractError> {
@@ -186,13 +501,18 @@ pub fn execute_admin_msgs(
         return Err(ContractError::Unauthorized {});
     }
 
+    // The admin is subject to the same treasury controls as proposal
+    // modules.
+    account_treasury_outflows(deps, &env, &msgs)?;
+
     Ok(Response::default()
         .add_attribute("action", "execute_admin_msgs")
         .add_messages(msgs))
 }
 
 pub fn execute_proposal_hook(
-    deps: Deps,
+    deps: DepsMut,
+    env: Env,
     sender: Addr,
     msgs: Vec<CosmosMsg<Empty>>,
 ) -> Result<Response, ContractError> {
@@ -205,11 +525,154 @@ pub fn execute_proposal_hook(
         return Err(ContractError::ModuleDisabledCannotExecute { address: sender });
     }
 
+    if is_paused(
+        PAUSED_PROPOSAL_MODULES.may_load(deps.storage, sender.clone())?,
+        &env,
+    ) {
+        return Err(ContractError::ProposalModulePaused { address: sender });
+    }
+
+    if let Some(policy) = &module.execution_policy {
+        check_execution_policy(&env, &module.address, policy, &msgs)?;
+    }
+
+    account_treasury_outflows(deps, &env, &msgs)?;
+
     Ok(Response::default()
         .add_attribute("action", "execute_proposal_hook")
         .add_messages(msgs))
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn execute_schedule_msgs(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    msgs: Vec<CosmosMsg<Empty>>,
+    start: Expiration,
+    interval: Option<Duration>,
+    count: u64,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    if count == 0 {
+        return Err(ContractError::ZeroScheduleCount {});
+    }
+    if matches!(start, Expiration::Never {}) {
+        return Err(ContractError::NeverScheduleStart {});
+    }
+    match interval {
+        // Errors if the start and interval are of different units.
+        Some(interval) => {
+            (start + interval)?;
+        }
+        None if count > 1 => return Err(ContractError::MissingScheduleInterval {}),
+        None => (),
+    }
+
+    let id = SCHEDULE_COUNT.may_load(deps.storage)?.unwrap_or_default() + 1;
+    SCHEDULE_COUNT.save(deps.storage, &id)?;
+    SCHEDULES.save(
+        deps.storage,
+        id,
+        &Schedule {
+            id,
+            msgs,
+            next: start,
+            interval,
+            remaining: count,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_schedule_msgs")
+        .add_attribute("schedule_id", id.to_string()))
+}
+
+pub fn execute_cancel_schedule(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    id: u64,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    if !SCHEDULES.has(deps.storage, id) {
+        return Err(ContractError::ScheduleDoesNotExist { id });
+    }
+    SCHEDULES.remove(deps.storage, id);
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_cancel_schedule")
+        .add_attribute("schedule_id", id.to_string()))
+}
+
+pub fn execute_crank(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let due: Vec<Schedule> = SCHEDULES
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, schedule)| schedule))
+        .collect::<StdResult<Vec<_>>>()?
+        .into_iter()
+        .filter(|schedule| schedule.next.is_expired(&env.block))
+        .collect();
+
+    let mut response = Response::default().add_attribute("action", "execute_crank");
+    for schedule in due {
+        response = response
+            .add_attribute("executed", schedule.id.to_string())
+            .add_submessage(SubMsg::reply_on_error(
+                WasmMsg::Execute {
+                    contract_addr: env.contract.address.to_string(),
+                    msg: to_json_binary(&ExecuteMsg::ExecuteSchedule { id: schedule.id })?,
+                    funds: vec![],
+                },
+                FAILED_SCHEDULE_REPLY_ID,
+            ));
+    }
+
+    Ok(response)
+}
+
+pub fn execute_schedule(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    id: u64,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    let mut schedule = SCHEDULES
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::ScheduleDoesNotExist { id })?;
+    if !schedule.next.is_expired(&env.block) {
+        return Err(ContractError::ScheduleNotDue { id });
+    }
+
+    // The schedule is advanced in the same submessage as its messages
+    // are executed, so an execution which fails is reverted and
+    // remains due.
+    schedule.remaining -= 1;
+    match schedule.interval {
+        Some(interval) if schedule.remaining > 0 => {
+            schedule.next = (schedule.next + interval)?;
+            SCHEDULES.save(deps.storage, id, &schedule)?;
+        }
+        _ => SCHEDULES.remove(deps.storage, id),
+    }
+
+    // Scheduled messages are subject to the same treasury controls as
+    // proposal hooks.
+    account_treasury_outflows(deps, &env, &schedule.msgs)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_schedule")
+        .add_attribute("schedule_id", id.to_string())
+        .add_messages(schedule.msgs))
+}
+
 pub fn execute_nominate_admin(
     deps: DepsMut,
     env: Env,
@@ -228,14 +691,26 @@ pub fn execute_nominate_admin(
         return Err(ContractError::PendingNomination {});
     }
 
-    match &nomination {
-        Some(nomination) => NOMINATED_ADMIN.save(deps.storage, nomination)?,
+    let hooks = match &nomination {
+        Some(nomination) => {
+            NOMINATED_ADMIN.save(deps.storage, nomination)?;
+            vec![]
+        }
         // If no admin set to default of the contract. This allows the
         // contract to later set a new admin via governance.
-        None => ADMIN.save(deps.storage, &env.contract.address)?,
-    }
+        None => {
+            ADMIN.save(deps.storage, &env.contract.address)?;
+            core_hooks(
+                deps.storage,
+                CoreChangedHookMsg::AdminChanged {
+                    admin: env.contract.address,
+                },
+            )?
+        }
+    };
 
     Ok(Response::default()
+        .add_submessages(hooks)
         .add_attribute("action", "execute_nominate_admin")
         .add_attribute(
             "nomination",
@@ -258,9 +733,15 @@ pub fn execute_accept_admin_nomination(
     NOMINATED_ADMIN.remove(deps.storage);
     ADMIN.save(deps.storage, &nomination)?;
 
+    let hooks = core_hooks(
+        deps.storage,
+        CoreChangedHookMsg::AdminChanged { admin: nomination },
+    )?;
+
     Ok(Response::default()
         .add_attribute("action", "execute_accept_admin_nomination")
-        .add_attribute("new_admin", sender))
+        .add_attribute("new_admin", sender)
+        .add_submessages(hooks))
 }
 
 pub fn execute_withdraw_admin_nomination(
@@ -308,10 +789,15 @@ pub fn execute_update_config(
         .add_attribute(
             "image_url",
             config.image_url.unwrap_or_else(|| "None".to_string()),
-        ))
+        )
+        .add_submessages(core_hooks(
+            deps.storage,
+            CoreChangedHookMsg::ConfigUpdated {},
+        )?))
 }
 
 pub fn execute_update_voting_module(
+    deps: Deps,
     env: Env,
     sender: Addr,
     module: ModuleInstantiateInfo,
@@ -323,9 +809,15 @@ pub fn execute_update_voting_module(
     let wasm = module.into_wasm_msg(env.contract.address);
     let submessage = SubMsg::reply_on_success(wasm, VOTE_MODULE_UPDATE_REPLY_ID);
 
+    // Hooks are sent after the new module has been saved by the
+    // instantiation reply.
     Ok(Response::default()
         .add_attribute("action", "execute_update_voting_module")
-        .add_submessage(submessage))
+        .add_submessage(submessage)
+        .add_submessages(core_hooks(
+            deps.storage,
+            CoreChangedHookMsg::VotingModuleUpdated {},
+        )?))
 }
 
 pub fn execute_update_proposal_modules(
@@ -370,13 +862,493 @@ pub fn execute_update_proposal_modules(
 
     let to_add: Vec<SubMsg<Empty>> = to_add
         .into_iter()
-        .map(|info| info.into_wasm_msg(env.contract.address.clone()))
-        .map(|wasm| SubMsg::reply_on_success(wasm, PROPOSAL_MODULE_REPLY_ID))
-        .collect();
+        .map(|info| info.into_wasm_msg(env.contract.address.clone()))
+        .map(|wasm| SubMsg::reply_on_success(wasm, PROPOSAL_MODULE_REPLY_ID))
+        .collect();
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_update_proposal_modules")
+        .add_submessages(to_add)
+        .add_submessages(core_hooks(
+            deps.storage,
+            CoreChangedHookMsg::ProposalModulesUpdated {},
+        )?))
+}
+
+pub fn execute_add_restricted_proposal_module(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    module: ModuleInstantiateInfo,
+    execution_policy: ExecutionPolicy,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    validate_execution_policy(deps.as_ref(), &execution_policy)?;
+    // Installed with the policy by the instantiation reply.
+    PENDING_EXECUTION_POLICY.save(deps.storage, &execution_policy)?;
+
+    let wasm = module.into_wasm_msg(env.contract.address);
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_add_restricted_proposal_module")
+        .add_submessage(SubMsg::reply_on_success(wasm, PROPOSAL_MODULE_REPLY_ID))
+        .add_submessages(core_hooks(
+            deps.storage,
+            CoreChangedHookMsg::ProposalModulesUpdated {},
+        )?))
+}
+
+pub fn execute_update_proposal_module_execution_policy(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    address: String,
+    execution_policy: Option<ExecutionPolicy>,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let addr = deps.api.addr_validate(&address)?;
+    let mut module = PROPOSAL_MODULES
+        .load(deps.storage, addr.clone())
+        .map_err(|_| ContractError::ProposalModuleDoesNotExist {
+            address: addr.clone(),
+        })?;
+
+    if let Some(policy) = &execution_policy {
+        validate_execution_policy(deps.as_ref(), policy)?;
+    }
+    module.execution_policy = execution_policy;
+    PROPOSAL_MODULES.save(deps.storage, addr.clone(), &module)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_update_proposal_module_execution_policy")
+        .add_attribute("address", addr))
+}
+
+fn validate_execution_policy(deps: Deps, policy: &ExecutionPolicy) -> StdResult<()> {
+    for contract in policy.allowed_contracts.iter().flatten() {
+        deps.api.addr_validate(contract)?;
+    }
+    Ok(())
+}
+
+/// Checks that `msgs` may be executed by the proposal module at
+/// `address` under `policy`.
+fn check_execution_policy(
+    env: &Env,
+    address: &Addr,
+    policy: &ExecutionPolicy,
+    msgs: &[CosmosMsg<Empty>],
+) -> Result<(), ContractError> {
+    let mut spent: Vec<Coin> = vec![];
+
+    for msg in msgs {
+        // A restricted module may not message the core module, unless
+        // explicitly allowed to, as it could otherwise remove its own
+        // policy.
+        if let CosmosMsg::Wasm(
+            WasmMsg::Execute { contract_addr, .. }
+            | WasmMsg::Migrate { contract_addr, .. }
+            | WasmMsg::UpdateAdmin { contract_addr, .. }
+            | WasmMsg::ClearAdmin { contract_addr },
+        ) = msg
+        {
+            let allowed = policy
+                .allowed_contracts
+                .as_ref()
+                .is_some_and(|allowed| allowed.contains(contract_addr));
+            if *contract_addr == env.contract.address && !allowed {
+                return Err(ContractError::ContractNotAllowed {
+                    address: address.clone(),
+                    contract: contract_addr.clone(),
+                });
+            }
+        }
+
+        let msg_type = MsgType::of(msg);
+        let explicitly_allowed = |msg_type: &MsgType| {
+            policy
+                .allowed_msg_types
+                .as_ref()
+                .is_some_and(|allowed| allowed.contains(msg_type))
+        };
+        if policy.allowed_msg_types.is_some() && !msg_type.as_ref().is_some_and(explicitly_allowed)
+        {
+            return Err(ContractError::MsgTypeNotAllowed {
+                address: address.clone(),
+            });
+        }
+
+        // Stargate and custom messages may execute any contract,
+        // including the core module, without it being possible to
+        // check which. As every restricted module is at least denied
+        // the core module, they must be explicitly allowed.
+        if !msg_type
+            .as_ref()
+            .is_some_and(|msg_type| !msg_type.is_opaque() || explicitly_allowed(msg_type))
+        {
+            return Err(ContractError::OpaqueMsgNotAllowed {
+                address: address.clone(),
+            });
+        }
+
+        if let Some(allowed) = &policy.allowed_contracts {
+            if let CosmosMsg::Wasm(
+                WasmMsg::Execute { contract_addr, .. }
+                | WasmMsg::Migrate { contract_addr, .. }
+                | WasmMsg::UpdateAdmin { contract_addr, .. }
+                | WasmMsg::ClearAdmin { contract_addr },
+            ) = msg
+            {
+                if !allowed.contains(contract_addr) {
+                    return Err(ContractError::ContractNotAllowed {
+                        address: address.clone(),
+                        contract: contract_addr.clone(),
+                    });
+                }
+            }
+        }
+
+        if policy.spend_limits.is_some() {
+            // cw20 tokens are limited under the address of their
+            // contract.
+            if let CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) = msg
+            {
+                if let Ok(
+                    cw20::Cw20ExecuteMsg::Transfer { amount, .. }
+                    | cw20::Cw20ExecuteMsg::Send { amount, .. }
+                    | cw20::Cw20ExecuteMsg::Burn { amount }
+                    | cw20::Cw20ExecuteMsg::IncreaseAllowance { amount, .. }
+                    | cw20::Cw20ExecuteMsg::TransferFrom { amount, .. }
+                    | cw20::Cw20ExecuteMsg::SendFrom { amount, .. }
+                    | cw20::Cw20ExecuteMsg::BurnFrom { amount, .. },
+                ) = from_json(msg)
+                {
+                    add_spend(&mut spent, Coin::new(amount.u128(), contract_addr))?;
+                }
+            }
+
+            let funds: &[Coin] = match msg {
+                CosmosMsg::Bank(BankMsg::Send { amount, .. } | BankMsg::Burn { amount }) => amount,
+                CosmosMsg::Wasm(
+                    WasmMsg::Execute { funds, .. } | WasmMsg::Instantiate { funds, .. },
+                ) => funds,
+                CosmosMsg::Wasm(
+                    WasmMsg::Migrate { .. }
+                    | WasmMsg::UpdateAdmin { .. }
+                    | WasmMsg::ClearAdmin { .. },
+                ) => &[],
+                CosmosMsg::Ibc(IbcMsg::Transfer { amount, .. }) => std::slice::from_ref(amount),
+                CosmosMsg::Ibc(_) | CosmosMsg::Gov(_) => &[],
+                _ => {
+                    return Err(ContractError::UnmeteredSpend {
+                        address: address.clone(),
+                    })
+                }
+            };
+            for coin in funds {
+                add_spend(&mut spent, coin.clone())?;
+            }
+        }
+    }
+
+    if let Some(limits) = &policy.spend_limits {
+        for coin in spent {
+            let limit = limits
+                .iter()
+                .find(|limit| limit.denom == coin.denom)
+                .map(|limit| limit.amount)
+                .unwrap_or_default();
+            if coin.amount > limit {
+                return Err(ContractError::SpendLimitExceeded {
+                    address: address.clone(),
+                    denom: coin.denom,
+                    limit,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn add_spend(spent: &mut Vec<Coin>, coin: Coin) -> Result<(), ContractError> {
+    match spent.iter_mut().find(|c| c.denom == coin.denom) {
+        Some(total) => {
+            total.amount = total
+                .amount
+                .checked_add(coin.amount)
+                .map_err(|_| ContractError::Overflow {})?
+        }
+        None => spent.push(coin),
+    }
+    Ok(())
+}
+
+pub fn execute_update_spend_limits(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    to_set: Vec<SpendLimit>,
+    to_remove: Vec<UncheckedDenom>,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    for SpendLimit {
+        denom,
+        limit,
+        period,
+    } in to_set
+    {
+        // Changing a limit keeps the amount sent out during the
+        // current period.
+        let update = |state: Option<SpendLimitState>| -> StdResult<_> {
+            Ok(match state {
+                Some(state) => SpendLimitState {
+                    limit,
+                    period,
+                    ..state
+                },
+                None => SpendLimitState {
+                    limit,
+                    period,
+                    spent: Uint128::zero(),
+                    period_expiration: None,
+                },
+            })
+        };
+        match denom.into_checked(deps.as_ref())? {
+            CheckedDenom::Native(denom) => {
+                NATIVE_SPEND_LIMITS.update(deps.storage, denom, update)?
+            }
+            CheckedDenom::Cw20(addr) => CW20_SPEND_LIMITS.update(deps.storage, addr, update)?,
+        };
+    }
+
+    for denom in to_remove {
+        match denom {
+            UncheckedDenom::Native(denom) => NATIVE_SPEND_LIMITS.remove(deps.storage, denom),
+            UncheckedDenom::Cw20(addr) => {
+                let addr = deps.api.addr_validate(&addr)?;
+                CW20_SPEND_LIMITS.remove(deps.storage, addr)
+            }
+        }
+    }
+
+    Ok(Response::default().add_attribute("action", "execute_update_spend_limits"))
+}
+
+/// Returns the tokens sent out of the treasury by `msgs`: bank sends
+/// and burns, funds attached to wasm messages, IBC transfers and cw20
+/// `Transfer`, `Send` and `Burn` messages. Also returns whether any of
+/// `msgs` may send tokens in a way that can not be measured, such as
+/// stargate messages.
+fn treasury_outflows(msgs: &[CosmosMsg<Empty>]) -> (Vec<(CheckedDenom, Uint128)>, bool) {
+    let native = |coins: &[Coin]| {
+        coins
+            .iter()
+            .map(|coin| (CheckedDenom::Native(coin.denom.clone()), coin.amount))
+            .collect::<Vec<_>>()
+    };
+
+    let mut outflows = vec![];
+    let mut unmetered = false;
+    for msg in msgs {
+        match msg {
+            CosmosMsg::Bank(BankMsg::Send { amount, .. } | BankMsg::Burn { amount }) => {
+                outflows.extend(native(amount))
+            }
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr,
+                msg,
+                funds,
+            }) => {
+                outflows.extend(native(funds));
+                if let Ok(
+                    cw20::Cw20ExecuteMsg::Transfer { amount, .. }
+                    | cw20::Cw20ExecuteMsg::Send { amount, .. }
+                    | cw20::Cw20ExecuteMsg::Burn { amount },
+                ) = from_json(msg)
+                {
+                    outflows.push((CheckedDenom::Cw20(Addr::unchecked(contract_addr)), amount));
+                }
+            }
+            CosmosMsg::Wasm(WasmMsg::Instantiate { funds, .. }) => outflows.extend(native(funds)),
+            CosmosMsg::Wasm(_) | CosmosMsg::Gov(_) => (),
+            CosmosMsg::Ibc(IbcMsg::Transfer { amount, .. }) => {
+                outflows.extend(native(std::slice::from_ref(amount)))
+            }
+            CosmosMsg::Ibc(_) => (),
+            _ => unmetered = true,
+        }
+    }
+    (outflows, unmetered)
+}
+
+/// Returns the cw20 allowances granted by `msgs`. These are not
+/// outflows, as the tokens remain in the treasury, but count against
+/// spend limits as the spender may transfer them at any time.
+fn cw20_allowances(msgs: &[CosmosMsg<Empty>]) -> Vec<(CheckedDenom, Uint128)> {
+    msgs.iter()
+        .filter_map(|msg| match msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) => match from_json(msg) {
+                Ok(cw20::Cw20ExecuteMsg::IncreaseAllowance { amount, .. }) => {
+                    Some((CheckedDenom::Cw20(Addr::unchecked(contract_addr)), amount))
+                }
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// Applies the treasury's pause and spend limits to `msgs`, which are
+/// about to be sent by the DAO, and snapshots the treasury's balances.
+fn account_treasury_outflows(
+    deps: DepsMut,
+    env: &Env,
+    msgs: &[CosmosMsg<Empty>],
+) -> Result<(), ContractError> {
+    let (outflows, unmetered) = treasury_outflows(msgs);
+    if unmetered
+        && !(NATIVE_SPEND_LIMITS.is_empty(deps.storage) && CW20_SPEND_LIMITS.is_empty(deps.storage))
+    {
+        return Err(ContractError::UnmeteredTreasurySpend {});
+    }
+    let spends: Vec<_> = outflows
+        .iter()
+        .cloned()
+        .chain(cw20_allowances(msgs))
+        .collect();
+    check_treasury_pause(deps.as_ref(), env, &spends)?;
+    record_treasury_outflows(deps.storage, &env.block, &spends)?;
+    snapshot_treasury(deps, env, vec![], &outflows)
+}
+
+/// Adds `outflows` to the amounts spent during the current periods of
+/// their spend limits, erroring if a limit is exceeded.
+fn record_treasury_outflows(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    outflows: &[(CheckedDenom, Uint128)],
+) -> Result<(), ContractError> {
+    for (denom, amount) in outflows {
+        let amount = *amount;
+        match denom {
+            CheckedDenom::Native(native) => {
+                if let Some(state) = NATIVE_SPEND_LIMITS.may_load(storage, native.clone())? {
+                    let state = spend(block, denom, state, amount)?;
+                    NATIVE_SPEND_LIMITS.save(storage, native.clone(), &state)?;
+                }
+            }
+            CheckedDenom::Cw20(addr) => {
+                if let Some(state) = CW20_SPEND_LIMITS.may_load(storage, addr.clone())? {
+                    let state = spend(block, denom, state, amount)?;
+                    CW20_SPEND_LIMITS.save(storage, addr.clone(), &state)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Saves the treasury's balances of the registered tokens in `denoms`
+/// and `outflows` to their snapshots, less the `outflows` which are yet
+/// to be sent.
+fn snapshot_treasury(
+    deps: DepsMut,
+    env: &Env,
+    denoms: impl IntoIterator<Item = CheckedDenom>,
+    outflows: &[(CheckedDenom, Uint128)],
+) -> Result<(), ContractError> {
+    let mut touched: Vec<CheckedDenom> = vec![];
+    for denom in denoms
+        .into_iter()
+        .chain(outflows.iter().map(|(denom, _)| denom.clone()))
+    {
+        if !touched.contains(&denom) {
+            touched.push(denom);
+        }
+    }
 
-    Ok(Response::default()
-        .add_attribute("action", "execute_update_proposal_modules")
-        .add_submessages(to_add))
+    for denom in touched {
+        let registered = match &denom {
+            CheckedDenom::Native(native) => NATIVE_LIST.has(deps.storage, native.clone()),
+            CheckedDenom::Cw20(addr) => CW20_LIST.has(deps.storage, addr.clone()),
+        };
+        if !registered {
+            continue;
+        }
+        let outflow: Uint128 = outflows
+            .iter()
+            .filter(|(outflow, _)| outflow == &denom)
+            .map(|(_, amount)| amount)
+            .sum();
+        let balance = denom
+            .query_balance(&deps.querier, &env.contract.address)?
+            .saturating_sub(outflow);
+        match denom {
+            CheckedDenom::Native(native) => {
+                NATIVE_BALANCE_SNAPSHOTS.save(
+                    deps.storage,
+                    native.clone(),
+                    &balance,
+                    env.block.height,
+                )?;
+                SNAPSHOTTED_NATIVES.save(deps.storage, native, &Empty {})?;
+            }
+            CheckedDenom::Cw20(addr) => {
+                CW20_BALANCE_SNAPSHOTS.save(
+                    deps.storage,
+                    addr.clone(),
+                    &balance,
+                    env.block.height,
+                )?;
+                SNAPSHOTTED_CW20S.save(deps.storage, addr, &Empty {})?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Spends `amount` of a spend limit, starting a new period if there
+/// is none in progress. Periods are fixed windows, so the amount
+/// spent is reset in full when a period ends.
+fn spend(
+    block: &BlockInfo,
+    denom: &CheckedDenom,
+    mut state: SpendLimitState,
+    amount: Uint128,
+) -> Result<SpendLimitState, ContractError> {
+    if state
+        .period_expiration
+        .is_none_or(|expiration| expiration.is_expired(block))
+    {
+        state.spent = Uint128::zero();
+        state.period_expiration = Some(state.period.after(block));
+    }
+    state.spent = state
+        .spent
+        .checked_add(amount)
+        .map_err(|_| ContractError::Overflow {})?;
+    if state.spent > state.limit {
+        return Err(ContractError::TreasurySpendLimitExceeded {
+            denom: denom.to_string(),
+            limit: state.limit,
+        });
+    }
+    Ok(state)
 }
 
 /// Updates a set of addresses in state applying VERIFY to each item
@@ -410,7 +1382,7 @@ fn do_update_addr_list(
 }
 
 pub fn execute_update_cw20_list(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     sender: Addr,
     to_add: Vec<String>,
@@ -419,7 +1391,15 @@ pub fn execute_update_cw20_list(
     if env.contract.address != sender {
         return Err(ContractError::Unauthorized {});
     }
-    do_update_addr_list(deps, CW20_LIST, to_add, to_remove, |addr, deps| {
+    let added = to_add
+        .iter()
+        .map(|a| deps.api.addr_validate(a))
+        .collect::<StdResult<Vec<_>>>()?;
+    let removed = to_remove
+        .iter()
+        .map(|a| deps.api.addr_validate(a))
+        .collect::<StdResult<Vec<_>>>()?;
+    do_update_addr_list(deps.branch(), CW20_LIST, to_add, to_remove, |addr, deps| {
         // Perform a balance query here as this is the query performed
         // by the `Cw20Balances` query.
         let _info: cw20::BalanceResponse = deps.querier.query_wasm_smart(
@@ -430,6 +1410,15 @@ pub fn execute_update_cw20_list(
         )?;
         Ok(())
     })?;
+    snapshot_treasury(
+        deps.branch(),
+        &env,
+        added.into_iter().map(CheckedDenom::Cw20),
+        &[],
+    )?;
+    for addr in removed {
+        CW20_BALANCE_SNAPSHOTS.remove(deps.storage, addr, env.block.height)?;
+    }
     Ok(Response::default().add_attribute("action", "update_cw20_list"))
 }
 
@@ -452,6 +1441,35 @@ pub fn execute_update_cw721_list(
     Ok(Response::default().add_attribute("action", "update_cw721_list"))
 }
 
+pub fn execute_update_native_list(
+    mut deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    to_add: Vec<String>,
+    to_remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    for denom in &to_add {
+        validate_native_denom(denom.clone())?;
+        NATIVE_LIST.save(deps.storage, denom.clone(), &Empty {})?;
+    }
+    for denom in &to_remove {
+        NATIVE_LIST.remove(deps.storage, denom.clone());
+    }
+    snapshot_treasury(
+        deps.branch(),
+        &env,
+        to_add.into_iter().map(CheckedDenom::Native),
+        &[],
+    )?;
+    for denom in to_remove {
+        NATIVE_BALANCE_SNAPSHOTS.remove(deps.storage, denom, env.block.height)?;
+    }
+    Ok(Response::default().add_attribute("action", "update_native_list"))
+}
+
 pub fn execute_set_item(
     deps: DepsMut,
     env: Env,
@@ -490,6 +1508,129 @@ pub fn execute_remove_item(
     }
 }
 
+pub fn execute_update_item_namespace(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    namespace: String,
+    writers: Vec<String>,
+    value_type: Option<ItemValueType>,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let writers = writers
+        .into_iter()
+        .map(|writer| deps.api.addr_validate(&writer))
+        .collect::<StdResult<Vec<_>>>()?;
+    ITEM_NAMESPACES.save(
+        deps.storage,
+        namespace.clone(),
+        &ItemNamespace {
+            namespace: namespace.clone(),
+            writers,
+            value_type,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_update_item_namespace")
+        .add_attribute("namespace", namespace))
+}
+
+pub fn execute_remove_item_namespace(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    namespace: String,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if !ITEM_NAMESPACES.has(deps.storage, namespace.clone()) {
+        return Err(ContractError::ItemNamespaceDoesNotExist { namespace });
+    }
+    let empty = NAMESPACED_ITEMS
+        .prefix(namespace.clone())
+        .keys_raw(deps.storage, None, None, Order::Ascending)
+        .next()
+        .is_none();
+    if !empty {
+        return Err(ContractError::ItemNamespaceNotEmpty { namespace });
+    }
+    ITEM_NAMESPACES.remove(deps.storage, namespace.clone());
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_remove_item_namespace")
+        .add_attribute("namespace", namespace))
+}
+
+/// Loads `namespace`, erroring if `sender` may not write to it.
+fn writable_item_namespace(
+    deps: Deps,
+    env: &Env,
+    sender: &Addr,
+    namespace: String,
+) -> Result<ItemNamespace, ContractError> {
+    let namespace = ITEM_NAMESPACES
+        .may_load(deps.storage, namespace.clone())?
+        .ok_or(ContractError::ItemNamespaceDoesNotExist { namespace })?;
+    if *sender != env.contract.address && !namespace.writers.contains(sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(namespace)
+}
+
+pub fn execute_set_namespaced_item(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    namespace: String,
+    key: String,
+    value: String,
+) -> Result<Response, ContractError> {
+    let namespace = writable_item_namespace(deps.as_ref(), &env, &sender, namespace)?;
+    if let Some(value_type) = namespace.value_type {
+        if !value_type.accepts(deps.api, &value) {
+            return Err(ContractError::InvalidItemValue { key, value_type });
+        }
+    }
+
+    NAMESPACED_ITEMS.save(
+        deps.storage,
+        (namespace.namespace.clone(), key.clone()),
+        &value,
+    )?;
+    Ok(Response::default()
+        .add_attribute("action", "execute_set_namespaced_item")
+        .add_attribute("namespace", namespace.namespace)
+        .add_attribute("key", key)
+        .add_attribute("sender", sender))
+}
+
+pub fn execute_remove_namespaced_item(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    namespace: String,
+    key: String,
+) -> Result<Response, ContractError> {
+    let namespace = writable_item_namespace(deps.as_ref(), &env, &sender, namespace)?;
+
+    let storage_key = (namespace.namespace.clone(), key.clone());
+    if !NAMESPACED_ITEMS.has(deps.storage, storage_key.clone()) {
+        return Err(ContractError::KeyMissing {});
+    }
+    NAMESPACED_ITEMS.remove(deps.storage, storage_key);
+    Ok(Response::default()
+        .add_attribute("action", "execute_remove_namespaced_item")
+        .add_attribute("namespace", namespace.namespace)
+        .add_attribute("key", key)
+        .add_attribute("sender", sender))
+}
+
 pub fn execute_update_sub_daos_list(
     deps: DepsMut,
     env: Env,
@@ -508,6 +1649,19 @@ pub fn execute_update_sub_daos_list(
 
     for subdao in to_add {
         let addr = deps.api.addr_validate(&subdao.addr)?;
+        // Older DAOs may have no admin, so the response is read as
+        // optional.
+        let admin: Option<Addr> = deps
+            .querier
+            .query_wasm_smart(&addr, &QueryMsg::Admin {})
+            .map_err(|_| ContractError::SubDaoAdminMismatch {
+                subdao: subdao.addr.clone(),
+            })?;
+        if admin.as_ref() != Some(&env.contract.address) {
+            return Err(ContractError::SubDaoAdminMismatch {
+                subdao: subdao.addr,
+            });
+        }
         SUBDAO_LIST.save(deps.storage, &addr, &subdao.charter)?;
     }
 
@@ -516,28 +1670,87 @@ pub fn execute_update_sub_daos_list(
         .add_attribute("sender", sender))
 }
 
-pub fn execute_receive_cw20(deps: DepsMut, sender: Addr) -> Result<Response, ContractError> {
+pub fn execute_on_sub_dao(
+    deps: Deps,
+    env: Env,
+    sender: Addr,
+    subdao: String,
+    msgs: Vec<CosmosMsg<Empty>>,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let addr = deps.api.addr_validate(&subdao)?;
+    if !SUBDAO_LIST.has(deps.storage, &addr) {
+        return Err(ContractError::SubDaoNotRegistered { subdao });
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_on_sub_dao")
+        .add_attribute("subdao", addr.as_str())
+        .add_message(WasmMsg::Execute {
+            contract_addr: addr.into_string(),
+            msg: to_json_binary(&ExecuteMsg::ExecuteAdminMsgs { msgs })?,
+            funds: vec![],
+        }))
+}
+
+pub fn execute_receive_cw20(
+    mut deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    msg: cw20::Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    if !config.automatically_add_cw20s {
-        Ok(Response::new())
+    let response = if !config.automatically_add_cw20s {
+        Response::new()
     } else {
         CW20_LIST.save(deps.storage, sender.clone(), &Empty {})?;
-        Ok(Response::new()
+        Response::new()
             .add_attribute("action", "receive_cw20")
-            .add_attribute("token", sender))
-    }
+            .add_attribute("token", sender.clone())
+    };
+    snapshot_treasury(
+        deps.branch(),
+        &env,
+        [CheckedDenom::Cw20(sender.clone())],
+        &[],
+    )?;
+    let hooks = core_hooks(
+        deps.storage,
+        CoreChangedHookMsg::Cw20Received {
+            token: sender,
+            sender: msg.sender,
+            amount: msg.amount,
+        },
+    )?;
+    Ok(response.add_submessages(hooks))
 }
 
-pub fn execute_receive_cw721(deps: DepsMut, sender: Addr) -> Result<Response, ContractError> {
+pub fn execute_receive_cw721(
+    deps: DepsMut,
+    sender: Addr,
+    msg: cw721::Cw721ReceiveMsg,
+) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    if !config.automatically_add_cw721s {
-        Ok(Response::new())
+    let response = if !config.automatically_add_cw721s {
+        Response::new()
     } else {
         CW721_LIST.save(deps.storage, sender.clone(), &Empty {})?;
-        Ok(Response::new()
+        Response::new()
             .add_attribute("action", "receive_cw721")
-            .add_attribute("token", sender))
-    }
+            .add_attribute("token", sender.clone())
+    };
+    let hooks = core_hooks(
+        deps.storage,
+        CoreChangedHookMsg::Cw721Received {
+            token: sender,
+            sender: msg.sender,
+            token_id: msg.token_id,
+        },
+    )?;
+    Ok(response.add_submessages(hooks))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -553,11 +1766,38 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Cw721TokenList { start_after, limit } => {
             query_cw721_list(deps, start_after, limit)
         }
+        QueryMsg::TreasurySnapshot { height } => query_treasury_snapshot(deps, height),
+        QueryMsg::NativeTokenList { start_after, limit } => {
+            query_native_list(deps, start_after, limit)
+        }
+        QueryMsg::NativeBalances { start_after, limit } => {
+            query_native_balances(deps, env, start_after, limit)
+        }
         QueryMsg::DumpState {} => query_dump_state(deps, env),
         QueryMsg::GetItem { key } => query_get_item(deps, key),
         QueryMsg::Info {} => query_info(deps),
         QueryMsg::ListItems { start_after, limit } => query_list_items(deps, start_after, limit),
+        QueryMsg::GetNamespacedItem { namespace, key } => {
+            query_get_namespaced_item(deps, namespace, key)
+        }
+        QueryMsg::ListItemNamespaces { start_after, limit } => {
+            query_list_item_namespaces(deps, start_after, limit)
+        }
+        QueryMsg::ListItemsWithValues {
+            namespace,
+            start_after,
+            limit,
+        } => query_list_items_with_values(deps, namespace, start_after, limit),
         QueryMsg::PauseInfo {} => query_paused(deps, env),
+        QueryMsg::ListPauses {} => query_list_pauses(deps, env),
+        QueryMsg::CoreHooks {} => to_json_binary(&CORE_HOOKS.query_hooks(deps)?),
+        QueryMsg::Pauser {} => to_json_binary(&PAUSER.may_load(deps.storage)?),
+        QueryMsg::PauserMaxPauseDuration {} => {
+            to_json_binary(&PAUSER_MAX_PAUSE_DURATION.may_load(deps.storage)?)
+        }
+        QueryMsg::ListSchedules { start_after, limit } => {
+            query_list_schedules(deps, start_after, limit)
+        }
         QueryMsg::ProposalModules { start_after, limit } => {
             query_proposal_modules(deps, start_after, limit)
         }
@@ -573,6 +1813,8 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ListSubDaos { start_after, limit } => {
             query_list_sub_daos(deps, start_after, limit)
         }
+        QueryMsg::ListSubDaoTree { depth } => query_list_sub_dao_tree(deps, env, depth),
+        QueryMsg::SpendLimits {} => query_spend_limits(deps, env),
         QueryMsg::DaoURI {} => query_dao_uri(deps),
     }
 }
@@ -671,6 +1913,51 @@ pub fn query_paused(deps: Deps, env: Env) -> StdResult<Binary> {
     to_json_binary(&get_pause_info(deps, env)?)
 }
 
+pub fn query_list_schedules(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    to_json_binary(&paginate_map_values(
+        deps,
+        &SCHEDULES,
+        start_after,
+        limit,
+        Order::Ascending,
+    )?)
+}
+
+pub fn query_list_pauses(deps: Deps, env: Env) -> StdResult<Binary> {
+    let mut pauses = vec![];
+    if let PauseInfoResponse::Paused { expiration } = get_pause_info(deps, env.clone())? {
+        pauses.push(PauseResponse {
+            scope: PauseScope::All {},
+            expiration,
+        });
+    }
+    for item in PAUSED_PROPOSAL_MODULES.range(deps.storage, None, None, Order::Ascending) {
+        let (address, expiration) = item?;
+        if !expiration.is_expired(&env.block) {
+            pauses.push(PauseResponse {
+                scope: PauseScope::ProposalModule {
+                    address: address.into_string(),
+                },
+                expiration,
+            });
+        }
+    }
+    if let Some(expiration) = TREASURY_PAUSED
+        .may_load(deps.storage)?
+        .filter(|expiration| !expiration.is_expired(&env.block))
+    {
+        pauses.push(PauseResponse {
+            scope: PauseScope::Treasury {},
+            expiration,
+        });
+    }
+    to_json_binary(&pauses)
+}
+
 pub fn query_dump_state(deps: Deps, env: Env) -> StdResult<Binary> {
     let admin = ADMIN.load(deps.storage)?;
     let config = CONFIG.load(deps.storage)?;
@@ -679,8 +1966,15 @@ pub fn query_dump_state(deps: Deps, env: Env) -> StdResult<Binary> {
         .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
         .map(|kv| Ok(kv?.1))
         .collect::<StdResult<Vec<ProposalModule>>>()?;
-    let pause_info = get_pause_info(deps, env)?;
+    let pause_info = get_pause_info(deps, env.clone())?;
     let version = get_contract_version(deps.storage)?;
+    let native_balances = native_balances(
+        deps,
+        &env,
+        NATIVE_LIST
+            .keys(deps.storage, None, None, Order::Descending)
+            .collect::<StdResult<Vec<_>>>()?,
+    )?;
     let active_proposal_module_count = ACTIVE_PROPOSAL_MODULE_COUNT.load(deps.storage)?;
     let total_proposal_module_count = TOTAL_PROPOSAL_MODULE_COUNT.load(deps.storage)?;
     to_json_binary(&DumpStateResponse {
@@ -692,15 +1986,38 @@ pub fn query_dump_state(deps: Deps, env: Env) -> StdResult<Binary> {
         voting_module,
         active_proposal_module_count,
         total_proposal_module_count,
+        native_balances,
     })
 }
 
+/// Loads the voting module which was authoritative at `height`, or
+/// the current voting module if `height` is `None`.
+fn voting_module_at_height(deps: Deps, height: Option<u64>) -> StdResult<Addr> {
+    let historic = match height {
+        Some(height) => VOTING_MODULE_HISTORY
+            .range(
+                deps.storage,
+                None,
+                Some(Bound::inclusive(height)),
+                Order::Descending,
+            )
+            .next()
+            .transpose()?
+            .map(|(_, module)| module),
+        None => None,
+    };
+    match historic {
+        Some(module) => Ok(module),
+        None => VOTING_MODULE.load(deps.storage),
+    }
+}
+
 pub fn query_voting_power_at_height(
     deps: Deps,
     address: String,
     height: Option<u64>,
 ) -> StdResult<Binary> {
-    let voting_module = VOTING_MODULE.load(deps.storage)?;
+    let voting_module = voting_module_at_height(deps, height)?;
     let voting_power: voting::VotingPowerAtHeightResponse = deps.querier.query_wasm_smart(
         voting_module,
         &voting::Query::VotingPowerAtHeight { height, address },
@@ -709,7 +2026,7 @@ pub fn query_voting_power_at_height(
 }
 
 pub fn query_total_power_at_height(deps: Deps, height: Option<u64>) -> StdResult<Binary> {
-    let voting_module = VOTING_MODULE.load(deps.storage)?;
+    let voting_module = voting_module_at_height(deps, height)?;
     let total_power: voting::TotalPowerAtHeightResponse = deps
         .querier
         .query_wasm_smart(voting_module, &voting::Query::TotalPowerAtHeight { height })?;
@@ -740,6 +2057,48 @@ pub fn query_list_items(
     )?)
 }
 
+pub fn query_get_namespaced_item(deps: Deps, namespace: String, key: String) -> StdResult<Binary> {
+    let item = NAMESPACED_ITEMS.may_load(deps.storage, (namespace, key))?;
+    to_json_binary(&GetItemResponse { item })
+}
+
+pub fn query_list_item_namespaces(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    to_json_binary(&paginate_map_values(
+        deps,
+        &ITEM_NAMESPACES,
+        start_after,
+        limit,
+        cosmwasm_std::Order::Descending,
+    )?)
+}
+
+pub fn query_list_items_with_values(
+    deps: Deps,
+    namespace: Option<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let Some(namespace) = namespace else {
+        return query_list_items(deps, start_after, limit);
+    };
+
+    let items = NAMESPACED_ITEMS.prefix(namespace).range(
+        deps.storage,
+        None,
+        start_after.map(Bound::exclusive),
+        cosmwasm_std::Order::Descending,
+    );
+    let items: Vec<(String, String)> = match limit {
+        Some(limit) => items.take(limit as usize).collect::<StdResult<_>>()?,
+        None => items.collect::<StdResult<_>>()?,
+    };
+    to_json_binary(&items)
+}
+
 pub fn query_cw20_list(
     deps: Deps,
     start_after: Option<String>,
@@ -805,6 +2164,69 @@ pub fn query_cw20_balances(
     to_json_binary(&balances)
 }
 
+pub fn query_native_list(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    to_json_binary(&paginate_map_keys(
+        deps,
+        &NATIVE_LIST,
+        start_after,
+        limit,
+        cosmwasm_std::Order::Descending,
+    )?)
+}
+
+fn native_balances(deps: Deps, env: &Env, denoms: Vec<String>) -> StdResult<Vec<Coin>> {
+    denoms
+        .into_iter()
+        .map(|denom| deps.querier.query_balance(&env.contract.address, denom))
+        .collect()
+}
+
+pub fn query_native_balances(
+    deps: Deps,
+    env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let denoms = paginate_map_keys(
+        deps,
+        &NATIVE_LIST,
+        start_after,
+        limit,
+        cosmwasm_std::Order::Descending,
+    )?;
+    to_json_binary(&native_balances(deps, &env, denoms)?)
+}
+
+pub fn query_treasury_snapshot(deps: Deps, height: u64) -> StdResult<Binary> {
+    let mut cw20_balances = vec![];
+    for addr in SNAPSHOTTED_CW20S.keys(deps.storage, None, None, Order::Descending) {
+        let addr = addr?;
+        if let Some(balance) =
+            CW20_BALANCE_SNAPSHOTS.may_load_at_height(deps.storage, addr.clone(), height)?
+        {
+            cw20_balances.push(Cw20BalanceResponse { addr, balance });
+        }
+    }
+    let mut native_balances = vec![];
+    for denom in SNAPSHOTTED_NATIVES.keys(deps.storage, None, None, Order::Descending) {
+        let denom = denom?;
+        if let Some(amount) =
+            NATIVE_BALANCE_SNAPSHOTS.may_load_at_height(deps.storage, denom.clone(), height)?
+        {
+            native_balances.push(Coin { denom, amount });
+        }
+    }
+    to_json_binary(&TreasurySnapshotResponse {
+        height,
+        cw20_balances,
+        native_balances,
+    })
+}
+
 pub fn query_list_sub_daos(
     deps: Deps,
     start_after: Option<String>,
@@ -833,6 +2255,120 @@ pub fn query_list_sub_daos(
     to_json_binary(&subdaos)
 }
 
+pub fn query_list_sub_dao_tree(deps: Deps, env: Env, depth: Option<u32>) -> StdResult<Binary> {
+    let depth = depth
+        .unwrap_or(MAX_SUBDAO_TREE_DEPTH)
+        .min(MAX_SUBDAO_TREE_DEPTH);
+    let subdaos = if depth == 0 {
+        vec![]
+    } else {
+        SUBDAO_LIST
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|item| {
+                item.map(|(addr, charter)| SubDao {
+                    addr: addr.into_string(),
+                    charter,
+                })
+            })
+            .collect::<StdResult<Vec<_>>>()?
+    };
+
+    let mut path = vec![env.contract.address.into_string()];
+    let tree = sub_dao_tree_nodes(deps, subdaos, depth, &mut path);
+    to_json_binary(&tree)
+}
+
+/// Builds tree nodes for `subdaos`, querying their own SubDAOs until
+/// `depth` levels have been walked. `path` holds the DAOs above the
+/// current level so that cycles are not followed.
+fn sub_dao_tree_nodes(
+    deps: Deps,
+    subdaos: Vec<SubDao>,
+    depth: u32,
+    path: &mut Vec<String>,
+) -> Vec<SubDaoTreeNode> {
+    subdaos
+        .into_iter()
+        .map(|subdao| {
+            let children = if depth <= 1 || path.contains(&subdao.addr) {
+                vec![]
+            } else {
+                path.push(subdao.addr.clone());
+                let children = list_all_sub_daos(deps, &subdao.addr);
+                let children = sub_dao_tree_nodes(deps, children, depth - 1, path);
+                path.pop();
+                children
+            };
+            SubDaoTreeNode {
+                addr: subdao.addr,
+                charter: subdao.charter,
+                children,
+            }
+        })
+        .collect()
+}
+
+/// Lists the SubDAOs of the DAO at `addr`, loading at most
+/// `MAX_SUBDAO_PAGES` pages. Contracts which do not support the
+/// `ListSubDaos` query are treated as having none.
+fn list_all_sub_daos(deps: Deps, addr: &str) -> Vec<SubDao> {
+    let mut subdaos: Vec<SubDao> = vec![];
+    for _ in 0..MAX_SUBDAO_PAGES {
+        let start_after = subdaos.last().map(|subdao| subdao.addr.clone());
+        let page: Vec<SubDao> = match deps.querier.query_wasm_smart(
+            addr,
+            &QueryMsg::ListSubDaos {
+                start_after: start_after.clone(),
+                limit: None,
+            },
+        ) {
+            Ok(page) => page,
+            Err(_) => break,
+        };
+        // SubDAOs are listed in ascending order, so a page which does
+        // not end after the previous one makes no progress.
+        let progressed = match (page.last(), &start_after) {
+            (Some(last), Some(start_after)) => last.addr > *start_after,
+            (Some(_), None) => true,
+            (None, _) => false,
+        };
+        if !progressed {
+            break;
+        }
+        subdaos.extend(page);
+    }
+    subdaos
+}
+
+pub fn query_spend_limits(deps: Deps, env: Env) -> StdResult<Binary> {
+    let response = |denom: CheckedDenom, state: SpendLimitState| {
+        // A period which has ended no longer counts towards the limit.
+        let current = state
+            .period_expiration
+            .filter(|expiration| !expiration.is_expired(&env.block));
+        SpendLimitResponse {
+            denom,
+            limit: state.limit,
+            period: state.period,
+            spent: if current.is_some() {
+                state.spent
+            } else {
+                Uint128::zero()
+            },
+            period_expiration: current,
+        }
+    };
+
+    let native = NATIVE_SPEND_LIMITS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, state)| response(CheckedDenom::Native(denom), state)));
+    let cw20 = CW20_SPEND_LIMITS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(addr, state)| response(CheckedDenom::Cw20(addr), state)));
+
+    to_json_binary(&native.chain(cw20).collect::<StdResult<Vec<_>>>()?)
+}
+
 pub fn query_dao_uri(deps: Deps) -> StdResult<Binary> {
     let config = CONFIG.load(deps.storage)?;
     to_json_binary(&DaoURIResponse {
@@ -882,6 +2418,7 @@ pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, Con
                         address: address.clone(),
                         status: ProposalModuleStatus::Enabled {},
                         prefix,
+                        execution_policy: None,
                     };
                     PROPOSAL_MODULES.save(deps.storage, address, proposal_module)?;
                     Ok(())
@@ -929,7 +2466,7 @@ pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, Con
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
     match msg.id {
         PROPOSAL_MODULE_REPLY_ID => {
             let res = parse_reply_instantiate_data(msg)?;
@@ -937,10 +2474,16 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
             let total_module_count = TOTAL_PROPOSAL_MODULE_COUNT.load(deps.storage)?;
 
             let prefix = derive_proposal_module_prefix(total_module_count as usize)?;
+            // Set if the module is being added by
+            // `AddRestrictedProposalModule`.
+            let execution_policy = PENDING_EXECUTION_POLICY.may_load(deps.storage)?;
+            PENDING_EXECUTION_POLICY.remove(deps.storage);
+
             let prop_module = ProposalModule {
                 address: prop_module_addr.clone(),
                 status: ProposalModuleStatus::Enabled,
                 prefix,
+                execution_policy,
             };
 
             PROPOSAL_MODULES.save(deps.storage, prop_module_addr, &prop_module)?;
@@ -992,10 +2535,37 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
             let res = parse_reply_instantiate_data(msg)?;
             let vote_module_addr = deps.api.addr_validate(&res.contract_address)?;
 
+            // Proposals created before the replacement read voting
+            // power at heights the new module may not know about, so
+            // those heights remain with the old module. The new module
+            // is authoritative from this height on, as it is the
+            // current module for the rest of this block.
+            let first_replacement = VOTING_MODULE_HISTORY
+                .keys_raw(deps.storage, None, None, Order::Ascending)
+                .next()
+                .is_none();
+            if first_replacement {
+                let old = VOTING_MODULE.load(deps.storage)?;
+                VOTING_MODULE_HISTORY.save(deps.storage, 0, &old)?;
+            }
+            VOTING_MODULE_HISTORY.save(deps.storage, env.block.height, &vote_module_addr)?;
             VOTING_MODULE.save(deps.storage, &vote_module_addr)?;
 
             Ok(Response::default().add_attribute("voting_module", vote_module_addr))
         }
+        FAILED_CORE_HOOK_REPLY_ID => {
+            // A failing hook receiver does not revert the change it
+            // was being notified of.
+            let error = msg.result.into_result().err().unwrap_or_default();
+            Ok(Response::default().add_attribute("failed_core_hook", error))
+        }
+        FAILED_SCHEDULE_REPLY_ID => {
+            // A failing schedule remains due, to be retried by a later
+            // crank, and does not prevent other schedules from
+            // executing.
+            let error = msg.result.into_result().err().unwrap_or_default();
+            Ok(Response::default().add_attribute("failed_schedule", error))
+        }
         _ => Err(ContractError::UnknownReplyID {}),
     }
 }
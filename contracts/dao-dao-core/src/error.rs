@@ -1,5 +1,8 @@
-use cosmwasm_std::{Addr, StdError};
-use cw_utils::ParseReplyError;
+use cosmwasm_std::{Addr, StdError, Uint128};
+use cw_denom::DenomError;
+use cw_hooks::HookError;
+use cw_utils::{Duration, Expiration, ParseReplyError};
+use dao_interface::state::ItemValueType;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -10,12 +13,36 @@ pub enum ContractError {
     #[error(transparent)]
     ParseReplyError(#[from] ParseReplyError),
 
+    #[error(transparent)]
+    Denom(#[from] DenomError),
+
+    #[error(transparent)]
+    Hook(#[from] HookError),
+
     #[error("Unauthorized.")]
     Unauthorized {},
 
     #[error("The contract is paused.")]
     Paused {},
 
+    #[error("Proposal module with address ({address}) is paused.")]
+    ProposalModulePaused { address: Addr },
+
+    #[error("Messages sending funds out of the treasury are paused.")]
+    TreasuryPaused {},
+
+    #[error("The pauser may pause for at most ({max:?}).")]
+    PauseTooLong { max: Duration },
+
+    #[error("A maximum pause duration must be provided with a pauser.")]
+    MissingMaxPauseDuration {},
+
+    #[error("The pauser may not pause again until ({until}).")]
+    PauserCooldown { until: Expiration },
+
+    #[error("The pauser may not change a pause imposed by the DAO.")]
+    PausedByDao {},
+
     #[error("No voting module provided.")]
     NoVotingModule {},
 
@@ -51,6 +78,64 @@ pub enum ContractError {
     #[error("Proposal module with address is disabled and cannot execute messages.")]
     ModuleDisabledCannotExecute { address: Addr },
 
+    #[error("Proposal module with address ({address}) may not execute messages of this type.")]
+    MsgTypeNotAllowed { address: Addr },
+
+    #[error("Proposal module with address ({address}) may only execute stargate and custom messages if their types are explicitly allowed.")]
+    OpaqueMsgNotAllowed { address: Addr },
+
+    #[error("Proposal module with address ({address}) may not execute messages on contract ({contract}).")]
+    ContractNotAllowed { address: Addr, contract: String },
+
+    #[error("Proposal module with address ({address}) may not spend more than ({limit}{denom}) in a single execution.")]
+    SpendLimitExceeded {
+        address: Addr,
+        denom: String,
+        limit: Uint128,
+    },
+
+    #[error("Proposal module with address ({address}) has spend limits and may not execute messages whose spending can not be measured.")]
+    UnmeteredSpend { address: Addr },
+
+    #[error("Messages whose spending can not be measured may not be executed while treasury spend limits are set.")]
+    UnmeteredTreasurySpend {},
+
+    #[error("Sending these funds would exceed the treasury's spend limit of ({limit}) ({denom}) per period.")]
+    TreasurySpendLimitExceeded { denom: String, limit: Uint128 },
+
+    #[error("A schedule must execute at least once.")]
+    ZeroScheduleCount {},
+
+    #[error("A schedule which executes more than once must have an interval.")]
+    MissingScheduleInterval {},
+
+    #[error("A schedule may not start never.")]
+    NeverScheduleStart {},
+
+    #[error("Schedule with ID ({id}) does not exist.")]
+    ScheduleDoesNotExist { id: u64 },
+
+    #[error("Schedule with ID ({id}) is not due.")]
+    ScheduleNotDue { id: u64 },
+
+    #[error("SubDAO ({subdao}) must have this DAO as its admin.")]
+    SubDaoAdminMismatch { subdao: String },
+
+    #[error("SubDAO ({subdao}) is not registered with this DAO.")]
+    SubDaoNotRegistered { subdao: String },
+
+    #[error("Item namespace ({namespace}) does not exist.")]
+    ItemNamespaceDoesNotExist { namespace: String },
+
+    #[error("Item namespace ({namespace}) must be empty to be removed.")]
+    ItemNamespaceNotEmpty { namespace: String },
+
+    #[error("Value of item ({key}) is not a valid ({value_type}).")]
+    InvalidItemValue {
+        key: String,
+        value_type: ItemValueType,
+    },
+
     #[error("Duplicate initial item: ({item})")]
     DuplicateInitialItem { item: String },
 
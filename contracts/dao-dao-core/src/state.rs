@@ -1,7 +1,9 @@
-use cosmwasm_std::{Addr, Empty};
-use cw_storage_plus::{Item, Map};
-use cw_utils::Expiration;
-use dao_interface::state::{Config, ProposalModule};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Empty, Uint128};
+use cw_hooks::Hooks;
+use cw_storage_plus::{Item, Map, SnapshotMap, Strategy};
+use cw_utils::{Duration, Expiration};
+use dao_interface::state::{Config, ExecutionPolicy, ItemNamespace, ProposalModule, Schedule};
 
 /// The admin of the contract. Typically a DAO. The contract admin may
 /// unilaterally execute messages on this contract.
@@ -27,14 +29,52 @@ pub const CONFIG: Item<Config> = Item::new("config_v2");
 /// the DAO has never been paused.
 pub const PAUSED: Item<Expiration> = Item::new("paused");
 
+/// Proposal modules whose proposal hooks are paused, and the times
+/// their pauses end.
+pub const PAUSED_PROPOSAL_MODULES: Map<Addr, Expiration> = Map::new("paused_proposal_modules");
+
+/// The time treasury outflows will unpause. Not set if they have never
+/// been paused.
+pub const TREASURY_PAUSED: Item<Expiration> = Item::new("treasury_paused");
+
+/// An address which may pause and unpause the DAO, but may not execute
+/// messages. Not set if the DAO has no pauser.
+pub const PAUSER: Item<Addr> = Item::new("pauser");
+
+/// The longest duration the pauser may pause for. Set whenever the
+/// pauser is set.
+pub const PAUSER_MAX_PAUSE_DURATION: Item<Duration> = Item::new("pauser_max_pause_duration");
+
+/// The time until which the pauser may not pause again: the end of
+/// its most recent pause plus its maximum pause duration. Not set if
+/// the pauser has not paused since it was set.
+pub const PAUSER_COOLDOWN: Item<Expiration> = Item::new("pauser_cooldown");
+
+/// The names of the scopes whose current pause was imposed by the
+/// pauser, and the times those pauses end. The pauser may only lift
+/// these pauses.
+pub const PAUSER_PAUSES: Map<String, Expiration> = Map::new("pauser_pauses");
+
 /// The voting module associated with this contract.
 pub const VOTING_MODULE: Item<Addr> = Item::new("voting_module");
 
+/// Voting modules keyed by the first block height they are
+/// authoritative for. Written when the voting module is replaced, so
+/// that voting power queries for heights before the replacement are
+/// answered by the module which was in use at that height.
+pub const VOTING_MODULE_HISTORY: Map<u64, Addr> = Map::new("voting_module_history");
+
 /// The proposal modules associated with this contract.
 /// When we change the data format of this map, we update the key (previously "proposal_modules")
 /// to create a new namespace for the changed state.
 pub const PROPOSAL_MODULES: Map<Addr, ProposalModule> = Map::new("proposal_modules_v2");
 
+/// The execution policy of a proposal module being instantiated by
+/// `AddRestrictedProposalModule`. Set before the module is
+/// instantiated and removed when the module is installed in the
+/// instantiation reply.
+pub const PENDING_EXECUTION_POLICY: Item<ExecutionPolicy> = Item::new("pending_execution_policy");
+
 /// The count of active proposal modules associated with this contract.
 pub const ACTIVE_PROPOSAL_MODULE_COUNT: Item<u32> = Item::new("active_proposal_module_count");
 
@@ -44,6 +84,11 @@ pub const TOTAL_PROPOSAL_MODULE_COUNT: Item<u32> = Item::new("total_proposal_mod
 // General purpose KV store for DAO associated state.
 pub const ITEMS: Map<String, String> = Map::new("items");
 
+/// Item namespaces, keyed by name.
+pub const ITEM_NAMESPACES: Map<String, ItemNamespace> = Map::new("item_namespaces");
+/// Items stored in namespaces, keyed by namespace and then item key.
+pub const NAMESPACED_ITEMS: Map<(String, String), String> = Map::new("namespaced_items");
+
 /// Set of cw20 tokens that have been registered with this contract's
 /// treasury.
 pub const CW20_LIST: Map<Addr, Empty> = Map::new("cw20s");
@@ -51,5 +96,59 @@ pub const CW20_LIST: Map<Addr, Empty> = Map::new("cw20s");
 /// treasury.
 pub const CW721_LIST: Map<Addr, Empty> = Map::new("cw721s");
 
+/// Set of native denoms that have been registered with this contract's
+/// treasury.
+pub const NATIVE_LIST: Map<String, Empty> = Map::new("natives");
+
+/// The treasury's balances of registered cw20 tokens, as of the last
+/// time they changed through this contract's entry points.
+pub const CW20_BALANCE_SNAPSHOTS: SnapshotMap<Addr, Uint128> = SnapshotMap::new(
+    "cw20_balance_snapshots",
+    "cw20_balance_snapshots__checkpoints",
+    "cw20_balance_snapshots__changelog",
+    Strategy::EveryBlock,
+);
+/// The treasury's balances of registered native denoms, as of the last
+/// time they changed through this contract's entry points.
+pub const NATIVE_BALANCE_SNAPSHOTS: SnapshotMap<String, Uint128> = SnapshotMap::new(
+    "native_balance_snapshots",
+    "native_balance_snapshots__checkpoints",
+    "native_balance_snapshots__changelog",
+    Strategy::EveryBlock,
+);
+/// Every cw20 token which has had a balance snapshot. Tokens are kept
+/// after they are removed from `CW20_BALANCE_SNAPSHOTS` so that their
+/// past balances may be queried.
+pub const SNAPSHOTTED_CW20S: Map<Addr, Empty> = Map::new("snapshotted_cw20s");
+/// Every native denom which has had a balance snapshot.
+pub const SNAPSHOTTED_NATIVES: Map<String, Empty> = Map::new("snapshotted_natives");
+
+/// Batches of messages scheduled for execution, keyed by schedule ID.
+pub const SCHEDULES: Map<u64, Schedule> = Map::new("schedules");
+/// The number of schedules which have been created. Used to assign
+/// schedule IDs.
+pub const SCHEDULE_COUNT: Item<u64> = Item::new("schedule_count");
+
+/// Contracts sent a hook when the DAO's config, modules, admin or
+/// pauses change, or when it receives tokens.
+pub const CORE_HOOKS: Hooks = Hooks::new("core_hooks");
+
 /// List of SubDAOs associated to this DAO. Each SubDAO has an optional charter.
 pub const SUBDAO_LIST: Map<&Addr, Option<String>> = Map::new("sub_daos");
+
+/// A treasury spend limit and the amount sent out during its current
+/// period.
+#[cw_serde]
+pub struct SpendLimitState {
+    pub limit: Uint128,
+    pub period: Duration,
+    pub spent: Uint128,
+    /// When the current, fixed, period ends. `None` until the first
+    /// outflow.
+    pub period_expiration: Option<Expiration>,
+}
+
+/// Spend limits on native denoms held by this contract's treasury.
+pub const NATIVE_SPEND_LIMITS: Map<String, SpendLimitState> = Map::new("native_spend_limits");
+/// Spend limits on cw20 tokens held by this contract's treasury.
+pub const CW20_SPEND_LIMITS: Map<Addr, SpendLimitState> = Map::new("cw20_spend_limits");
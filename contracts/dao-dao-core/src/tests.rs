@@ -2,20 +2,27 @@ use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
     from_json,
     testing::{mock_dependencies, mock_env},
-    to_json_binary, Addr, CosmosMsg, Empty, Storage, Uint128, WasmMsg,
+    to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo,
+    Response, StdResult, Storage, Uint128, WasmMsg,
 };
 use cw2::{set_contract_version, ContractVersion};
-use cw_multi_test::{App, Contract, ContractWrapper, Executor};
+use cw_hooks::HooksResponse;
+use cw_multi_test::{next_block, App, BankSudo, Contract, ContractWrapper, Executor};
 use cw_storage_plus::{Item, Map};
 use cw_utils::{Duration, Expiration};
+use dao_hooks::dao_core::{CoreChangedExecuteMsg, CoreChangedHookMsg};
 use dao_interface::{
     msg::{ExecuteMsg, InitialItem, InstantiateMsg, MigrateMsg, QueryMsg},
     query::{
         AdminNominationResponse, Cw20BalanceResponse, DaoURIResponse, DumpStateResponse,
-        GetItemResponse, PauseInfoResponse, ProposalModuleCountResponse, SubDao,
+        GetItemResponse, PauseInfoResponse, PauseResponse, ProposalModuleCountResponse,
+        SpendLimitResponse, SubDao, SubDaoTreeNode, TreasurySnapshotResponse,
     },
-    state::{Admin, Config, ModuleInstantiateInfo, ProposalModule, ProposalModuleStatus},
-    voting::{InfoResponse, VotingPowerAtHeightResponse},
+    state::{
+        Admin, Config, ExecutionPolicy, ItemNamespace, ItemValueType, ModuleInstantiateInfo,
+        MsgType, PauseScope, ProposalModule, ProposalModuleStatus, SpendLimit,
+    },
+    voting::{InfoResponse, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse},
 };
 
 use crate::{
@@ -63,6 +70,51 @@ fn cw20_balances_voting() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
+/// Records the core hooks it receives, returning them when queried.
+fn core_hook_receiver_contract() -> Box<dyn Contract<Empty>> {
+    const RECEIVED: Item<Vec<CoreChangedHookMsg>> = Item::new("received");
+    let contract = ContractWrapper::new(
+        |deps: DepsMut,
+         _: Env,
+         _: MessageInfo,
+         msg: CoreChangedExecuteMsg|
+         -> StdResult<Response> {
+            let CoreChangedExecuteMsg::CoreChangedHook(hook) = msg;
+            let mut received = RECEIVED.may_load(deps.storage)?.unwrap_or_default();
+            received.push(hook);
+            RECEIVED.save(deps.storage, &received)?;
+            Ok(Response::new())
+        },
+        |_: DepsMut, _: Env, _: MessageInfo, _: Empty| -> StdResult<Response> {
+            Ok(Response::new())
+        },
+        |deps: Deps, _: Env, _: Empty| -> StdResult<Binary> {
+            to_json_binary(&RECEIVED.may_load(deps.storage)?.unwrap_or_default())
+        },
+    );
+    Box::new(contract)
+}
+
+/// Answers every query with the same page of SubDAOs, ignoring
+/// `start_after`.
+fn repeating_sub_daos_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        |_: DepsMut, _: Env, _: MessageInfo, _: Empty| -> StdResult<Response> {
+            Ok(Response::new())
+        },
+        |_: DepsMut, _: Env, _: MessageInfo, _: Empty| -> StdResult<Response> {
+            Ok(Response::new())
+        },
+        |_: Deps, _: Env, _: QueryMsg| -> StdResult<Binary> {
+            to_json_binary(&vec![SubDao {
+                addr: "repeated".to_string(),
+                charter: None,
+            }])
+        },
+    );
+    Box::new(contract)
+}
+
 fn cw_core_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
         crate::contract::execute,
@@ -954,6 +1006,30 @@ fn test_permissions() {
         },
     );
 
+    test_unauthorized(
+        &mut app,
+        gov_addr.clone(),
+        ExecuteMsg::AddRestrictedProposalModule {
+            module: ModuleInstantiateInfo {
+                code_id: govmod_id,
+                msg: to_json_binary(&govmod_instantiate).unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "restricted governance module".to_string(),
+            },
+            execution_policy: ExecutionPolicy::default(),
+        },
+    );
+
+    test_unauthorized(
+        &mut app,
+        gov_addr.clone(),
+        ExecuteMsg::UpdateProposalModuleExecutionPolicy {
+            address: CREATOR_ADDR.to_string(),
+            execution_policy: None,
+        },
+    );
+
     test_unauthorized(
         &mut app,
         gov_addr,
@@ -972,6 +1048,12 @@ fn test_permissions() {
 
 fn do_standard_instantiate(auto_add: bool, admin: Option<String>) -> (Addr, App) {
     let mut app = App::default();
+    let gov_addr = instantiate_dao(&mut app, auto_add, admin);
+    (gov_addr, app)
+}
+
+/// Instantiates a DAO in `app`, storing the code it needs.
+fn instantiate_dao(app: &mut App, auto_add: bool, admin: Option<String>) -> Addr {
     let govmod_id = app.store_code(sudo_proposal_contract());
     let voting_id = app.store_code(cw20_balances_voting());
     let gov_id = app.store_code(cw_core_contract());
@@ -1020,18 +1102,15 @@ fn do_standard_instantiate(auto_add: bool, admin: Option<String>) -> (Addr, App)
         initial_items: None,
     };
 
-    let gov_addr = app
-        .instantiate_contract(
-            gov_id,
-            Addr::unchecked(CREATOR_ADDR),
-            &gov_instantiate,
-            &[],
-            "cw-governance",
-            None,
-        )
-        .unwrap();
-
-    (gov_addr, app)
+    app.instantiate_contract(
+        gov_id,
+        Addr::unchecked(CREATOR_ADDR),
+        &gov_instantiate,
+        &[],
+        "cw-governance",
+        None,
+    )
+    .unwrap()
 }
 
 #[test]
@@ -1493,6 +1572,82 @@ fn test_passthrough_voting_queries() {
     );
 }
 
+#[test]
+fn test_swap_voting_module_routes_old_heights() {
+    let (gov_addr, mut app) = do_standard_instantiate(true, None);
+    let voting_id = app.store_code(cw20_balances_voting());
+    let cw20_id = app.store_code(cw20_contract());
+
+    let power_at = |app: &App, height: Option<u64>| -> Uint128 {
+        let response: VotingPowerAtHeightResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &gov_addr,
+                &QueryMsg::VotingPowerAtHeight {
+                    address: CREATOR_ADDR.to_string(),
+                    height,
+                },
+            )
+            .unwrap();
+        response.power
+    };
+    let total_power_at = |app: &App, height: Option<u64>| -> Uint128 {
+        let response: TotalPowerAtHeightResponse = app
+            .wrap()
+            .query_wasm_smart(&gov_addr, &QueryMsg::TotalPowerAtHeight { height })
+            .unwrap();
+        response.power
+    };
+
+    let swap_height = app.block_info().height;
+    app.execute_contract(
+        gov_addr.clone(),
+        gov_addr.clone(),
+        &ExecuteMsg::UpdateVotingModule {
+            module: ModuleInstantiateInfo {
+                code_id: voting_id,
+                msg: to_json_binary(&dao_voting_cw20_balance::msg::InstantiateMsg {
+                    token_info: dao_voting_cw20_balance::msg::TokenInfo::New {
+                        code_id: cw20_id,
+                        label: "new voting".to_string(),
+                        name: "New DAO".to_string(),
+                        symbol: "NEW".to_string(),
+                        decimals: 6,
+                        initial_balances: vec![cw20::Cw20Coin {
+                            address: CREATOR_ADDR.to_string(),
+                            amount: Uint128::new(5),
+                        }],
+                        marketing: None,
+                    },
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "new voting module".to_string(),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    // In the block of the swap, queries at the current height agree
+    // with queries without a height.
+    assert_eq!(power_at(&app, Some(swap_height)), Uint128::new(5));
+    assert_eq!(total_power_at(&app, Some(swap_height)), Uint128::new(5));
+    assert_eq!(power_at(&app, None), Uint128::new(5));
+    app.update_block(next_block);
+
+    // Heights before the swap are answered by the old module, so
+    // proposals open during the swap are unaffected.
+    assert_eq!(power_at(&app, Some(swap_height - 1)), Uint128::new(2));
+    assert_eq!(total_power_at(&app, Some(swap_height - 1)), Uint128::new(2));
+
+    assert_eq!(power_at(&app, Some(swap_height)), Uint128::new(5));
+    assert_eq!(power_at(&app, Some(swap_height + 1)), Uint128::new(5));
+    assert_eq!(total_power_at(&app, Some(swap_height + 1)), Uint128::new(5));
+    assert_eq!(power_at(&app, None), Uint128::new(5));
+}
+
 fn set_item(app: &mut App, gov_addr: Addr, key: String, value: String) {
     app.execute_contract(
         gov_addr.clone(),
@@ -1722,6 +1877,246 @@ fn test_list_items() {
     );
 }
 
+#[test]
+fn test_namespaced_items() {
+    let (gov_addr, mut app) = do_standard_instantiate(true, None);
+
+    // Namespaces may only be created by the DAO.
+    test_unauthorized(
+        &mut app,
+        gov_addr.clone(),
+        ExecuteMsg::UpdateItemNamespace {
+            namespace: "prices".to_string(),
+            writers: vec!["oracle".to_string()],
+            value_type: Some(ItemValueType::Decimal),
+        },
+    );
+
+    // Items may not be set in namespaces which do not exist.
+    let err: ContractError = app
+        .execute_contract(
+            gov_addr.clone(),
+            gov_addr.clone(),
+            &ExecuteMsg::SetNamespacedItem {
+                namespace: "prices".to_string(),
+                key: "ujuno".to_string(),
+                value: "1.5".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::ItemNamespaceDoesNotExist {
+            namespace: "prices".to_string()
+        }
+    );
+
+    app.execute_contract(
+        gov_addr.clone(),
+        gov_addr.clone(),
+        &ExecuteMsg::UpdateItemNamespace {
+            namespace: "prices".to_string(),
+            writers: vec!["oracle".to_string()],
+            value_type: Some(ItemValueType::Decimal),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Writers may set items without going through the DAO.
+    for (key, value) in [("ujuno", "1.5"), ("uatom", "8")] {
+        app.execute_contract(
+            Addr::unchecked("oracle"),
+            gov_addr.clone(),
+            &ExecuteMsg::SetNamespacedItem {
+                namespace: "prices".to_string(),
+                key: key.to_string(),
+                value: value.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // Other addresses may not.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            gov_addr.clone(),
+            &ExecuteMsg::SetNamespacedItem {
+                namespace: "prices".to_string(),
+                key: "ujuno".to_string(),
+                value: "100".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // Values must be of the namespace's type.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("oracle"),
+            gov_addr.clone(),
+            &ExecuteMsg::SetNamespacedItem {
+                namespace: "prices".to_string(),
+                key: "ujuno".to_string(),
+                value: "cheap".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::InvalidItemValue {
+            key: "ujuno".to_string(),
+            value_type: ItemValueType::Decimal
+        }
+    );
+
+    let item: GetItemResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &gov_addr,
+            &QueryMsg::GetNamespacedItem {
+                namespace: "prices".to_string(),
+                key: "ujuno".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(item.item, Some("1.5".to_string()));
+
+    // Namespaced items are kept apart from other items.
+    set_item(
+        &mut app,
+        gov_addr.clone(),
+        "ujuno".to_string(),
+        "juno".to_string(),
+    );
+    let items: Vec<(String, String)> = app
+        .wrap()
+        .query_wasm_smart(
+            &gov_addr,
+            &QueryMsg::ListItemsWithValues {
+                namespace: Some("prices".to_string()),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        items,
+        vec![
+            ("ujuno".to_string(), "1.5".to_string()),
+            ("uatom".to_string(), "8".to_string())
+        ]
+    );
+    let items: Vec<(String, String)> = app
+        .wrap()
+        .query_wasm_smart(
+            &gov_addr,
+            &QueryMsg::ListItemsWithValues {
+                namespace: Some("prices".to_string()),
+                start_after: Some("ujuno".to_string()),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(items, vec![("uatom".to_string(), "8".to_string())]);
+    let items: Vec<(String, String)> = app
+        .wrap()
+        .query_wasm_smart(
+            &gov_addr,
+            &QueryMsg::ListItemsWithValues {
+                namespace: None,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(items, vec![("ujuno".to_string(), "juno".to_string())]);
+
+    let namespaces: Vec<ItemNamespace> = app
+        .wrap()
+        .query_wasm_smart(
+            &gov_addr,
+            &QueryMsg::ListItemNamespaces {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        namespaces,
+        vec![ItemNamespace {
+            namespace: "prices".to_string(),
+            writers: vec![Addr::unchecked("oracle")],
+            value_type: Some(ItemValueType::Decimal),
+        }]
+    );
+
+    // Namespaces must be emptied before they are removed.
+    let err: ContractError = app
+        .execute_contract(
+            gov_addr.clone(),
+            gov_addr.clone(),
+            &ExecuteMsg::RemoveItemNamespace {
+                namespace: "prices".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::ItemNamespaceNotEmpty {
+            namespace: "prices".to_string()
+        }
+    );
+
+    for key in ["ujuno", "uatom"] {
+        app.execute_contract(
+            Addr::unchecked("oracle"),
+            gov_addr.clone(),
+            &ExecuteMsg::RemoveNamespacedItem {
+                namespace: "prices".to_string(),
+                key: key.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+    app.execute_contract(
+        gov_addr.clone(),
+        gov_addr.clone(),
+        &ExecuteMsg::RemoveItemNamespace {
+            namespace: "prices".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let namespaces: Vec<ItemNamespace> = app
+        .wrap()
+        .query_wasm_smart(
+            &gov_addr,
+            &QueryMsg::ListItemNamespaces {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(namespaces.is_empty());
+}
+
 #[test]
 fn test_instantiate_with_items() {
     let mut app = App::default();
@@ -2999,21 +3394,26 @@ fn test_add_remove_subdaos() {
         },
     );
 
+    let mut subdaos: Vec<String> = (0..4)
+        .map(|_| instantiate_dao(&mut app, false, Some(core_addr.to_string())).into_string())
+        .collect();
+    subdaos.sort();
+
     let to_add: Vec<SubDao> = vec![
         SubDao {
-            addr: "subdao001".to_string(),
+            addr: subdaos[0].clone(),
             charter: None,
         },
         SubDao {
-            addr: "subdao002".to_string(),
+            addr: subdaos[1].clone(),
             charter: Some("cool charter bro".to_string()),
         },
         SubDao {
-            addr: "subdao005".to_string(),
+            addr: subdaos[2].clone(),
             charter: None,
         },
         SubDao {
-            addr: "subdao007".to_string(),
+            addr: subdaos[3].clone(),
             charter: None,
         },
     ];
@@ -3040,7 +3440,7 @@ fn test_add_remove_subdaos() {
 
     assert_eq!(res.len(), 4);
 
-    let to_remove: Vec<String> = vec!["subdao005".to_string()];
+    let to_remove: Vec<String> = vec![subdaos[2].clone()];
 
     app.execute_contract(
         Addr::unchecked(core_addr.clone()),
@@ -3056,7 +3456,7 @@ fn test_add_remove_subdaos() {
     let res: Vec<SubDao> = app
         .wrap()
         .query_wasm_smart(
-            core_addr,
+            core_addr.clone(),
             &QueryMsg::ListSubDaos {
                 start_after: None,
                 limit: None,
@@ -3067,7 +3467,7 @@ fn test_add_remove_subdaos() {
     assert_eq!(res.len(), 3);
 
     let test_res: SubDao = SubDao {
-        addr: "subdao002".to_string(),
+        addr: subdaos[1].clone(),
         charter: Some("cool charter bro".to_string()),
     };
 
@@ -3075,28 +3475,244 @@ fn test_add_remove_subdaos() {
 
     let full_result_set: Vec<SubDao> = vec![
         SubDao {
-            addr: "subdao001".to_string(),
+            addr: subdaos[0].clone(),
             charter: None,
         },
         SubDao {
-            addr: "subdao002".to_string(),
+            addr: subdaos[1].clone(),
             charter: Some("cool charter bro".to_string()),
         },
         SubDao {
-            addr: "subdao007".to_string(),
+            addr: subdaos[3].clone(),
             charter: None,
         },
     ];
 
     assert_eq!(res, full_result_set);
-}
 
-#[test]
-pub fn test_migrate_update_version() {
-    let mut deps = mock_dependencies();
-    cw2::set_contract_version(&mut deps.storage, "my-contract", "old-version").unwrap();
-    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
-    let version = cw2::get_contract_version(&deps.storage).unwrap();
+    // Contracts which are not administered by the DAO may not be
+    // added.
+    let unadministered = instantiate_dao(&mut app, false, None);
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::UpdateSubDaos {
+                to_add: vec![SubDao {
+                    addr: unadministered.to_string(),
+                    charter: None,
+                }],
+                to_remove: vec![],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::SubDaoAdminMismatch {
+            subdao: unadministered.to_string()
+        }
+    );
+
+    // Nor may addresses which are not DAOs.
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr,
+            &ExecuteMsg::UpdateSubDaos {
+                to_add: vec![SubDao {
+                    addr: "notadao".to_string(),
+                    charter: None,
+                }],
+                to_remove: vec![],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::SubDaoAdminMismatch {
+            subdao: "notadao".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_sub_dao_tree() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+    let child = instantiate_dao(&mut app, false, Some(core_addr.to_string()));
+    let grandchild = instantiate_dao(&mut app, false, Some(child.to_string()));
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateSubDaos {
+            to_add: vec![SubDao {
+                addr: child.to_string(),
+                charter: Some("child".to_string()),
+            }],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The DAO registers the grandchild on the child's behalf.
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteOnSubDao {
+            subdao: child.to_string(),
+            msgs: vec![WasmMsg::Execute {
+                contract_addr: child.to_string(),
+                msg: to_json_binary(&ExecuteMsg::UpdateSubDaos {
+                    to_add: vec![SubDao {
+                        addr: grandchild.to_string(),
+                        charter: None,
+                    }],
+                    to_remove: vec![],
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let tree: Vec<SubDaoTreeNode> = app
+        .wrap()
+        .query_wasm_smart(&core_addr, &QueryMsg::ListSubDaoTree { depth: None })
+        .unwrap();
+    assert_eq!(
+        tree,
+        vec![SubDaoTreeNode {
+            addr: child.to_string(),
+            charter: Some("child".to_string()),
+            children: vec![SubDaoTreeNode {
+                addr: grandchild.to_string(),
+                charter: None,
+                children: vec![],
+            }],
+        }]
+    );
+
+    let tree: Vec<SubDaoTreeNode> = app
+        .wrap()
+        .query_wasm_smart(&core_addr, &QueryMsg::ListSubDaoTree { depth: Some(1) })
+        .unwrap();
+    assert_eq!(
+        tree,
+        vec![SubDaoTreeNode {
+            addr: child.to_string(),
+            charter: Some("child".to_string()),
+            children: vec![],
+        }]
+    );
+
+    let tree: Vec<SubDaoTreeNode> = app
+        .wrap()
+        .query_wasm_smart(&core_addr, &QueryMsg::ListSubDaoTree { depth: Some(0) })
+        .unwrap();
+    assert!(tree.is_empty());
+
+    // Only the DAO may execute on its SubDAOs.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteOnSubDao {
+                subdao: child.to_string(),
+                msgs: vec![],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // The grandchild is not registered directly with the DAO.
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr,
+            &ExecuteMsg::ExecuteOnSubDao {
+                subdao: grandchild.to_string(),
+                msgs: vec![],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::SubDaoNotRegistered {
+            subdao: grandchild.to_string()
+        }
+    );
+}
+
+#[test]
+fn test_sub_dao_tree_stops_without_progress() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+    let code_id = app.store_code(repeating_sub_daos_contract());
+    let child = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &Empty {},
+            &[],
+            "repeating",
+            None,
+        )
+        .unwrap();
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateSubDaos {
+            to_add: vec![SubDao {
+                addr: child.to_string(),
+                charter: None,
+            }],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The child ignores `start_after`, so its second page repeats the
+    // first and listing stops there.
+    let tree: Vec<SubDaoTreeNode> = app
+        .wrap()
+        .query_wasm_smart(&core_addr, &QueryMsg::ListSubDaoTree { depth: Some(2) })
+        .unwrap();
+    assert_eq!(
+        tree,
+        vec![SubDaoTreeNode {
+            addr: child.to_string(),
+            charter: None,
+            children: vec![SubDaoTreeNode {
+                addr: "repeated".to_string(),
+                charter: None,
+                children: vec![],
+            }],
+        }]
+    );
+}
+
+#[test]
+pub fn test_migrate_update_version() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(&mut deps.storage, "my-contract", "old-version").unwrap();
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
+    let version = cw2::get_contract_version(&deps.storage).unwrap();
     assert_eq!(version.version, CONTRACT_VERSION);
     assert_eq!(version.contract, CONTRACT_NAME);
 }
@@ -3118,3 +3734,1682 @@ fn test_query_info() {
         }
     )
 }
+
+fn bank_send(amount: u128, denom: &str) -> CosmosMsg {
+    BankMsg::Send {
+        to_address: CREATOR_ADDR.to_string(),
+        amount: vec![Coin::new(amount, denom)],
+    }
+    .into()
+}
+
+fn execute_proposal_hook(
+    app: &mut App,
+    core_addr: &Addr,
+    module: &Addr,
+    msgs: Vec<CosmosMsg>,
+) -> Result<(), ContractError> {
+    app.execute_contract(
+        module.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook { msgs },
+        &[],
+    )
+    .map(|_| ())
+    .map_err(|e| e.downcast().unwrap())
+}
+
+#[test]
+fn test_execution_policy() {
+    let (core_addr, mut app) = do_standard_instantiate(true, None);
+    app.sudo(cw_multi_test::SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: vec![Coin::new(100, "ujuno"), Coin::new(100, "uatom")],
+    }))
+    .unwrap();
+
+    let module = get_active_modules(&app, core_addr.clone())
+        .into_iter()
+        .next()
+        .unwrap();
+    assert_eq!(module.execution_policy, None);
+
+    let policy = ExecutionPolicy {
+        allowed_msg_types: Some(vec![MsgType::Bank, MsgType::Wasm]),
+        allowed_contracts: Some(vec![core_addr.to_string()]),
+        spend_limits: Some(vec![Coin::new(10, "ujuno")]),
+    };
+
+    // Only the DAO may set execution policies.
+    let err: ContractError = app
+        .execute_contract(
+            module.address.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::UpdateProposalModuleExecutionPolicy {
+                address: module.address.to_string(),
+                execution_policy: Some(policy.clone()),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::UpdateProposalModuleExecutionPolicy {
+                address: CREATOR_ADDR.to_string(),
+                execution_policy: Some(policy.clone()),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::ProposalModuleDoesNotExist {
+            address: Addr::unchecked(CREATOR_ADDR)
+        }
+    );
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateProposalModuleExecutionPolicy {
+            address: module.address.to_string(),
+            execution_policy: Some(policy.clone()),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let module = get_active_modules(&app, core_addr.clone())
+        .into_iter()
+        .next()
+        .unwrap();
+    assert_eq!(module.execution_policy, Some(policy));
+
+    // Spending up to the limit is allowed.
+    execute_proposal_hook(
+        &mut app,
+        &core_addr,
+        &module.address,
+        vec![bank_send(10, "ujuno")],
+    )
+    .unwrap();
+
+    // Spending is summed over all of the messages of an execution.
+    let err = execute_proposal_hook(
+        &mut app,
+        &core_addr,
+        &module.address,
+        vec![bank_send(6, "ujuno"), bank_send(6, "ujuno")],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::SpendLimitExceeded {
+            address: module.address.clone(),
+            denom: "ujuno".to_string(),
+            limit: Uint128::new(10),
+        }
+    );
+
+    // Denoms without a limit may not be spent.
+    let err = execute_proposal_hook(
+        &mut app,
+        &core_addr,
+        &module.address,
+        vec![bank_send(1, "uatom")],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::SpendLimitExceeded {
+            address: module.address.clone(),
+            denom: "uatom".to_string(),
+            limit: Uint128::zero(),
+        }
+    );
+
+    let err = execute_proposal_hook(
+        &mut app,
+        &core_addr,
+        &module.address,
+        vec![CosmosMsg::Stargate {
+            type_url: "foo_type".to_string(),
+            value: to_json_binary("foo_bin").unwrap(),
+        }],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MsgTypeNotAllowed {
+            address: module.address.clone()
+        }
+    );
+
+    let voting_module: Addr = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::VotingModule {})
+        .unwrap();
+    let err = execute_proposal_hook(
+        &mut app,
+        &core_addr,
+        &module.address,
+        vec![WasmMsg::ClearAdmin {
+            contract_addr: voting_module.to_string(),
+        }
+        .into()],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ContractNotAllowed {
+            address: module.address.clone(),
+            contract: voting_module.to_string(),
+        }
+    );
+
+    // Allowed contracts may be executed.
+    execute_proposal_hook(
+        &mut app,
+        &core_addr,
+        &module.address,
+        vec![WasmMsg::Execute {
+            contract_addr: core_addr.to_string(),
+            msg: to_json_binary(&ExecuteMsg::SetItem {
+                key: "foo".to_string(),
+                value: "bar".to_string(),
+            })
+            .unwrap(),
+            funds: vec![],
+        }
+        .into()],
+    )
+    .unwrap();
+    assert_eq!(
+        get_item(&mut app, core_addr.clone(), "foo".to_string()).item,
+        Some("bar".to_string())
+    );
+
+    // Removing the policy lifts all restrictions.
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateProposalModuleExecutionPolicy {
+            address: module.address.to_string(),
+            execution_policy: None,
+        },
+        &[],
+    )
+    .unwrap();
+    execute_proposal_hook(
+        &mut app,
+        &core_addr,
+        &module.address,
+        vec![bank_send(50, "ujuno"), bank_send(50, "uatom")],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_add_restricted_proposal_module() {
+    let (core_addr, mut app) = do_standard_instantiate(true, None);
+    let govmod_id = app.store_code(sudo_proposal_contract());
+    let govmod_instantiate = dao_proposal_sudo::msg::InstantiateMsg {
+        root: CREATOR_ADDR.to_string(),
+    };
+
+    let policy = ExecutionPolicy {
+        allowed_msg_types: Some(vec![MsgType::Bank]),
+        allowed_contracts: None,
+        spend_limits: Some(vec![Coin::new(10, "ujuno")]),
+    };
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::AddRestrictedProposalModule {
+            module: ModuleInstantiateInfo {
+                code_id: govmod_id,
+                msg: to_json_binary(&govmod_instantiate).unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "restricted governance module".to_string(),
+            },
+            execution_policy: policy.clone(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let modules = get_active_modules(&app, core_addr.clone());
+    assert_eq!(modules.len(), 2);
+    let restricted = modules.iter().find(|module| module.prefix == "B").unwrap();
+    assert_eq!(restricted.execution_policy, Some(policy));
+    let unrestricted = modules.iter().find(|module| module.prefix == "A").unwrap();
+    assert_eq!(unrestricted.execution_policy, None);
+    assert_eq!(
+        query_proposal_module_count(&app, &core_addr),
+        ProposalModuleCountResponse {
+            active_proposal_module_count: 2,
+            total_proposal_module_count: 2,
+        }
+    );
+
+    // The restricted module is bound by its policy from the moment it
+    // is installed.
+    let err = execute_proposal_hook(
+        &mut app,
+        &core_addr,
+        &restricted.address,
+        vec![bank_send(11, "ujuno")],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::SpendLimitExceeded {
+            address: restricted.address.clone(),
+            denom: "ujuno".to_string(),
+            limit: Uint128::new(10),
+        }
+    );
+
+    // Modules added afterwards are not restricted.
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateProposalModules {
+            to_add: vec![ModuleInstantiateInfo {
+                code_id: govmod_id,
+                msg: to_json_binary(&govmod_instantiate).unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "governance module".to_string(),
+            }],
+            to_disable: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    let added = get_active_modules(&app, core_addr)
+        .into_iter()
+        .find(|module| module.prefix == "C")
+        .unwrap();
+    assert_eq!(added.execution_policy, None);
+}
+
+#[test]
+fn test_execution_policy_self_call() {
+    let (core_addr, mut app) = do_standard_instantiate(true, None);
+    let module = get_active_modules(&app, core_addr.clone())
+        .into_iter()
+        .next()
+        .unwrap();
+
+    let token = Addr::unchecked("token");
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateProposalModuleExecutionPolicy {
+            address: module.address.to_string(),
+            execution_policy: Some(ExecutionPolicy {
+                allowed_msg_types: Some(vec![MsgType::Wasm]),
+                allowed_contracts: None,
+                spend_limits: Some(vec![Coin::new(5, token.as_str())]),
+            }),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // A restricted module may not remove its own policy.
+    let err = execute_proposal_hook(
+        &mut app,
+        &core_addr,
+        &module.address,
+        vec![WasmMsg::Execute {
+            contract_addr: core_addr.to_string(),
+            msg: to_json_binary(&ExecuteMsg::UpdateProposalModuleExecutionPolicy {
+                address: module.address.to_string(),
+                execution_policy: None,
+            })
+            .unwrap(),
+            funds: vec![],
+        }
+        .into()],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ContractNotAllowed {
+            address: module.address.clone(),
+            contract: core_addr.to_string(),
+        }
+    );
+    let module = get_active_modules(&app, core_addr.clone())
+        .into_iter()
+        .next()
+        .unwrap();
+    assert!(module.execution_policy.is_some());
+
+    // Nor may it do so through a stargate message, which may only be
+    // sent if stargate messages are explicitly allowed.
+    let stargate_self_call = CosmosMsg::Stargate {
+        type_url: "/cosmwasm.wasm.v1.MsgExecuteContract".to_string(),
+        value: Binary::default(),
+    };
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateProposalModuleExecutionPolicy {
+            address: module.address.to_string(),
+            execution_policy: Some(ExecutionPolicy {
+                allowed_msg_types: None,
+                allowed_contracts: Some(vec![token.to_string()]),
+                spend_limits: None,
+            }),
+        },
+        &[],
+    )
+    .unwrap();
+    let err = execute_proposal_hook(
+        &mut app,
+        &core_addr,
+        &module.address,
+        vec![stargate_self_call],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::OpaqueMsgNotAllowed {
+            address: module.address.clone(),
+        }
+    );
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateProposalModuleExecutionPolicy {
+            address: module.address.to_string(),
+            execution_policy: Some(ExecutionPolicy {
+                allowed_msg_types: Some(vec![MsgType::Wasm]),
+                allowed_contracts: None,
+                spend_limits: Some(vec![Coin::new(5, token.as_str())]),
+            }),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // cw20 transfers count against the token contract's limit.
+    let err = execute_proposal_hook(
+        &mut app,
+        &core_addr,
+        &module.address,
+        vec![cw20_transfer(&token, 3), cw20_transfer(&token, 3)],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::SpendLimitExceeded {
+            address: module.address.clone(),
+            denom: token.to_string(),
+            limit: Uint128::new(5),
+        }
+    );
+}
+
+fn cw20_transfer(token: &Addr, amount: u128) -> CosmosMsg {
+    WasmMsg::Execute {
+        contract_addr: token.to_string(),
+        msg: to_json_binary(&cw20::Cw20ExecuteMsg::Transfer {
+            recipient: CREATOR_ADDR.to_string(),
+            amount: Uint128::new(amount),
+        })
+        .unwrap(),
+        funds: vec![],
+    }
+    .into()
+}
+
+#[test]
+fn test_spend_limits() {
+    let (core_addr, mut app) = do_standard_instantiate(true, None);
+    app.sudo(cw_multi_test::SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: vec![Coin::new(100, "ujuno"), Coin::new(100, "uatom")],
+    }))
+    .unwrap();
+
+    let voting_module: Addr = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::VotingModule {})
+        .unwrap();
+    let gov_token: Addr = app
+        .wrap()
+        .query_wasm_smart(
+            voting_module,
+            &dao_interface::voting::Query::TokenContract {},
+        )
+        .unwrap();
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        gov_token.clone(),
+        &cw20::Cw20ExecuteMsg::Transfer {
+            recipient: core_addr.to_string(),
+            amount: Uint128::new(2),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let module = get_active_modules(&app, core_addr.clone())
+        .into_iter()
+        .next()
+        .unwrap()
+        .address;
+
+    let update = ExecuteMsg::UpdateSpendLimits {
+        to_set: vec![
+            SpendLimit {
+                denom: cw_denom::UncheckedDenom::Native("ujuno".to_string()),
+                limit: Uint128::new(10),
+                period: Duration::Height(10),
+            },
+            SpendLimit {
+                denom: cw_denom::UncheckedDenom::Cw20(gov_token.to_string()),
+                limit: Uint128::new(1),
+                period: Duration::Height(10),
+            },
+        ],
+        to_remove: vec![],
+    };
+
+    // Only the DAO may update spend limits.
+    let err: ContractError = app
+        .execute_contract(module.clone(), core_addr.clone(), &update, &[])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    app.execute_contract(core_addr.clone(), core_addr.clone(), &update, &[])
+        .unwrap();
+
+    execute_proposal_hook(&mut app, &core_addr, &module, vec![bank_send(6, "ujuno")]).unwrap();
+    let err = execute_proposal_hook(&mut app, &core_addr, &module, vec![bank_send(5, "ujuno")])
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TreasurySpendLimitExceeded {
+            denom: "ujuno".to_string(),
+            limit: Uint128::new(10),
+        }
+    );
+    // Denoms without a limit are not restricted.
+    execute_proposal_hook(
+        &mut app,
+        &core_addr,
+        &module,
+        vec![bank_send(4, "ujuno"), bank_send(50, "uatom")],
+    )
+    .unwrap();
+
+    execute_proposal_hook(
+        &mut app,
+        &core_addr,
+        &module,
+        vec![cw20_transfer(&gov_token, 1)],
+    )
+    .unwrap();
+    let err = execute_proposal_hook(
+        &mut app,
+        &core_addr,
+        &module,
+        vec![cw20_transfer(&gov_token, 1)],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TreasurySpendLimitExceeded {
+            denom: gov_token.to_string(),
+            limit: Uint128::new(1),
+        }
+    );
+    // Allowances count against spend limits, as the spender may
+    // transfer the tokens at any time.
+    let err = execute_proposal_hook(
+        &mut app,
+        &core_addr,
+        &module,
+        vec![WasmMsg::Execute {
+            contract_addr: gov_token.to_string(),
+            msg: to_json_binary(&cw20::Cw20ExecuteMsg::IncreaseAllowance {
+                spender: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(1),
+                expires: None,
+            })
+            .unwrap(),
+            funds: vec![],
+        }
+        .into()],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TreasurySpendLimitExceeded {
+            denom: gov_token.to_string(),
+            limit: Uint128::new(1),
+        }
+    );
+
+    // Messages whose spending can not be measured may not be executed
+    // while spend limits are set.
+    let err = execute_proposal_hook(
+        &mut app,
+        &core_addr,
+        &module,
+        vec![CosmosMsg::Stargate {
+            type_url: "/cosmos.authz.v1beta1.MsgExec".to_string(),
+            value: Binary::default(),
+        }],
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::UnmeteredTreasurySpend {});
+
+    let start_height = app.block_info().height;
+    let limits: Vec<SpendLimitResponse> = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::SpendLimits {})
+        .unwrap();
+    assert_eq!(
+        limits,
+        vec![
+            SpendLimitResponse {
+                denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
+                limit: Uint128::new(10),
+                period: Duration::Height(10),
+                spent: Uint128::new(10),
+                period_expiration: Some(Expiration::AtHeight(start_height + 10)),
+            },
+            SpendLimitResponse {
+                denom: cw_denom::CheckedDenom::Cw20(gov_token.clone()),
+                limit: Uint128::new(1),
+                period: Duration::Height(10),
+                spent: Uint128::new(1),
+                period_expiration: Some(Expiration::AtHeight(start_height + 10)),
+            },
+        ]
+    );
+
+    // Once the period ends the limit may be spent again.
+    app.update_block(|block| block.height += 10);
+    let limits: Vec<SpendLimitResponse> = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::SpendLimits {})
+        .unwrap();
+    assert_eq!(limits[0].spent, Uint128::zero());
+    assert_eq!(limits[0].period_expiration, None);
+    execute_proposal_hook(&mut app, &core_addr, &module, vec![bank_send(10, "ujuno")]).unwrap();
+    execute_proposal_hook(
+        &mut app,
+        &core_addr,
+        &module,
+        vec![cw20_transfer(&gov_token, 1)],
+    )
+    .unwrap();
+
+    // Removing a limit lifts the restriction.
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateSpendLimits {
+            to_set: vec![],
+            to_remove: vec![cw_denom::UncheckedDenom::Native("ujuno".to_string())],
+        },
+        &[],
+    )
+    .unwrap();
+    execute_proposal_hook(&mut app, &core_addr, &module, vec![bank_send(20, "ujuno")]).unwrap();
+
+    let limits: Vec<SpendLimitResponse> = app
+        .wrap()
+        .query_wasm_smart(core_addr, &QueryMsg::SpendLimits {})
+        .unwrap();
+    assert_eq!(limits.len(), 1);
+    assert_eq!(limits[0].denom, cw_denom::CheckedDenom::Cw20(gov_token));
+}
+
+#[test]
+fn test_admin_spend_limits() {
+    let (core_addr, mut app) = do_standard_instantiate(true, Some("admin".to_string()));
+    app.sudo(cw_multi_test::SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: vec![Coin::new(100, "ujuno")],
+    }))
+    .unwrap();
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateSpendLimits {
+            to_set: vec![SpendLimit {
+                denom: cw_denom::UncheckedDenom::Native("ujuno".to_string()),
+                limit: Uint128::new(10),
+                period: Duration::Height(10),
+            }],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The admin is bound by the same spend limits as proposal modules.
+    let execute_admin_msgs = |app: &mut App, msgs: Vec<CosmosMsg>| {
+        app.execute_contract(
+            Addr::unchecked("admin"),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteAdminMsgs { msgs },
+            &[],
+        )
+        .map_err(|e| e.downcast::<ContractError>().unwrap())
+    };
+    execute_admin_msgs(&mut app, vec![bank_send(6, "ujuno")]).unwrap();
+    let err = execute_admin_msgs(&mut app, vec![bank_send(5, "ujuno")]).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TreasurySpendLimitExceeded {
+            denom: "ujuno".to_string(),
+            limit: Uint128::new(10),
+        }
+    );
+    let err = execute_admin_msgs(
+        &mut app,
+        vec![CosmosMsg::Stargate {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: Binary::default(),
+        }],
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::UnmeteredTreasurySpend {});
+    execute_admin_msgs(&mut app, vec![bank_send(4, "ujuno")]).unwrap();
+}
+
+#[test]
+fn test_scoped_pause() {
+    let (core_addr, mut app) = do_standard_instantiate(true, None);
+    app.sudo(cw_multi_test::SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: vec![Coin::new(100, "ujuno")],
+    }))
+    .unwrap();
+    let module = get_active_modules(&app, core_addr.clone())
+        .into_iter()
+        .next()
+        .unwrap()
+        .address;
+    let guardian = Addr::unchecked("guardian");
+    let start_height = app.block_info().height;
+
+    // Only the DAO may set the pauser.
+    let err: ContractError = app
+        .execute_contract(
+            module.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::UpdatePauser {
+                pauser: Some(guardian.to_string()),
+                max_pause_duration: Some(Duration::Height(10)),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // A pauser must be set with a maximum pause duration.
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::UpdatePauser {
+                pauser: Some(guardian.to_string()),
+                max_pause_duration: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::MissingMaxPauseDuration {});
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdatePauser {
+            pauser: Some(guardian.to_string()),
+            max_pause_duration: Some(Duration::Height(10)),
+        },
+        &[],
+    )
+    .unwrap();
+    let pauser: Option<Addr> = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::Pauser {})
+        .unwrap();
+    assert_eq!(pauser, Some(guardian.clone()));
+    let max_pause_duration: Option<Duration> = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::PauserMaxPauseDuration {})
+        .unwrap();
+    assert_eq!(max_pause_duration, Some(Duration::Height(10)));
+
+    // The pauser may not pause for longer than its maximum pause
+    // duration, or indefinitely.
+    for duration in [Duration::Height(11), Duration::Time(1)] {
+        let err: ContractError = app
+            .execute_contract(
+                guardian.clone(),
+                core_addr.clone(),
+                &ExecuteMsg::Pause { duration },
+                &[],
+            )
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+        assert_eq!(
+            err,
+            ContractError::PauseTooLong {
+                max: Duration::Height(10)
+            }
+        );
+    }
+
+    // The pauser may not execute messages.
+    for msg in [
+        ExecuteMsg::ExecuteProposalHook { msgs: vec![] },
+        ExecuteMsg::ExecuteAdminMsgs { msgs: vec![] },
+    ] {
+        let err: ContractError = app
+            .execute_contract(guardian.clone(), core_addr.clone(), &msg, &[])
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+        assert_eq!(err, ContractError::Unauthorized {});
+    }
+
+    // Pausing a proposal module stops only its proposal hooks.
+    app.execute_contract(
+        guardian.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::PauseScoped {
+            scope: PauseScope::ProposalModule {
+                address: module.to_string(),
+            },
+            duration: Duration::Height(10),
+        },
+        &[],
+    )
+    .unwrap();
+    let err = execute_proposal_hook(&mut app, &core_addr, &module, vec![]).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ProposalModulePaused {
+            address: module.clone()
+        }
+    );
+    set_item(
+        &mut app,
+        core_addr.clone(),
+        "foo".to_string(),
+        "bar".to_string(),
+    );
+
+    let pauses: Vec<PauseResponse> = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::ListPauses {})
+        .unwrap();
+    assert_eq!(
+        pauses,
+        vec![PauseResponse {
+            scope: PauseScope::ProposalModule {
+                address: module.to_string()
+            },
+            expiration: Expiration::AtHeight(start_height + 10),
+        }]
+    );
+
+    app.execute_contract(
+        guardian.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::Unpause {
+            scope: PauseScope::ProposalModule {
+                address: module.to_string(),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    execute_proposal_hook(&mut app, &core_addr, &module, vec![]).unwrap();
+
+    // The pauser must wait its maximum pause duration after the end
+    // of a pause before pausing again, even if the pause was lifted
+    // early.
+    let err: ContractError = app
+        .execute_contract(
+            guardian.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::PauseScoped {
+                scope: PauseScope::Treasury {},
+                duration: Duration::Height(1),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::PauserCooldown {
+            until: Expiration::AtHeight(start_height + 20)
+        }
+    );
+    app.update_block(|block| block.height += 20);
+
+    // Pausing the treasury stops only messages which send funds.
+    app.execute_contract(
+        guardian.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::PauseScoped {
+            scope: PauseScope::Treasury {},
+            duration: Duration::Height(10),
+        },
+        &[],
+    )
+    .unwrap();
+    let err = execute_proposal_hook(&mut app, &core_addr, &module, vec![bank_send(1, "ujuno")])
+        .unwrap_err();
+    assert_eq!(err, ContractError::TreasuryPaused {});
+    execute_proposal_hook(
+        &mut app,
+        &core_addr,
+        &module,
+        vec![WasmMsg::Execute {
+            contract_addr: core_addr.to_string(),
+            msg: to_json_binary(&ExecuteMsg::RemoveItem {
+                key: "foo".to_string(),
+            })
+            .unwrap(),
+            funds: vec![],
+        }
+        .into()],
+    )
+    .unwrap();
+
+    // The treasury pause ends once it expires.
+    app.update_block(|block| block.height += 10);
+    execute_proposal_hook(&mut app, &core_addr, &module, vec![bank_send(1, "ujuno")]).unwrap();
+    app.update_block(|block| block.height += 10);
+
+    // A pause of the whole DAO may be lifted early, even though
+    // nothing else may be executed while it is in effect.
+    app.execute_contract(
+        guardian.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::Pause {
+            duration: Duration::Height(10),
+        },
+        &[],
+    )
+    .unwrap();
+    let err = execute_proposal_hook(&mut app, &core_addr, &module, vec![]).unwrap_err();
+    assert_eq!(err, ContractError::Paused {});
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            core_addr.clone(),
+            &ExecuteMsg::Unpause {
+                scope: PauseScope::All {},
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    app.execute_contract(
+        guardian.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::Unpause {
+            scope: PauseScope::All {},
+        },
+        &[],
+    )
+    .unwrap();
+    let paused: PauseInfoResponse = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::PauseInfo {})
+        .unwrap();
+    assert_eq!(paused, PauseInfoResponse::Unpaused {});
+    execute_proposal_hook(&mut app, &core_addr, &module, vec![]).unwrap();
+
+    // The pauser may neither lift nor replace a pause imposed by the
+    // DAO, even once its cooldown has passed.
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::PauseScoped {
+            scope: PauseScope::Treasury {},
+            duration: Duration::Height(100),
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(|block| block.height += 20);
+    for msg in [
+        ExecuteMsg::Unpause {
+            scope: PauseScope::Treasury {},
+        },
+        ExecuteMsg::PauseScoped {
+            scope: PauseScope::Treasury {},
+            duration: Duration::Height(1),
+        },
+    ] {
+        let err: ContractError = app
+            .execute_contract(guardian.clone(), core_addr.clone(), &msg, &[])
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+        assert_eq!(err, ContractError::PausedByDao {});
+    }
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::Unpause {
+            scope: PauseScope::Treasury {},
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Once removed, the pauser may no longer pause the DAO.
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdatePauser {
+            pauser: None,
+            max_pause_duration: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            guardian,
+            core_addr,
+            &ExecuteMsg::Pause {
+                duration: Duration::Height(10),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
+
+#[test]
+fn test_native_list() {
+    let (core_addr, mut app) = do_standard_instantiate(true, None);
+    app.sudo(cw_multi_test::SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: vec![Coin::new(100, "ujuno")],
+    }))
+    .unwrap();
+
+    test_unauthorized(
+        &mut app,
+        core_addr.clone(),
+        ExecuteMsg::UpdateNativeList {
+            to_add: vec!["ujuno".to_string()],
+            to_remove: vec![],
+        },
+    );
+
+    // Invalid denoms may not be added.
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateNativeList {
+            to_add: vec!["1nvalid".to_string()],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap_err();
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateNativeList {
+            to_add: vec!["ujuno".to_string(), "uatom".to_string()],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let natives: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::NativeTokenList {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(natives, vec!["ujuno".to_string(), "uatom".to_string()]);
+
+    let balances: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::NativeBalances {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        balances,
+        vec![Coin::new(100, "ujuno"), Coin::new(0, "uatom")]
+    );
+
+    let balances: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::NativeBalances {
+                start_after: Some("ujuno".to_string()),
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(balances, vec![Coin::new(0, "uatom")]);
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateNativeList {
+            to_add: vec![],
+            to_remove: vec!["uatom".to_string()],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let state: DumpStateResponse = app
+        .wrap()
+        .query_wasm_smart(core_addr, &QueryMsg::DumpState {})
+        .unwrap();
+    assert_eq!(state.native_balances, vec![Coin::new(100, "ujuno")]);
+}
+
+fn treasury_snapshot(app: &App, core_addr: &Addr, height: u64) -> TreasurySnapshotResponse {
+    app.wrap()
+        .query_wasm_smart(core_addr, &QueryMsg::TreasurySnapshot { height })
+        .unwrap()
+}
+
+#[test]
+fn test_treasury_snapshot() {
+    let (core_addr, mut app) = do_standard_instantiate(true, None);
+    app.sudo(cw_multi_test::SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: vec![Coin::new(100, "ujuno")],
+    }))
+    .unwrap();
+    let voting_module: Addr = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::VotingModule {})
+        .unwrap();
+    let gov_token: Addr = app
+        .wrap()
+        .query_wasm_smart(
+            voting_module,
+            &dao_interface::voting::Query::TokenContract {},
+        )
+        .unwrap();
+    let module = get_active_modules(&app, core_addr.clone())
+        .into_iter()
+        .next()
+        .unwrap()
+        .address;
+
+    let start = app.block_info().height;
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateNativeList {
+            to_add: vec!["ujuno".to_string()],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    // Receiving a cw20 registers it and records its balance.
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        gov_token.clone(),
+        &cw20::Cw20ExecuteMsg::Send {
+            contract: core_addr.to_string(),
+            amount: Uint128::new(2),
+            msg: to_json_binary(&"").unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.update_block(|block| block.height += 1);
+    execute_proposal_hook(
+        &mut app,
+        &core_addr,
+        &module,
+        vec![bank_send(30, "ujuno"), cw20_transfer(&gov_token, 1)],
+    )
+    .unwrap();
+
+    app.update_block(|block| block.height += 1);
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateNativeList {
+            to_add: vec![],
+            to_remove: vec!["ujuno".to_string()],
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(|block| block.height += 1);
+
+    // Snapshots are taken at the start of a block, so nothing was
+    // registered at the start height.
+    assert_eq!(
+        treasury_snapshot(&app, &core_addr, start),
+        TreasurySnapshotResponse {
+            height: start,
+            cw20_balances: vec![],
+            native_balances: vec![],
+        }
+    );
+    assert_eq!(
+        treasury_snapshot(&app, &core_addr, start + 1),
+        TreasurySnapshotResponse {
+            height: start + 1,
+            cw20_balances: vec![Cw20BalanceResponse {
+                addr: gov_token.clone(),
+                balance: Uint128::new(2),
+            }],
+            native_balances: vec![Coin::new(100, "ujuno")],
+        }
+    );
+    assert_eq!(
+        treasury_snapshot(&app, &core_addr, start + 2),
+        TreasurySnapshotResponse {
+            height: start + 2,
+            cw20_balances: vec![Cw20BalanceResponse {
+                addr: gov_token.clone(),
+                balance: Uint128::new(1),
+            }],
+            native_balances: vec![Coin::new(70, "ujuno")],
+        }
+    );
+    // Denoms are no longer snapshotted once they are removed.
+    assert_eq!(
+        treasury_snapshot(&app, &core_addr, start + 3),
+        TreasurySnapshotResponse {
+            height: start + 3,
+            cw20_balances: vec![Cw20BalanceResponse {
+                addr: gov_token,
+                balance: Uint128::new(1),
+            }],
+            native_balances: vec![],
+        }
+    );
+}
+
+fn list_schedules(app: &App, core_addr: &Addr) -> Vec<Schedule> {
+    app.wrap()
+        .query_wasm_smart(
+            core_addr,
+            &QueryMsg::ListSchedules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap()
+}
+
+fn crank(app: &mut App, core_addr: &Addr) {
+    app.execute_contract(
+        Addr::unchecked("cranker"),
+        core_addr.clone(),
+        &ExecuteMsg::Crank {},
+        &[],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_schedules() {
+    let (core_addr, mut app) = do_standard_instantiate(true, None);
+    app.sudo(cw_multi_test::SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: vec![Coin::new(100, "ujuno")],
+    }))
+    .unwrap();
+    let start = app.block_info().height;
+    let balance = |app: &App| {
+        app.wrap()
+            .query_balance(CREATOR_ADDR, "ujuno")
+            .unwrap()
+            .amount
+            .u128()
+    };
+
+    let schedule = |interval: Option<Duration>, count: u64| ExecuteMsg::ScheduleMsgs {
+        msgs: vec![bank_send(10, "ujuno")],
+        start: Expiration::AtHeight(start + 1),
+        interval,
+        count,
+    };
+
+    // Only the DAO may schedule messages.
+    test_unauthorized(
+        &mut app,
+        core_addr.clone(),
+        schedule(Some(Duration::Height(2)), 2),
+    );
+
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &schedule(Some(Duration::Height(2)), 0),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ZeroScheduleCount {});
+
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &schedule(None, 2),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::MissingScheduleInterval {});
+
+    // The start and interval must be of the same unit.
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &schedule(Some(Duration::Time(60)), 2),
+        &[],
+    )
+    .unwrap_err();
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &schedule(Some(Duration::Height(2)), 2),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ScheduleMsgs {
+            msgs: vec![bank_send(5, "ujuno")],
+            start: Expiration::AtHeight(start + 10),
+            interval: None,
+            count: 1,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Nothing is due yet.
+    crank(&mut app, &core_addr);
+    assert_eq!(balance(&app), 0);
+
+    app.update_block(|block| block.height += 1);
+    crank(&mut app, &core_addr);
+    assert_eq!(balance(&app), 10);
+    // A schedule is executed at most once per interval.
+    crank(&mut app, &core_addr);
+    assert_eq!(balance(&app), 10);
+
+    assert_eq!(
+        list_schedules(&app, &core_addr),
+        vec![
+            Schedule {
+                id: 1,
+                msgs: vec![bank_send(10, "ujuno")],
+                next: Expiration::AtHeight(start + 3),
+                interval: Some(Duration::Height(2)),
+                remaining: 1,
+            },
+            Schedule {
+                id: 2,
+                msgs: vec![bank_send(5, "ujuno")],
+                next: Expiration::AtHeight(start + 10),
+                interval: None,
+                remaining: 1,
+            },
+        ]
+    );
+
+    // Only the DAO may cancel schedules.
+    test_unauthorized(
+        &mut app,
+        core_addr.clone(),
+        ExecuteMsg::CancelSchedule { id: 2 },
+    );
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::CancelSchedule { id: 2 },
+        &[],
+    )
+    .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::CancelSchedule { id: 2 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ScheduleDoesNotExist { id: 2 });
+
+    // Schedules are removed after their last execution.
+    app.update_block(|block| block.height += 10);
+    crank(&mut app, &core_addr);
+    assert_eq!(balance(&app), 20);
+    assert_eq!(list_schedules(&app, &core_addr), vec![]);
+}
+
+#[test]
+fn test_failing_schedule() {
+    let (core_addr, mut app) = do_standard_instantiate(true, None);
+    app.sudo(cw_multi_test::SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: vec![Coin::new(100, "ujuno")],
+    }))
+    .unwrap();
+    let start = app.block_info().height;
+
+    // The first schedule sends more than the DAO holds.
+    for amount in [1000, 10] {
+        app.execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ScheduleMsgs {
+                msgs: vec![bank_send(amount, "ujuno")],
+                start: Expiration::AtHeight(start + 1),
+                interval: None,
+                count: 1,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // Only the DAO may execute a schedule directly.
+    test_unauthorized(
+        &mut app,
+        core_addr.clone(),
+        ExecuteMsg::ExecuteSchedule { id: 2 },
+    );
+
+    app.update_block(|block| block.height += 1);
+    let res = app
+        .execute_contract(
+            Addr::unchecked("cranker"),
+            core_addr.clone(),
+            &ExecuteMsg::Crank {},
+            &[],
+        )
+        .unwrap();
+    assert!(res
+        .events
+        .iter()
+        .flat_map(|e| e.attributes.iter())
+        .any(|a| a.key == "failed_schedule"));
+
+    // The failing schedule does not prevent the other from executing.
+    let balance = app.wrap().query_balance(CREATOR_ADDR, "ujuno").unwrap();
+    assert_eq!(balance.amount.u128(), 10);
+
+    // The failed execution remains due.
+    assert_eq!(
+        list_schedules(&app, &core_addr),
+        vec![Schedule {
+            id: 1,
+            msgs: vec![bank_send(1000, "ujuno")],
+            next: Expiration::AtHeight(start + 1),
+            interval: None,
+            remaining: 1,
+        }]
+    );
+
+    // Only due schedules may be executed.
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ScheduleMsgs {
+            msgs: vec![bank_send(10, "ujuno")],
+            start: Expiration::AtHeight(start + 100),
+            interval: None,
+            count: 1,
+        },
+        &[],
+    )
+    .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteSchedule { id: 3 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ScheduleNotDue { id: 3 });
+
+    // Once the DAO can afford it, the failed execution is retried.
+    app.sudo(cw_multi_test::SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: vec![Coin::new(1000, "ujuno")],
+    }))
+    .unwrap();
+    crank(&mut app, &core_addr);
+    let balance = app.wrap().query_balance(CREATOR_ADDR, "ujuno").unwrap();
+    assert_eq!(balance.amount.u128(), 1010);
+    assert_eq!(
+        list_schedules(&app, &core_addr)
+            .into_iter()
+            .map(|schedule| schedule.id)
+            .collect::<Vec<_>>(),
+        vec![3]
+    );
+}
+
+#[test]
+fn test_core_hooks() {
+    let (core_addr, mut app) = do_standard_instantiate(true, None);
+    let receiver_id = app.store_code(core_hook_receiver_contract());
+    let receiver = app
+        .instantiate_contract(
+            receiver_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &Empty {},
+            &[],
+            "receiver",
+            None,
+        )
+        .unwrap();
+    let received = |app: &App| -> Vec<CoreChangedHookMsg> {
+        app.wrap().query_wasm_smart(&receiver, &Empty {}).unwrap()
+    };
+
+    test_unauthorized(
+        &mut app,
+        core_addr.clone(),
+        ExecuteMsg::AddCoreHook {
+            address: receiver.to_string(),
+        },
+    );
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::AddCoreHook {
+            address: receiver.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let hooks: HooksResponse = app
+        .wrap()
+        .query_wasm_smart(&core_addr, &QueryMsg::CoreHooks {})
+        .unwrap();
+    assert_eq!(hooks.hooks, vec![receiver.to_string()]);
+
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(&core_addr, &QueryMsg::Config {})
+        .unwrap();
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateConfig {
+            config: Config {
+                name: "new name".to_string(),
+                ..config.clone()
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(received(&app), vec![CoreChangedHookMsg::ConfigUpdated {}]);
+
+    let expiration = Duration::Height(10).after(&app.block_info());
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::PauseScoped {
+            scope: PauseScope::Treasury {},
+            duration: Duration::Height(10),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::Unpause {
+            scope: PauseScope::Treasury {},
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        received(&app)[1..],
+        [
+            CoreChangedHookMsg::Paused {
+                scope: "treasury".to_string(),
+                expiration,
+            },
+            CoreChangedHookMsg::Unpaused {
+                scope: "treasury".to_string(),
+            },
+        ]
+    );
+
+    // A hook receiver which fails does not revert the change.
+    let cw20_id = app.store_code(cw20_contract());
+    let failing = app
+        .instantiate_contract(
+            cw20_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &cw20_base::msg::InstantiateMsg {
+                name: "DAO".to_string(),
+                symbol: "DAO".to_string(),
+                decimals: 6,
+                initial_balances: vec![],
+                mint: None,
+                marketing: None,
+            },
+            &[],
+            "failing",
+            None,
+        )
+        .unwrap();
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::AddCoreHook {
+            address: failing.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let res = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::UpdateConfig { config },
+            &[],
+        )
+        .unwrap();
+    assert!(res
+        .events
+        .iter()
+        .flat_map(|event| &event.attributes)
+        .any(|attribute| attribute.key == "failed_core_hook"));
+    assert_eq!(received(&app).len(), 4);
+
+    for hook in [receiver, failing] {
+        app.execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::RemoveCoreHook {
+                address: hook.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+    let hooks: HooksResponse = app
+        .wrap()
+        .query_wasm_smart(&core_addr, &QueryMsg::CoreHooks {})
+        .unwrap();
+    assert!(hooks.hooks.is_empty());
+}
@@ -55,6 +55,10 @@ pub fn query_proposal_v1(
         votes: v1_votes_to_v2(proposal.votes),
         allow_revoting: proposal.allow_revoting,
         veto: None,
+        vote_weighting: dao_voting::voting::VoteWeighting::default(),
+        objection_threshold: None,
+        voting_start: None,
+        amendable: false,
     };
 
     (proposal_count, proposal)
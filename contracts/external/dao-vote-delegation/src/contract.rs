@@ -0,0 +1,270 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult,
+    Uint128,
+};
+use cw2::set_contract_version;
+use cw_paginate_storage::paginate_snapshot_map;
+use dao_interface::delegation::{DelegationResponse, DelegatorsResponse};
+use dao_interface::voting::VotingPowerAtHeightResponse;
+
+use crate::error::ContractError;
+use crate::msg::{Delegation, ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{Config, DelegateInfo, CONFIG, DELEGATES, DELEGATIONS, DELEGATORS};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-vote-delegation";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    if msg.max_delegators == 0 {
+        return Err(ContractError::ZeroMaxDelegators {});
+    }
+    let dao = deps.api.addr_validate(&msg.dao)?;
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            dao: dao.clone(),
+            max_delegators: msg.max_delegators,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("method", "instantiate")
+        .add_attribute("dao", dao)
+        .add_attribute("max_delegators", msg.max_delegators.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Register {
+            min_delegated_power,
+        } => execute_register(deps, info.sender, min_delegated_power),
+        ExecuteMsg::Unregister {} => execute_unregister(deps, info.sender),
+        ExecuteMsg::Delegate { delegate } => execute_delegate(deps, env, info.sender, delegate),
+        ExecuteMsg::Undelegate {} => execute_undelegate(deps, env, info.sender),
+        ExecuteMsg::UpdateConfig { max_delegators } => {
+            execute_update_config(deps, info.sender, max_delegators)
+        }
+    }
+}
+
+pub fn execute_register(
+    deps: DepsMut,
+    sender: Addr,
+    min_delegated_power: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    let min_delegated_power = min_delegated_power.unwrap_or_default();
+    DELEGATES.save(
+        deps.storage,
+        &sender,
+        &DelegateInfo {
+            min_delegated_power,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "register")
+        .add_attribute("delegate", sender)
+        .add_attribute("min_delegated_power", min_delegated_power))
+}
+
+pub fn execute_unregister(deps: DepsMut, sender: Addr) -> Result<Response, ContractError> {
+    if !DELEGATES.has(deps.storage, &sender) {
+        return Err(ContractError::NotRegistered { delegate: sender });
+    }
+    DELEGATES.remove(deps.storage, &sender);
+
+    Ok(Response::default()
+        .add_attribute("action", "unregister")
+        .add_attribute("delegate", sender))
+}
+
+pub fn execute_delegate(
+    mut deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    delegate: String,
+) -> Result<Response, ContractError> {
+    let delegate = deps.api.addr_validate(&delegate)?;
+    if delegate == sender {
+        return Err(ContractError::CannotDelegateToSelf {});
+    }
+    let info = DELEGATES
+        .may_load(deps.storage, &delegate)?
+        .ok_or_else(|| ContractError::NotRegistered {
+            delegate: delegate.clone(),
+        })?;
+
+    if let Some(current) = DELEGATIONS.may_load(deps.storage, &sender)? {
+        if current == delegate {
+            return Err(ContractError::AlreadyDelegated { delegate });
+        }
+        remove_delegator(deps.branch(), &env, &current, &sender)?;
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+
+    // Addresses without voting power, or with less than the delegate
+    // asks for, may not delegate, so that they can not use up a
+    // delegate's limited delegator slots.
+    let power: VotingPowerAtHeightResponse = deps.querier.query_wasm_smart(
+        &config.dao,
+        &dao_interface::msg::QueryMsg::VotingPowerAtHeight {
+            address: sender.to_string(),
+            height: None,
+        },
+    )?;
+    if power.power.is_zero() {
+        return Err(ContractError::ZeroVotingPower {});
+    }
+    if power.power < info.min_delegated_power {
+        return Err(ContractError::InsufficientVotingPower {
+            delegate,
+            min: info.min_delegated_power,
+        });
+    }
+
+    let mut delegators = DELEGATORS
+        .may_load(deps.storage, &delegate)?
+        .unwrap_or_default();
+    if delegators.len() as u32 >= config.max_delegators {
+        return Err(ContractError::TooManyDelegators {
+            delegate,
+            max: config.max_delegators,
+        });
+    }
+    delegators.push(sender.clone());
+    DELEGATORS.save(deps.storage, &delegate, &delegators, env.block.height)?;
+    DELEGATIONS.save(deps.storage, &sender, &delegate, env.block.height)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "delegate")
+        .add_attribute("delegator", sender)
+        .add_attribute("delegate", delegate))
+}
+
+pub fn execute_undelegate(
+    mut deps: DepsMut,
+    env: Env,
+    sender: Addr,
+) -> Result<Response, ContractError> {
+    let delegate = DELEGATIONS
+        .may_load(deps.storage, &sender)?
+        .ok_or(ContractError::NotDelegated {})?;
+    remove_delegator(deps.branch(), &env, &delegate, &sender)?;
+    DELEGATIONS.remove(deps.storage, &sender, env.block.height)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "undelegate")
+        .add_attribute("delegator", sender)
+        .add_attribute("delegate", delegate))
+}
+
+/// Removes `delegator` from the delegators of `delegate`.
+fn remove_delegator(deps: DepsMut, env: &Env, delegate: &Addr, delegator: &Addr) -> StdResult<()> {
+    let mut delegators = DELEGATORS
+        .may_load(deps.storage, delegate)?
+        .unwrap_or_default();
+    delegators.retain(|addr| addr != delegator);
+    if delegators.is_empty() {
+        DELEGATORS.remove(deps.storage, delegate, env.block.height)
+    } else {
+        DELEGATORS.save(deps.storage, delegate, &delegators, env.block.height)
+    }
+}
+
+pub fn execute_update_config(
+    deps: DepsMut,
+    sender: Addr,
+    max_delegators: u32,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    if max_delegators == 0 {
+        return Err(ContractError::ZeroMaxDelegators {});
+    }
+    config.max_delegators = max_delegators;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "update_config")
+        .add_attribute("max_delegators", max_delegators.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Delegate { address } => {
+            let address = deps.api.addr_validate(&address)?;
+            to_json_binary(&DELEGATES.may_load(deps.storage, &address)?)
+        }
+        QueryMsg::Delegation { delegator, height } => query_delegation(deps, delegator, height),
+        QueryMsg::Delegators { delegate, height } => query_delegators(deps, delegate, height),
+        QueryMsg::ListDelegations { start_after, limit } => {
+            query_list_delegations(deps, start_after, limit)
+        }
+    }
+}
+
+pub fn query_delegation(deps: Deps, delegator: String, height: Option<u64>) -> StdResult<Binary> {
+    let delegator = deps.api.addr_validate(&delegator)?;
+    let delegate = match height {
+        Some(height) => DELEGATIONS.may_load_at_height(deps.storage, &delegator, height)?,
+        None => DELEGATIONS.may_load(deps.storage, &delegator)?,
+    };
+    to_json_binary(&DelegationResponse { delegate })
+}
+
+pub fn query_delegators(deps: Deps, delegate: String, height: Option<u64>) -> StdResult<Binary> {
+    let delegate = deps.api.addr_validate(&delegate)?;
+    let delegators = match height {
+        Some(height) => DELEGATORS.may_load_at_height(deps.storage, &delegate, height)?,
+        None => DELEGATORS.may_load(deps.storage, &delegate)?,
+    };
+    to_json_binary(&DelegatorsResponse {
+        delegators: delegators.unwrap_or_default(),
+    })
+}
+
+pub fn query_list_delegations(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let delegations = paginate_snapshot_map(
+        deps,
+        &DELEGATIONS,
+        start_after.as_ref(),
+        limit,
+        Order::Ascending,
+    )?;
+    let delegations: Vec<Delegation> = delegations
+        .into_iter()
+        .map(|(delegator, delegate)| Delegation {
+            delegator,
+            delegate,
+        })
+        .collect();
+    to_json_binary(&delegations)
+}
@@ -0,0 +1,35 @@
+use cosmwasm_std::{Addr, StdError, Uint128};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized.")]
+    Unauthorized {},
+
+    #[error("Voting power may not be delegated to oneself.")]
+    CannotDelegateToSelf {},
+
+    #[error("Voting power is already delegated to ({delegate}).")]
+    AlreadyDelegated { delegate: Addr },
+
+    #[error("Voting power is not delegated.")]
+    NotDelegated {},
+
+    #[error("Delegate ({delegate}) already has the maximum of ({max}) delegators.")]
+    TooManyDelegators { delegate: Addr, max: u32 },
+
+    #[error("A delegate must be allowed at least one delegator.")]
+    ZeroMaxDelegators {},
+
+    #[error("Only members with voting power may delegate.")]
+    ZeroVotingPower {},
+
+    #[error("({delegate}) is not registered as a delegate.")]
+    NotRegistered { delegate: Addr },
+
+    #[error("Delegating to ({delegate}) requires at least ({min}) voting power.")]
+    InsufficientVotingPower { delegate: Addr, min: Uint128 },
+}
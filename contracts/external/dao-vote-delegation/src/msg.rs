@@ -0,0 +1,77 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Uint128};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The DAO the registry belongs to. May update the registry's
+    /// config.
+    pub dao: String,
+    /// The maximum number of delegators a delegate may have.
+    pub max_delegators: u32,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Registers the sender as a delegate, or updates their
+    /// registration. Only registered delegates may be delegated
+    /// to. Addresses with less than `min_delegated_power` voting
+    /// power may not delegate to the sender, so that small holders
+    /// can not fill up the sender's delegator slots.
+    Register {
+        min_delegated_power: Option<Uint128>,
+    },
+    /// Unregisters the sender as a delegate. Existing delegations to
+    /// the sender are kept, but no new ones may be made.
+    Unregister {},
+    /// Delegates the sender's voting power to `delegate`, replacing
+    /// any existing delegation. `delegate` must be registered. The
+    /// sender's own vote on a proposal overrides their delegation for
+    /// that proposal.
+    Delegate { delegate: String },
+    /// Removes the sender's delegation.
+    Undelegate {},
+    /// Callable by the DAO. Updates the maximum number of delegators
+    /// a delegate may have. Delegates with more delegators keep them,
+    /// but may not gain new ones.
+    UpdateConfig { max_delegators: u32 },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Returns the registry's config.
+    #[returns(crate::state::Config)]
+    Config {},
+    /// Returns the registration of `address` as a delegate, or
+    /// `None` if it is not registered.
+    #[returns(Option<crate::state::DelegateInfo>)]
+    Delegate { address: String },
+    /// Returns the address `delegator` had delegated their voting
+    /// power to at `height`, or at the current height if `height` is
+    /// `None`.
+    #[returns(dao_interface::delegation::DelegationResponse)]
+    Delegation {
+        delegator: String,
+        height: Option<u64>,
+    },
+    /// Returns the addresses which had delegated their voting power
+    /// to `delegate` at `height`, or at the current height if
+    /// `height` is `None`.
+    #[returns(dao_interface::delegation::DelegatorsResponse)]
+    Delegators {
+        delegate: String,
+        height: Option<u64>,
+    },
+    /// Lists current delegations, ordered by delegator.
+    #[returns(Vec<Delegation>)]
+    ListDelegations {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct Delegation {
+    pub delegator: Addr,
+    pub delegate: Addr,
+}
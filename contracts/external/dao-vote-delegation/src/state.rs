@@ -0,0 +1,42 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, Map, SnapshotMap, Strategy};
+
+#[cw_serde]
+pub struct Config {
+    /// The DAO the registry belongs to, which may update its config.
+    pub dao: Addr,
+    /// The maximum number of delegators a delegate may have. Bounds
+    /// the gas used by proposal modules to tally a delegate's vote.
+    pub max_delegators: u32,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+#[cw_serde]
+pub struct DelegateInfo {
+    /// The voting power an address must have to delegate to this
+    /// delegate.
+    pub min_delegated_power: Uint128,
+}
+
+/// The addresses which have registered as delegates.
+pub const DELEGATES: Map<&Addr, DelegateInfo> = Map::new("delegates");
+
+/// The delegate of each delegator.
+pub const DELEGATIONS: SnapshotMap<&Addr, Addr> = SnapshotMap::new(
+    "delegations",
+    "delegations__checkpoints",
+    "delegations__changelog",
+    Strategy::EveryBlock,
+);
+
+/// The delegators of each delegate. Kept alongside `DELEGATIONS` so
+/// that a delegate's delegators at a past height may be loaded
+/// without iterating over every delegation.
+pub const DELEGATORS: SnapshotMap<&Addr, Vec<Addr>> = SnapshotMap::new(
+    "delegators",
+    "delegators__checkpoints",
+    "delegators__changelog",
+    Strategy::EveryBlock,
+);
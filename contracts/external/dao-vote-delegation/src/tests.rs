@@ -0,0 +1,464 @@
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
+    Uint128,
+};
+use cw_multi_test::{next_block, App, Contract, ContractWrapper, Executor};
+use dao_interface::{
+    delegation::{DelegationResponse, DelegatorsResponse},
+    voting::VotingPowerAtHeightResponse,
+};
+
+use crate::{
+    msg::{Delegation, ExecuteMsg, InstantiateMsg, QueryMsg},
+    state::{Config, DelegateInfo},
+    ContractError,
+};
+
+/// An address without voting power in the mock DAO.
+const NON_MEMBER: &str = "non_member";
+
+fn dao_instantiate(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> StdResult<Response> {
+    Ok(Response::default())
+}
+
+fn dao_execute(_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty) -> StdResult<Response> {
+    Ok(Response::default())
+}
+
+/// A DAO in which every address other than `NON_MEMBER` has one
+/// voting power.
+fn dao_query(_deps: Deps, env: Env, msg: dao_interface::msg::QueryMsg) -> StdResult<Binary> {
+    match msg {
+        dao_interface::msg::QueryMsg::VotingPowerAtHeight { address, height } => {
+            to_json_binary(&VotingPowerAtHeightResponse {
+                power: if address == NON_MEMBER {
+                    Uint128::zero()
+                } else {
+                    Uint128::one()
+                },
+                height: height.unwrap_or(env.block.height),
+            })
+        }
+        _ => unimplemented!(),
+    }
+}
+
+fn dao_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        dao_execute,
+        dao_instantiate,
+        dao_query,
+    ))
+}
+
+fn delegation_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        crate::contract::execute,
+        crate::contract::instantiate,
+        crate::contract::query,
+    );
+    Box::new(contract)
+}
+
+fn setup(max_delegators: u32) -> (App, Addr, Addr) {
+    let mut app = App::default();
+    let dao_id = app.store_code(dao_contract());
+    let dao = app
+        .instantiate_contract(
+            dao_id,
+            Addr::unchecked("creator"),
+            &Empty {},
+            &[],
+            "dao",
+            None,
+        )
+        .unwrap();
+    let code_id = app.store_code(delegation_contract());
+    let registry = app
+        .instantiate_contract(
+            code_id,
+            dao.clone(),
+            &InstantiateMsg {
+                dao: dao.to_string(),
+                max_delegators,
+            },
+            &[],
+            "delegation",
+            None,
+        )
+        .unwrap();
+    (app, dao, registry)
+}
+
+fn register(app: &mut App, registry: &Addr, delegate: &str, min_delegated_power: Option<u128>) {
+    app.execute_contract(
+        Addr::unchecked(delegate),
+        registry.clone(),
+        &ExecuteMsg::Register {
+            min_delegated_power: min_delegated_power.map(Uint128::new),
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+fn delegate(app: &mut App, registry: &Addr, delegator: &str, delegate: &str) {
+    app.execute_contract(
+        Addr::unchecked(delegator),
+        registry.clone(),
+        &ExecuteMsg::Delegate {
+            delegate: delegate.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+fn query_delegation(
+    app: &App,
+    registry: &Addr,
+    delegator: &str,
+    height: Option<u64>,
+) -> Option<Addr> {
+    let response: DelegationResponse = app
+        .wrap()
+        .query_wasm_smart(
+            registry,
+            &QueryMsg::Delegation {
+                delegator: delegator.to_string(),
+                height,
+            },
+        )
+        .unwrap();
+    response.delegate
+}
+
+fn query_delegators(app: &App, registry: &Addr, delegate: &str, height: Option<u64>) -> Vec<Addr> {
+    let response: DelegatorsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            registry,
+            &QueryMsg::Delegators {
+                delegate: delegate.to_string(),
+                height,
+            },
+        )
+        .unwrap();
+    response.delegators
+}
+
+#[test]
+fn test_delegate() {
+    let (mut app, _dao, registry) = setup(10);
+    register(&mut app, &registry, "carol", None);
+    register(&mut app, &registry, "dave", None);
+
+    let start = app.block_info().height;
+    delegate(&mut app, &registry, "alice", "carol");
+    delegate(&mut app, &registry, "bob", "carol");
+    app.update_block(next_block);
+
+    assert_eq!(
+        query_delegators(&app, &registry, "carol", None),
+        vec![Addr::unchecked("alice"), Addr::unchecked("bob")]
+    );
+    assert_eq!(
+        query_delegation(&app, &registry, "alice", None),
+        Some(Addr::unchecked("carol"))
+    );
+
+    // Delegations take effect from the next block.
+    assert_eq!(
+        query_delegators(&app, &registry, "carol", Some(start)),
+        vec![]
+    );
+    assert_eq!(
+        query_delegation(&app, &registry, "alice", Some(start)),
+        None
+    );
+
+    // Delegating again moves the delegation.
+    let moved = app.block_info().height;
+    delegate(&mut app, &registry, "alice", "dave");
+    app.update_block(next_block);
+    assert_eq!(
+        query_delegators(&app, &registry, "carol", None),
+        vec![Addr::unchecked("bob")]
+    );
+    assert_eq!(
+        query_delegators(&app, &registry, "dave", None),
+        vec![Addr::unchecked("alice")]
+    );
+    assert_eq!(
+        query_delegators(&app, &registry, "carol", Some(moved)),
+        vec![Addr::unchecked("alice"), Addr::unchecked("bob")]
+    );
+
+    let delegations: Vec<Delegation> = app
+        .wrap()
+        .query_wasm_smart(
+            &registry,
+            &QueryMsg::ListDelegations {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        delegations,
+        vec![
+            Delegation {
+                delegator: Addr::unchecked("alice"),
+                delegate: Addr::unchecked("dave"),
+            },
+            Delegation {
+                delegator: Addr::unchecked("bob"),
+                delegate: Addr::unchecked("carol"),
+            },
+        ]
+    );
+
+    app.execute_contract(
+        Addr::unchecked("bob"),
+        registry.clone(),
+        &ExecuteMsg::Undelegate {},
+        &[],
+    )
+    .unwrap();
+    assert_eq!(query_delegation(&app, &registry, "bob", None), None);
+    assert_eq!(query_delegators(&app, &registry, "carol", None), vec![]);
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("bob"),
+            registry,
+            &ExecuteMsg::Undelegate {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NotDelegated {});
+}
+
+#[test]
+fn test_delegate_errors() {
+    let (mut app, dao, registry) = setup(1);
+    register(&mut app, &registry, "carol", None);
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("alice"),
+            registry.clone(),
+            &ExecuteMsg::Delegate {
+                delegate: "alice".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::CannotDelegateToSelf {});
+
+    // Only registered delegates may be delegated to.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("alice"),
+            registry.clone(),
+            &ExecuteMsg::Delegate {
+                delegate: "dave".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::NotRegistered {
+            delegate: Addr::unchecked("dave")
+        }
+    );
+
+    // Addresses without voting power may not take up delegator slots.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(NON_MEMBER),
+            registry.clone(),
+            &ExecuteMsg::Delegate {
+                delegate: "carol".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ZeroVotingPower {});
+
+    delegate(&mut app, &registry, "alice", "carol");
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("alice"),
+            registry.clone(),
+            &ExecuteMsg::Delegate {
+                delegate: "carol".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::AlreadyDelegated {
+            delegate: Addr::unchecked("carol")
+        }
+    );
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("bob"),
+            registry.clone(),
+            &ExecuteMsg::Delegate {
+                delegate: "carol".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::TooManyDelegators {
+            delegate: Addr::unchecked("carol"),
+            max: 1
+        }
+    );
+
+    // Only the DAO may raise the limit.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("bob"),
+            registry.clone(),
+            &ExecuteMsg::UpdateConfig { max_delegators: 2 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    app.execute_contract(
+        dao,
+        registry.clone(),
+        &ExecuteMsg::UpdateConfig { max_delegators: 2 },
+        &[],
+    )
+    .unwrap();
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(&registry, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.max_delegators, 2);
+
+    delegate(&mut app, &registry, "bob", "carol");
+}
+
+#[test]
+fn test_register() {
+    let (mut app, _dao, registry) = setup(10);
+
+    // Every member of the mock DAO has one voting power, so none may
+    // delegate to a delegate asking for two.
+    register(&mut app, &registry, "carol", Some(2));
+    let info: Option<DelegateInfo> = app
+        .wrap()
+        .query_wasm_smart(
+            &registry,
+            &QueryMsg::Delegate {
+                address: "carol".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        info,
+        Some(DelegateInfo {
+            min_delegated_power: Uint128::new(2)
+        })
+    );
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("alice"),
+            registry.clone(),
+            &ExecuteMsg::Delegate {
+                delegate: "carol".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::InsufficientVotingPower {
+            delegate: Addr::unchecked("carol"),
+            min: Uint128::new(2)
+        }
+    );
+
+    register(&mut app, &registry, "carol", Some(1));
+    delegate(&mut app, &registry, "alice", "carol");
+
+    // Unregistering keeps existing delegations but prevents new ones.
+    app.execute_contract(
+        Addr::unchecked("carol"),
+        registry.clone(),
+        &ExecuteMsg::Unregister {},
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_delegation(&app, &registry, "alice", None),
+        Some(Addr::unchecked("carol"))
+    );
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("bob"),
+            registry.clone(),
+            &ExecuteMsg::Delegate {
+                delegate: "carol".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::NotRegistered {
+            delegate: Addr::unchecked("carol")
+        }
+    );
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("carol"),
+            registry,
+            &ExecuteMsg::Unregister {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::NotRegistered {
+            delegate: Addr::unchecked("carol")
+        }
+    );
+}
@@ -99,6 +99,8 @@ pub fn execute_propose(
             description,
             msgs,
             proposer: Some(info.sender.to_string()),
+            // An amendment would bypass the approval.
+            immutable: true,
         },
     };
 
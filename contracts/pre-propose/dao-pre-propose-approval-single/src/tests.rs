@@ -14,7 +14,7 @@ use dao_voting::{
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
     status::Status,
     threshold::{PercentageThreshold, Threshold},
-    voting::Vote,
+    voting::{Vote, VoteWeighting},
 };
 
 use crate::state::{Proposal, ProposalStatus};
@@ -78,6 +78,9 @@ fn get_default_proposal_module_instantiate(
         },
         close_proposal_on_execution_failure: false,
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
+        objection_threshold: None,
+        review_period: None,
     }
 }
 
@@ -1364,6 +1367,9 @@ fn test_instantiate_with_zero_native_deposit() {
             },
             close_proposal_on_execution_failure: false,
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
+            objection_threshold: None,
+            review_period: None,
         }
     };
 
@@ -1429,6 +1435,9 @@ fn test_instantiate_with_zero_cw20_deposit() {
             },
             close_proposal_on_execution_failure: false,
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
+            objection_threshold: None,
+            review_period: None,
         }
     };
 
@@ -1710,3 +1719,48 @@ fn test_withdraw() {
     let balance = get_balance_native(&app, core_addr.as_str(), "ujuno");
     assert_eq!(balance, Uint128::new(30));
 }
+
+#[test]
+fn test_approved_proposal_not_amendable() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, None, false);
+
+    let pre_propose_id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    let id = approve_proposal(&mut app, pre_propose, "approver", pre_propose_id);
+
+    // The proposer may not replace the messages of an approved
+    // proposal, even before it has been voted on.
+    let err: dao_proposal_single::ContractError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            proposal_single.clone(),
+            &dao_proposal_single::msg::ExecuteMsg::Amend {
+                proposal_id: id,
+                title: None,
+                description: None,
+                msgs: Some(vec![cosmwasm_std::BankMsg::Send {
+                    to_address: "ekez".to_string(),
+                    amount: coins(10, "ujuno"),
+                }
+                .into()]),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, dao_proposal_single::ContractError::NotAmendable { id });
+
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &dao_proposal_single::msg::QueryMsg::Proposal { proposal_id: id },
+        )
+        .unwrap();
+    assert!(proposal.proposal.msgs.is_empty());
+}
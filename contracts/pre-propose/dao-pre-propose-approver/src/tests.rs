@@ -21,7 +21,7 @@ use dao_voting::{
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
     status::Status,
     threshold::{PercentageThreshold, Threshold},
-    voting::Vote,
+    voting::{Vote, VoteWeighting},
 };
 
 use crate::contract::{CONTRACT_NAME, CONTRACT_VERSION};
@@ -105,6 +105,9 @@ fn get_proposal_module_approval_single_instantiate(
         },
         close_proposal_on_execution_failure: false,
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
+        objection_threshold: None,
+        review_period: None,
     }
 }
 
@@ -138,6 +141,9 @@ fn get_proposal_module_approver_instantiate(
         },
         close_proposal_on_execution_failure: false,
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
+        objection_threshold: None,
+        review_period: None,
     }
 }
 
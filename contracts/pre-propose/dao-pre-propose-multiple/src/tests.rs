@@ -19,6 +19,7 @@ use dao_voting::{
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
     status::Status,
     threshold::PercentageThreshold,
+    voting::VoteWeighting,
 };
 
 use crate::contract::*;
@@ -78,6 +79,7 @@ fn get_default_proposal_module_instantiate(
         },
         close_proposal_on_execution_failure: false,
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     }
 }
 
@@ -1067,6 +1069,7 @@ fn test_instantiate_with_zero_native_deposit() {
             },
             close_proposal_on_execution_failure: false,
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
         }
     };
 
@@ -1130,6 +1133,7 @@ fn test_instantiate_with_zero_cw20_deposit() {
             },
             close_proposal_on_execution_failure: false,
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
         }
     };
 
@@ -82,6 +82,7 @@ pub fn execute(
                 title,
                 description,
                 msgs,
+                immutable: false,
             }),
         },
         ExecuteMsg::Extension { msg } => ExecuteInternal::Extension { msg },
@@ -14,7 +14,7 @@ use dao_voting::{
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
     status::Status,
     threshold::{PercentageThreshold, Threshold},
-    voting::Vote,
+    voting::{Vote, VoteWeighting},
 };
 use dps::query::ProposalResponse;
 
@@ -76,6 +76,9 @@ fn get_default_proposal_module_instantiate(
         },
         close_proposal_on_execution_failure: false,
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
+        objection_threshold: None,
+        review_period: None,
     }
 }
 
@@ -1003,6 +1006,9 @@ fn test_instantiate_with_zero_native_deposit() {
             },
             close_proposal_on_execution_failure: false,
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
+            objection_threshold: None,
+            review_period: None,
         }
     };
 
@@ -1066,6 +1072,9 @@ fn test_instantiate_with_zero_cw20_deposit() {
             },
             close_proposal_on_execution_failure: false,
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
+            objection_threshold: None,
+            review_period: None,
         }
     };
 
@@ -2,7 +2,7 @@
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     to_json_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply, Response,
-    StdResult, Storage, SubMsg, WasmMsg,
+    StdError, StdResult, Storage, SubMsg, WasmMsg,
 };
 
 use cw2::set_contract_version;
@@ -16,6 +16,7 @@ use dao_hooks::vote::new_vote_hooks;
 use dao_interface::voting::IsActiveResponse;
 use dao_voting::veto::{VetoConfig, VetoError};
 use dao_voting::{
+    delegation::{tally_delegated_vote, DelegatedVote},
     multiple_choice::{
         MultipleChoiceOptions, MultipleChoiceVote, MultipleChoiceVotes, VotingStrategy,
     },
@@ -25,7 +26,7 @@ use dao_voting::{
         failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id, TaggedReplyId,
     },
     status::Status,
-    voting::{get_total_power, get_voting_power, validate_voting_period},
+    voting::{get_total_power, get_voting_power, validate_voting_period, VoteWeighting},
 };
 
 use crate::{msg::MigrateMsg, state::CREATION_POLICY};
@@ -34,7 +35,8 @@ use crate::{
     proposal::{MultipleChoiceProposal, VoteResult},
     query::{ProposalListResponse, ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
     state::{
-        Ballot, Config, BALLOTS, CONFIG, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS,
+        Ballot, Config, BALLOTS, CONFIG, DELEGATED_BALLOTS, DELEGATION_MODULE, PROPOSALS,
+        PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS,
     },
     ContractError,
 };
@@ -76,6 +78,7 @@ pub fn instantiate(
         dao,
         close_proposal_on_execution_failure: msg.close_proposal_on_execution_failure,
         veto: msg.veto,
+        vote_weighting: msg.vote_weighting,
     };
 
     // Initialize proposal count to zero so that queries return zero
@@ -129,6 +132,7 @@ pub fn execute(
             dao,
             close_proposal_on_execution_failure,
             veto,
+            vote_weighting,
         } => execute_update_config(
             deps,
             info,
@@ -140,6 +144,7 @@ pub fn execute(
             dao,
             close_proposal_on_execution_failure,
             veto,
+            vote_weighting,
         ),
         ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
             execute_update_proposal_creation_policy(deps, info, new_info)
@@ -158,6 +163,9 @@ pub fn execute(
             proposal_id,
             rationale,
         } => execute_update_rationale(deps, info, proposal_id, rationale),
+        ExecuteMsg::UpdateDelegationModule { module } => {
+            execute_update_delegation_module(deps, info, module)
+        }
     }
 }
 
@@ -229,6 +237,7 @@ pub fn execute_propose(
             allow_revoting: config.allow_revoting,
             choices: checked_multiple_choice_options,
             veto: config.veto,
+            vote_weighting: config.vote_weighting,
         };
         // Update the proposal's status. Addresses case where proposal
         // expires on the same block as it is created.
@@ -345,7 +354,7 @@ pub fn execute_veto(
 }
 
 pub fn execute_vote(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     proposal_id: u64,
@@ -373,15 +382,44 @@ pub fn execute_vote(
         return Err(ContractError::Expired { id: proposal_id });
     }
 
-    let vote_power = get_voting_power(
-        deps.as_ref(),
-        info.sender.clone(),
+    // Delegators who have not voted themselves have their voting
+    // power cast by their delegate.
+    let registry = DELEGATION_MODULE.may_load(deps.storage)?;
+    let DelegatedVote {
+        own_weight,
+        vote_power,
+        total_power_reduction,
+        overridden_delegate,
+    } = tally_delegated_vote(
+        deps.branch(),
+        &BALLOTS,
+        &DELEGATED_BALLOTS,
+        registry.as_ref(),
         &config.dao,
-        Some(prop.start_height),
+        &info.sender,
+        proposal_id,
+        prop.start_height,
+        prop.vote_weighting,
     )?;
     if vote_power.is_zero() {
         return Err(ContractError::NotRegistered {});
     }
+    prop.total_power = prop
+        .total_power
+        .checked_sub(total_power_reduction)
+        .map_err(StdError::overflow)?;
+
+    // If a delegate's ballot is carrying the sender's voting power,
+    // the sender's vote overrides it.
+    if let Some(delegate) = overridden_delegate {
+        let mut ballot = BALLOTS.load(deps.storage, (proposal_id, &delegate))?;
+        ballot.power = ballot
+            .power
+            .checked_sub(own_weight)
+            .map_err(StdError::overflow)?;
+        prop.votes.remove_vote(ballot.vote, own_weight)?;
+        BALLOTS.save(deps.storage, (proposal_id, &delegate), &ballot)?;
+    }
 
     BALLOTS.update(deps.storage, (proposal_id, &info.sender), |bal| match bal {
         Some(current_ballot) => {
@@ -612,6 +650,7 @@ pub fn execute_update_config(
     dao: String,
     close_proposal_on_execution_failure: bool,
     veto: Option<VetoConfig>,
+    vote_weighting: VoteWeighting,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -643,6 +682,7 @@ pub fn execute_update_config(
             dao,
             close_proposal_on_execution_failure,
             veto,
+            vote_weighting,
         },
     )?;
 
@@ -785,6 +825,35 @@ pub fn execute_remove_vote_hook(
         .add_attribute("address", address))
 }
 
+pub fn execute_update_delegation_module(
+    deps: DepsMut,
+    info: MessageInfo,
+    module: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    match module {
+        Some(module) => {
+            let module = deps.api.addr_validate(&module)?;
+            DELEGATION_MODULE.save(deps.storage, &module)?;
+        }
+        None => DELEGATION_MODULE.remove(deps.storage),
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "update_delegation_module")
+        .add_attribute("sender", info.sender)
+        .add_attribute(
+            "module",
+            DELEGATION_MODULE
+                .may_load(deps.storage)?
+                .map_or_else(|| "_none".to_string(), Addr::into_string),
+        ))
+}
+
 pub fn add_hook(
     hooks: Hooks,
     storage: &mut dyn Storage,
@@ -842,6 +911,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ProposalHooks {} => to_json_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
         QueryMsg::VoteHooks {} => to_json_binary(&VOTE_HOOKS.query_hooks(deps)?),
         QueryMsg::Dao {} => query_dao(deps),
+        QueryMsg::DelegationModule {} => to_json_binary(&DELEGATION_MODULE.may_load(deps.storage)?),
     }
 }
 
@@ -1021,6 +1091,8 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
             };
             Ok(Response::new().add_attribute("failed_prepropose_hook", format!("{addr}")))
         }
+        // This module does not send amendment hooks.
+        TaggedReplyId::FailedAmendmentHook(_) => Err(ContractError::InvalidReplyID { id: msg.id }),
     }
 }
 
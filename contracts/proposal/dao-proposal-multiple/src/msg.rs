@@ -5,6 +5,7 @@ use dao_voting::{
     multiple_choice::{MultipleChoiceOptions, MultipleChoiceVote, VotingStrategy},
     pre_propose::PreProposeInfo,
     veto::VetoConfig,
+    voting::VoteWeighting,
 };
 
 #[cw_serde]
@@ -44,6 +45,11 @@ pub struct InstantiateMsg {
     /// During this period an oversight account (`veto.vetoer`) can
     /// veto the proposal.
     pub veto: Option<VetoConfig>,
+    /// How votes are weighted by voting power. Quadratic weighting
+    /// counts the integer square root of a voter's voting power.
+    /// Defaults to linear weighting.
+    #[serde(default)]
+    pub vote_weighting: VoteWeighting,
 }
 
 #[cw_serde]
@@ -131,6 +137,9 @@ pub enum ExecuteMsg {
         /// Optional time delay on proposal execution, during which the
         /// proposal may be vetoed.
         veto: Option<VetoConfig>,
+        /// How votes are weighted by voting power. This will only
+        /// apply to proposals created after the config update.
+        vote_weighting: VoteWeighting,
     },
     /// Updates the sender's rationale for their vote on the specified
     /// proposal. Errors if no vote vote has been cast.
@@ -155,6 +164,14 @@ pub enum ExecuteMsg {
     RemoveVoteHook {
         address: String,
     },
+    /// Sets the vote delegation registry used by this module. While
+    /// set, a vote carries the voting power of the voter's
+    /// delegators at the proposal's start height, unless those
+    /// delegators vote themselves. `None` disables delegated
+    /// voting. Only the DAO may call this method.
+    UpdateDelegationModule {
+        module: Option<String>,
+    },
 }
 
 #[proposal_module_query]
@@ -202,6 +219,10 @@ pub enum QueryMsg {
     /// Lists all of the consumers of vote hooks for this module.
     #[returns(::cw_hooks::HooksResponse)]
     VoteHooks {},
+    /// Gets the vote delegation registry used by this module, if
+    /// any.
+    #[returns(::std::option::Option<::cosmwasm_std::Addr>)]
+    DelegationModule {},
 }
 
 #[cw_serde]
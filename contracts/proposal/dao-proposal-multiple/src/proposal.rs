@@ -9,7 +9,7 @@ use dao_voting::{
     },
     status::Status,
     veto::VetoConfig,
-    voting::does_vote_count_pass,
+    voting::{does_vote_count_pass, VoteWeighting},
 };
 
 use crate::query::ProposalResponse;
@@ -51,6 +51,12 @@ pub struct MultipleChoiceProposal {
     /// Optional veto configuration. If set to `None`, veto option
     /// is disabled. Otherwise contains the configuration for veto flow.
     pub veto: Option<VetoConfig>,
+    /// How votes on this proposal are weighted by voting power. In
+    /// quadratic mode `total_power` is reduced as voters are counted
+    /// so that it remains an upper bound on the votes that may be
+    /// cast.
+    #[serde(default)]
+    pub vote_weighting: VoteWeighting,
 }
 
 pub enum VoteResult {
@@ -336,6 +342,7 @@ mod tests {
             allow_revoting,
             min_voting_period: None,
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
         }
     }
 
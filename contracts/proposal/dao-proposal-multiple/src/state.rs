@@ -8,6 +8,7 @@ use dao_voting::{
     multiple_choice::{MultipleChoiceVote, VotingStrategy},
     pre_propose::ProposalCreationPolicy,
     veto::VetoConfig,
+    voting::VoteWeighting,
 };
 
 /// The proposal module's configuration.
@@ -47,6 +48,10 @@ pub struct Config {
     /// Optional veto configuration. If set to `None`, veto option
     /// is disabled. Otherwise contains the configuration for veto flow.
     pub veto: Option<VetoConfig>,
+    /// How votes are weighted by voting power. Configs saved before
+    /// this field existed deserialize to linear weighting.
+    #[serde(default)]
+    pub vote_weighting: VoteWeighting,
 }
 
 // Each ballot stores a chosen vote and corresponding voting power and rationale.
@@ -72,3 +77,9 @@ pub const VOTE_HOOKS: Hooks = Hooks::new("vote_hooks");
 /// The address of the pre-propose module associated with this
 /// proposal module (if any).
 pub const CREATION_POLICY: Item<ProposalCreationPolicy> = Item::new("creation_policy");
+/// The vote delegation registry used by this module, if any.
+pub const DELEGATION_MODULE: Item<Addr> = Item::new("delegation_module");
+/// Maps a proposal and a delegator to the delegate whose ballot
+/// carries the delegator's voting power on that proposal. Removed
+/// once the delegator votes themselves.
+pub const DELEGATED_BALLOTS: Map<(u64, &Addr), Addr> = Map::new("delegated_ballots");
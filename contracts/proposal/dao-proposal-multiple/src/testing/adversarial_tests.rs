@@ -20,6 +20,7 @@ use dao_voting::{
     },
     status::Status,
     threshold::PercentageThreshold,
+    voting::VoteWeighting,
 };
 
 struct CommonTest {
@@ -283,6 +284,7 @@ pub fn test_allow_voting_after_proposal_execution_pre_expiration_cw20() {
         ),
         close_proposal_on_execution_failure: true,
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     let core_addr = instantiate_with_multiple_staked_balances_governance(
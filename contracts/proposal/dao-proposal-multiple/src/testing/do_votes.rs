@@ -11,6 +11,7 @@ use dao_voting::{
     },
     status::Status,
     threshold::PercentageThreshold,
+    voting::VoteWeighting,
 };
 use rand::{prelude::SliceRandom, Rng};
 use std::panic;
@@ -131,6 +132,7 @@ where
         close_proposal_on_execution_failure: true,
         pre_propose_info,
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     let governance_addr = setup_governance(&mut app, instantiate, Some(initial_balances));
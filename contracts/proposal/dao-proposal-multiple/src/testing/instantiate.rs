@@ -14,6 +14,7 @@ use dao_voting::{
     multiple_choice::VotingStrategy,
     pre_propose::PreProposeInfo,
     threshold::{ActiveThreshold, ActiveThreshold::AbsoluteCount, PercentageThreshold},
+    voting::VoteWeighting,
 };
 use dao_voting_cw4::msg::GroupContract;
 
@@ -68,6 +69,7 @@ pub fn _get_default_token_dao_proposal_module_instantiate(app: &mut App) -> Inst
         ),
         close_proposal_on_execution_failure: true,
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     }
 }
 
@@ -85,6 +87,7 @@ fn _get_default_non_token_dao_proposal_module_instantiate(app: &mut App) -> Inst
         pre_propose_info: get_pre_propose_info(app, None, false),
         close_proposal_on_execution_failure: true,
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     }
 }
 
@@ -22,6 +22,7 @@ use dao_voting::{
     pre_propose::PreProposeInfo,
     status::Status,
     threshold::{ActiveThreshold, PercentageThreshold, Threshold},
+    voting::VoteWeighting,
 };
 use std::ops::Add;
 use std::panic;
@@ -129,6 +130,7 @@ fn test_propose() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -145,6 +147,7 @@ fn test_propose() {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
     assert_eq!(config, expected);
 
@@ -186,6 +189,7 @@ fn test_propose() {
         allow_revoting: false,
         min_voting_period: None,
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     assert_eq!(created.proposal, expected);
@@ -211,6 +215,7 @@ fn test_propose_wrong_num_choices() {
         voting_strategy: voting_strategy.clone(),
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -227,6 +232,7 @@ fn test_propose_wrong_num_choices() {
         dao: core_addr,
         voting_strategy,
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
     assert_eq!(config, expected);
 
@@ -289,6 +295,7 @@ fn test_proposal_count_initialized_to_zero() {
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
     let core_addr = instantiate_with_staked_balances_governance(&mut app, msg, None);
 
@@ -324,6 +331,7 @@ fn test_no_early_pass_with_min_duration() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -419,6 +427,7 @@ fn test_propose_with_messages() {
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -456,6 +465,7 @@ fn test_propose_with_messages() {
         allow_revoting: false,
         dao: "dao".to_string(),
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     let wasm_msg = WasmMsg::Execute {
@@ -540,6 +550,7 @@ fn test_min_duration_units_missmatch() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
     instantiate_with_staked_balances_governance(
         &mut app,
@@ -573,6 +584,7 @@ fn test_min_duration_larger_than_proposal_duration() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
     instantiate_with_staked_balances_governance(
         &mut app,
@@ -605,6 +617,7 @@ fn test_min_duration_same_as_proposal_duration() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -728,6 +741,7 @@ fn test_voting_module_token_proposal_deposit_instantiate() {
             false,
         ),
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -804,6 +818,7 @@ fn test_different_token_proposal_deposit() {
             false,
         ),
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -866,6 +881,7 @@ fn test_bad_token_proposal_deposit() {
             false,
         ),
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -899,6 +915,7 @@ fn test_take_proposal_deposit() {
             false,
         ),
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     let core_addr = instantiate_with_cw20_balances_governance(
@@ -1006,6 +1023,7 @@ fn test_take_native_proposal_deposit() {
             false,
         ),
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     let core_addr = instantiate_with_native_staked_balances_governance(
@@ -1099,6 +1117,7 @@ fn test_native_proposal_deposit() {
             false,
         ),
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -1529,6 +1548,7 @@ fn test_cant_propose_zero_power() {
             false,
         ),
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     let core_addr = instantiate_with_cw20_balances_governance(
@@ -1696,6 +1716,7 @@ fn test_cant_execute_not_member() {
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -1787,6 +1808,7 @@ fn test_cant_execute_not_member_when_proposal_created() {
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -1905,6 +1927,7 @@ fn test_open_proposal_submission() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: get_pre_propose_info(&mut app, None, true),
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
     let govmod = query_multiple_proposal_module(&app, &core_addr);
@@ -1974,6 +1997,7 @@ fn test_open_proposal_submission() {
             vote_weights: vec![Uint128::zero(); 3],
         },
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     assert_eq!(created.proposal, expected);
@@ -2206,6 +2230,7 @@ fn test_execute_expired_proposal() {
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -2369,6 +2394,7 @@ fn test_update_config() {
             allow_revoting: false,
             dao: dao.to_string(),
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
         },
         &[],
     )
@@ -2389,6 +2415,7 @@ fn test_update_config() {
             allow_revoting: false,
             dao: Addr::unchecked(CREATOR_ADDR).to_string(),
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
         },
         &[],
     )
@@ -2407,6 +2434,7 @@ fn test_update_config() {
         allow_revoting: false,
         dao: Addr::unchecked(CREATOR_ADDR),
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
     assert_eq!(govmod_config, expected);
 
@@ -2426,6 +2454,7 @@ fn test_update_config() {
             allow_revoting: false,
             dao: Addr::unchecked(CREATOR_ADDR).to_string(),
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
         },
         &[],
     )
@@ -2504,6 +2533,7 @@ fn test_query_list_proposals() {
         voting_strategy: voting_strategy.clone(),
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
     let gov_addr = instantiate_with_staked_balances_governance(
         &mut app,
@@ -2585,6 +2615,7 @@ fn test_query_list_proposals() {
             allow_revoting: false,
             min_voting_period: None,
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
         },
     };
     assert_eq!(proposals_forward.proposals[0], expected);
@@ -2614,6 +2645,7 @@ fn test_query_list_proposals() {
             allow_revoting: false,
             min_voting_period: None,
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
         },
     };
     assert_eq!(proposals_forward.proposals[0], expected);
@@ -2640,6 +2672,7 @@ fn test_hooks() {
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -2767,6 +2800,7 @@ fn test_active_threshold_absolute() {
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     let core_addr = instantiate_with_staking_active_threshold(
@@ -2895,6 +2929,7 @@ fn test_active_threshold_percent() {
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     // 20% needed to be active, 20% of 100000000 is 20000000
@@ -3024,6 +3059,7 @@ fn test_active_threshold_none() {
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     let core_addr =
@@ -3135,6 +3171,7 @@ fn test_revoting() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
         },
         Some(vec![
             Cw20Coin {
@@ -3268,6 +3305,7 @@ fn test_allow_revoting_config_changes() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
         },
         Some(vec![
             Cw20Coin {
@@ -3326,6 +3364,7 @@ fn test_allow_revoting_config_changes() {
             },
             close_proposal_on_execution_failure: false,
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
         },
         &[],
     )
@@ -3421,6 +3460,7 @@ fn test_revoting_same_vote_twice() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
         },
         Some(vec![
             Cw20Coin {
@@ -3516,6 +3556,7 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
         },
         Some(vec![
             Cw20Coin {
@@ -3710,6 +3751,7 @@ fn test_close_failed_proposal() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     let core_addr = instantiate_with_staking_active_threshold(&mut app, instantiate, None, None);
@@ -3845,6 +3887,7 @@ fn test_close_failed_proposal() {
                                     dao: original.dao.to_string(),
                                     close_proposal_on_execution_failure: false,
                                     veto: None,
+                                    vote_weighting: VoteWeighting::Linear {},
                                 })
                                 .unwrap(),
                                 funds: vec![],
@@ -3962,6 +4005,7 @@ fn test_no_double_refund_on_execute_fail_and_close() {
             false,
         ),
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
 
     let core_addr = instantiate_with_staking_active_threshold(
@@ -4141,6 +4185,7 @@ pub fn test_not_allow_voting_on_expired_proposal() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
     };
     let core_addr = instantiate_with_staked_balances_governance(
         &mut app,
@@ -4233,6 +4278,7 @@ fn test_next_proposal_id() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
         },
         Some(vec![
             Cw20Coin {
@@ -4305,6 +4351,7 @@ fn test_vote_with_rationale() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
         },
         Some(vec![
             Cw20Coin {
@@ -4402,6 +4449,7 @@ fn test_revote_with_rationale() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
         },
         Some(vec![
             Cw20Coin {
@@ -4557,6 +4605,7 @@ fn test_update_rationale() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
         },
         Some(vec![
             Cw20Coin {
@@ -4695,6 +4744,7 @@ fn test_open_proposal_passes_with_zero_timelock_veto_duration() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            vote_weighting: VoteWeighting::Linear {},
         },
         Some(vec![
             Cw20Coin {
@@ -4803,6 +4853,7 @@ fn test_veto_non_existing_prop_id() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            vote_weighting: VoteWeighting::Linear {},
         },
         Some(vec![
             Cw20Coin {
@@ -4849,6 +4900,7 @@ fn test_veto_with_no_veto_configuration() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
         },
         Some(vec![
             Cw20Coin {
@@ -4940,6 +4992,7 @@ fn test_veto_open_prop_with_veto_before_passed_disabled() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            vote_weighting: VoteWeighting::Linear {},
         },
         Some(vec![
             Cw20Coin {
@@ -5047,6 +5100,7 @@ fn test_veto_when_veto_timelock_expired() -> anyhow::Result<()> {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            vote_weighting: VoteWeighting::Linear {},
         },
         Some(vec![
             Cw20Coin {
@@ -5161,6 +5215,7 @@ fn test_veto_sets_prop_status_to_vetoed() -> anyhow::Result<()> {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            vote_weighting: VoteWeighting::Linear {},
         },
         Some(vec![
             Cw20Coin {
@@ -5271,6 +5326,7 @@ fn test_veto_from_catchall_state() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            vote_weighting: VoteWeighting::Linear {},
         },
         Some(vec![
             Cw20Coin {
@@ -5391,6 +5447,7 @@ fn test_veto_timelock_early_execute_happy() -> anyhow::Result<()> {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            vote_weighting: VoteWeighting::Linear {},
         },
         Some(vec![
             Cw20Coin {
@@ -5514,6 +5571,7 @@ fn test_veto_timelock_expires_happy() -> anyhow::Result<()> {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            vote_weighting: VoteWeighting::Linear {},
         },
         Some(vec![
             Cw20Coin {
@@ -5626,6 +5684,7 @@ fn test_veto_only_members_execute_proposal() -> anyhow::Result<()> {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            vote_weighting: VoteWeighting::Linear {},
         },
         Some(vec![
             Cw20Coin {
@@ -5729,3 +5788,328 @@ fn test_veto_only_members_execute_proposal() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_delegated_voting() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        max_voting_period: Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        pre_propose_info: get_pre_propose_info(&mut app, None, false),
+        veto: None,
+        vote_weighting: VoteWeighting::Linear {},
+    };
+    let core_addr = instantiate_with_cw20_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(10),
+            },
+            Cw20Coin {
+                address: "delegate".to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "delegator".to_string(),
+                amount: Uint128::new(3),
+            },
+        ]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let registry_id = app.store_code(dao_testing::contracts::dao_vote_delegation_contract());
+    let registry = app
+        .instantiate_contract(
+            registry_id,
+            core_addr.clone(),
+            &dao_vote_delegation::msg::InstantiateMsg {
+                dao: core_addr.to_string(),
+                max_delegators: 10,
+            },
+            &[],
+            "registry",
+            None,
+        )
+        .unwrap();
+
+    // Only the DAO may set the delegation module.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            govmod.clone(),
+            &ExecuteMsg::UpdateDelegationModule {
+                module: Some(registry.to_string()),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    app.execute_contract(
+        core_addr,
+        govmod.clone(),
+        &ExecuteMsg::UpdateDelegationModule {
+            module: Some(registry.to_string()),
+        },
+        &[],
+    )
+    .unwrap();
+    let module: Option<Addr> = app
+        .wrap()
+        .query_wasm_smart(&govmod, &QueryMsg::DelegationModule {})
+        .unwrap();
+    assert_eq!(module, Some(registry.clone()));
+
+    app.execute_contract(
+        Addr::unchecked("delegate"),
+        registry.clone(),
+        &dao_vote_delegation::msg::ExecuteMsg::Register {
+            min_delegated_power: None,
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked("delegator"),
+        registry,
+        &dao_vote_delegation::msg::ExecuteMsg::Delegate {
+            delegate: "delegate".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+
+    let options = vec![
+        MultipleChoiceOption {
+            description: "multiple choice option 1".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+        },
+        MultipleChoiceOption {
+            description: "multiple choice option 2".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+        },
+    ];
+    let proposal_id = make_proposal(
+        &mut app,
+        &govmod,
+        CREATOR_ADDR,
+        MultipleChoiceOptions { options },
+    );
+
+    let vote = |app: &mut App, voter: &str, option_id: u32| {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id,
+                vote: MultipleChoiceVote { option_id },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    };
+
+    vote(&mut app, "delegate", 0);
+    let ballot: VoteResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &govmod,
+            &QueryMsg::GetVote {
+                proposal_id,
+                voter: "delegate".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(ballot.vote.unwrap().power, Uint128::new(4));
+    let proposal = query_proposal(&app, &govmod, proposal_id);
+    assert_eq!(proposal.proposal.votes.vote_weights[0], Uint128::new(4));
+
+    // A delegator's own vote overrides their delegate's.
+    vote(&mut app, "delegator", 1);
+    let ballot: VoteResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &govmod,
+            &QueryMsg::GetVote {
+                proposal_id,
+                voter: "delegate".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(ballot.vote.unwrap().power, Uint128::new(1));
+    let proposal = query_proposal(&app, &govmod, proposal_id);
+    assert_eq!(proposal.proposal.votes.vote_weights[0], Uint128::new(1));
+    assert_eq!(proposal.proposal.votes.vote_weights[1], Uint128::new(3));
+}
+
+#[test]
+fn test_quadratic_voting() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        max_voting_period: Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        pre_propose_info: get_pre_propose_info(&mut app, None, false),
+        veto: None,
+        vote_weighting: VoteWeighting::Quadratic {},
+    };
+    let core_addr = instantiate_with_cw20_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(49),
+            },
+            Cw20Coin {
+                address: "whale".to_string(),
+                amount: Uint128::new(100),
+            },
+            Cw20Coin {
+                address: "delegator".to_string(),
+                amount: Uint128::new(36),
+            },
+        ]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+    let config = query_proposal_config(&app, &govmod);
+    assert_eq!(config.vote_weighting, VoteWeighting::Quadratic {});
+
+    // Delegated voting power is weighted per delegator.
+    let registry_id = app.store_code(dao_testing::contracts::dao_vote_delegation_contract());
+    let registry = app
+        .instantiate_contract(
+            registry_id,
+            core_addr.clone(),
+            &dao_vote_delegation::msg::InstantiateMsg {
+                dao: core_addr.to_string(),
+                max_delegators: 10,
+            },
+            &[],
+            "registry",
+            None,
+        )
+        .unwrap();
+    app.execute_contract(
+        core_addr,
+        govmod.clone(),
+        &ExecuteMsg::UpdateDelegationModule {
+            module: Some(registry.to_string()),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        registry.clone(),
+        &dao_vote_delegation::msg::ExecuteMsg::Register {
+            min_delegated_power: None,
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked("delegator"),
+        registry,
+        &dao_vote_delegation::msg::ExecuteMsg::Delegate {
+            delegate: CREATOR_ADDR.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+
+    let options = vec![
+        MultipleChoiceOption {
+            description: "multiple choice option 1".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+        },
+        MultipleChoiceOption {
+            description: "multiple choice option 2".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+        },
+    ];
+    let proposal_id = make_proposal(
+        &mut app,
+        &govmod,
+        CREATOR_ADDR,
+        MultipleChoiceOptions { options },
+    );
+    let proposal = query_proposal(&app, &govmod, proposal_id);
+    assert_eq!(
+        proposal.proposal.vote_weighting,
+        VoteWeighting::Quadratic {}
+    );
+    assert_eq!(proposal.proposal.total_power, Uint128::new(185));
+
+    let vote = |app: &mut App, voter: &str, option_id: u32| {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id,
+                vote: MultipleChoiceVote { option_id },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    };
+    let ballot_power = |app: &App, voter: &str| -> Uint128 {
+        let ballot: VoteResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &govmod,
+                &QueryMsg::GetVote {
+                    proposal_id,
+                    voter: voter.to_string(),
+                },
+            )
+            .unwrap();
+        ballot.vote.unwrap().power
+    };
+
+    // 7 for the creator and 6 for their delegator.
+    vote(&mut app, CREATOR_ADDR, 1);
+    assert_eq!(ballot_power(&app, CREATOR_ADDR), Uint128::new(13));
+    let proposal = query_proposal(&app, &govmod, proposal_id);
+    assert_eq!(proposal.proposal.total_power, Uint128::new(113));
+    assert_eq!(proposal.proposal.status, Status::Open);
+
+    // Everyone has been counted, so option 2 passes with 13 of 23.
+    vote(&mut app, "whale", 0);
+    assert_eq!(ballot_power(&app, "whale"), Uint128::new(10));
+    let proposal = query_proposal(&app, &govmod, proposal_id);
+    assert_eq!(proposal.proposal.total_power, Uint128::new(23));
+    assert_eq!(proposal.proposal.status, Status::Passed);
+
+    // A delegator voting themselves moves their weight, but does not
+    // change the proposal's total power.
+    vote(&mut app, "delegator", 1);
+    assert_eq!(ballot_power(&app, CREATOR_ADDR), Uint128::new(7));
+    assert_eq!(ballot_power(&app, "delegator"), Uint128::new(6));
+    let proposal = query_proposal(&app, &govmod, proposal_id);
+    assert_eq!(proposal.proposal.total_power, Uint128::new(23));
+    assert_eq!(proposal.proposal.votes.vote_weights[1], Uint128::new(13));
+}
@@ -1,10 +1,13 @@
-use crate::msg::{ProposalPayload, SingleChoiceInstantProposalMsg as ProposeMsg, VoteSignature};
+use crate::msg::{
+    KeyType, ProposalPayload, SignedPayload, SignedVote,
+    SingleChoiceInstantProposalMsg as ProposeMsg, VoteSignature, MAX_PAYLOAD_MSGS,
+};
 use bech32::ToBase32;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Order, Reply, Response,
-    StdResult, Storage, SubMsg, Uint128, WasmMsg,
+    to_json_binary, to_json_string, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Order, Reply,
+    Response, StdResult, Storage, SubMsg, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
 use cw4::MemberListResponse;
@@ -29,27 +32,32 @@ use dao_voting::voting::{get_total_power, get_voting_power, validate_voting_peri
 use dao_voting_cw4::msg::QueryMsg::GroupContract;
 use ripemd::{Digest as RipDigest, Ripemd160};
 use sha2::Sha256;
-use std::collections::HashMap;
+use sha3::Keccak256;
+use std::collections::HashSet;
 use std::convert::TryInto;
 
 use crate::msg::MigrateMsg;
 use crate::proposal::{next_proposal_id, SingleChoiceProposal};
-use crate::state::{Config, CREATION_POLICY, NONCES};
+use crate::state::{Config, CREATION_POLICY, NONCES, NONCE_EXPIRATIONS, PAYLOADS};
 use crate::v1_state::{
     v1_duration_to_v2, v1_expiration_to_v2, v1_status_to_v2, v1_threshold_to_v2, v1_votes_to_v2,
 };
 use crate::{
-    error::ContractError,
+    error::{ContractError, SignatureRejection},
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::advance_proposal_id,
     query::ProposalListResponse,
-    query::{ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
+    query::{
+        NonceInfo, NonceListResponse, ProposalResponse, VoteInfo, VoteListResponse, VoteResponse,
+    },
     state::{Ballot, BALLOTS, CONFIG, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS},
 };
 use cw_proposal_single_v1 as v1;
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-proposal-single-instant";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const ED25519_PUBKEY_LEN: usize = 32;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -75,6 +83,8 @@ pub fn instantiate(
         veto_config.validate(&deps.as_ref(), &max_voting_period)?;
     };
 
+    validate_bech32_prefix(&msg.bech32_prefix)?;
+
     let config = Config {
         threshold: msg.threshold,
         max_voting_period,
@@ -84,6 +94,8 @@ pub fn instantiate(
         allow_revoting: msg.allow_revoting,
         close_proposal_on_execution_failure: msg.close_proposal_on_execution_failure,
         veto: msg.veto,
+        bech32_prefix: msg.bech32_prefix,
+        allow_legacy_signatures: msg.allow_legacy_signatures,
     };
 
     // Initialize proposal count to zero so that queries return zero
@@ -121,11 +133,49 @@ pub fn execute(
             payload,
             proposer,
             vote_signatures,
+            false,
+        ),
+        ExecuteMsg::ProposeForSignatures(ProposeMsg {
+            title,
+            description,
+            payload,
+            proposer,
+            vote_signatures,
+        }) => execute_propose(
+            deps,
+            env,
+            info,
+            title,
+            description,
+            payload,
+            proposer,
+            vote_signatures,
+            true,
+        ),
+        ExecuteMsg::SubmitSignatures {
+            proposal_id,
+            signatures,
+        } => execute_submit_signatures(deps, env, info, proposal_id, signatures),
+        ExecuteMsg::Vote {
+            proposal_id,
+            vote,
+            rationale,
+        } => proposal_vote(
+            deps,
+            env,
+            info.clone(),
+            proposal_id,
+            info.sender,
+            vote,
+            rationale,
         ),
         ExecuteMsg::UpdateRationale {
             proposal_id,
             rationale,
         } => execute_update_rationale(deps, info, proposal_id, rationale),
+        ExecuteMsg::Execute { proposal_id } => proposal_execute(deps, env, info, proposal_id),
+        ExecuteMsg::Veto { proposal_id } => execute_veto(deps, env, info, proposal_id),
+        ExecuteMsg::PruneNonces { nonces } => execute_prune_nonces(deps, env, nonces),
         ExecuteMsg::Close { proposal_id } => execute_close(deps, env, info, proposal_id),
         ExecuteMsg::UpdateConfig {
             threshold,
@@ -136,6 +186,8 @@ pub fn execute(
             dao,
             close_proposal_on_execution_failure,
             veto,
+            bech32_prefix,
+            allow_legacy_signatures,
         } => execute_update_config(
             deps,
             info,
@@ -147,6 +199,8 @@ pub fn execute(
             dao,
             close_proposal_on_execution_failure,
             veto,
+            bech32_prefix,
+            allow_legacy_signatures,
         ),
         ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
             execute_update_proposal_creation_policy(deps, info, new_info)
@@ -164,6 +218,7 @@ pub fn execute(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn execute_propose(
     mut deps: DepsMut,
     env: Env,
@@ -173,6 +228,7 @@ pub fn execute_propose(
     payload: ProposalPayload,
     proposer: Option<String>,
     vote_signatures: Vec<VoteSignature>,
+    collect_signatures: bool,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
@@ -187,9 +243,18 @@ pub fn execute_propose(
         return Err(ContractError::NotEnoughMsgs {});
     }
 
-    // MVP Limitation: Supporting only one msg per proposal
-    if payload.msgs.len() > 1 {
-        return Err(ContractError::TooManyMsgs {});
+    // Bound the number of messages executed atomically by a single
+    // proposal. The byte size of the proposal is checked below.
+    let msgs_count = payload.msgs.len() as u64;
+    if msgs_count > MAX_PAYLOAD_MSGS {
+        return Err(ContractError::TooManyMsgs {
+            count: msgs_count,
+            max: MAX_PAYLOAD_MSGS,
+        });
+    }
+
+    if payload_expired(&env, &payload) {
+        return Err(ContractError::PayloadExpired {});
     }
 
     // Verify nonce and save it in one storage access
@@ -197,6 +262,9 @@ pub fn execute_propose(
         Some(_) => Err(ContractError::NonceAlreadyUsed {}),
         None => Ok(true),
     })?;
+    if let Some(expiration) = payload.expiration {
+        NONCE_EXPIRATIONS.save(deps.storage, &payload.nonce, &expiration)?;
+    }
 
     // Determine the appropriate proposer. If this is coming from our
     // pre-propose module, it must be specified. Otherwise, the
@@ -281,117 +349,511 @@ pub fn execute_propose(
 
     let hooks = new_proposal_hooks(PROPOSAL_HOOKS, deps.storage, id, proposer.as_str())?;
 
-    // Init empty message hash majority counts, this will be filled with message hashes and their accrued voting power
-    let mut message_hash_counts: HashMap<Vec<u8>, Uint128> = HashMap::new();
+    if collect_signatures {
+        PAYLOADS.save(deps.storage, id, &payload)?;
+    }
 
-    // sum vote counts based on member weight / voting power
-    for vote_signature in &vote_signatures {
-        let voter_address = derive_addr_from_pubkey(&vote_signature.public_key, "osmo").unwrap();
+    let cast = cast_signature_votes(
+        deps.branch(),
+        &env,
+        &info,
+        &config,
+        id,
+        &payload,
+        &vote_signatures,
+    )?;
 
-        let vote_power = get_voting_power(
-            deps.as_ref(),
-            Addr::unchecked(voter_address),
-            &config.dao,
-            Some(proposal.start_height),
-        )?;
+    // Instant proposals are executed if they pass with the signatures
+    // they are created with, and fail if they are rejected. Otherwise
+    // they are left open for on-chain votes, or in their veto
+    // timelock to be executed or vetoed once it ends. Proposals
+    // collecting signatures stay open until they pass or expire.
+    let p_execute = if !collect_signatures {
+        if cast.votes == 0 {
+            return Err(ContractError::ThresholdError(
+                ThresholdError::UnreachableThreshold {},
+            ));
+        }
+        let mut proposal = PROPOSALS.load(deps.storage, id)?;
+        proposal.update_status(&env.block)?;
+        match proposal.status {
+            Status::Passed => proposal_execute(deps.branch(), env.clone(), info.clone(), id)?,
+            Status::Open | Status::VetoTimelock { .. } => Response::default(),
+            _ => return Err(ContractError::NotPassed {}),
+        }
+    } else {
+        execute_if_passed(deps.branch(), env.clone(), info.clone(), id)?
+    };
+
+    // Report the proposal's status after its votes are cast and it is
+    // executed.
+    let mut proposal = PROPOSALS.load(deps.storage, id)?;
+    proposal.update_status(&env.block)?;
+
+    Ok(add_rejected_signatures(
+        Response::default()
+            .add_attributes(cast.response.attributes)
+            .add_submessages(cast.response.messages)
+            .add_attributes(p_execute.attributes)
+            .add_submessages(p_execute.messages)
+            .add_submessages(hooks)
+            .add_attribute("action", "propose")
+            .add_attribute("sender", info.sender)
+            .add_attribute("proposal_id", id.to_string())
+            .add_attribute("status", proposal.status.to_string()),
+        cast.rejected,
+    ))
+}
 
-        *message_hash_counts
-            .entry(vote_signature.message_hash.clone())
-            .or_insert(Uint128::zero()) += vote_power;
+pub fn execute_submit_signatures(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    vote_signatures: Vec<VoteSignature>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut proposal = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+    let payload = PAYLOADS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NotCollectingSignatures { id: proposal_id })?;
+    if payload_expired(&env, &payload) {
+        return Err(ContractError::PayloadExpired {});
     }
 
-    // Validate that message_hash_counts contains at least one key with value > 0
-    if !message_hash_counts
-        .values()
-        .any(|&value| value > Uint128::zero())
-    {
-        return Err(ContractError::ThresholdError(
-            ThresholdError::UnreachableThreshold {},
-        ));
+    if proposal.expiration.is_expired(&env.block) {
+        return Err(ContractError::Expired { id: proposal_id });
+    }
+    proposal.update_status(&env.block)?;
+    if proposal.status != Status::Open {
+        return Err(ContractError::NotOpen { id: proposal_id });
+    }
+
+    let cast = cast_signature_votes(
+        deps.branch(),
+        &env,
+        &info,
+        &config,
+        proposal_id,
+        &payload,
+        &vote_signatures,
+    )?;
+    let p_execute = execute_if_passed(deps.branch(), env.clone(), info.clone(), proposal_id)?;
+
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+    proposal.update_status(&env.block)?;
+
+    Ok(add_rejected_signatures(
+        Response::default()
+            .add_attributes(cast.response.attributes)
+            .add_submessages(cast.response.messages)
+            .add_attributes(p_execute.attributes)
+            .add_submessages(p_execute.messages)
+            .add_attribute("action", "submit_signatures")
+            .add_attribute("sender", info.sender)
+            .add_attribute("proposal_id", proposal_id.to_string())
+            .add_attribute("status", proposal.status.to_string()),
+        cast.rejected,
+    ))
+}
+
+/// Returns true if `payload` may no longer be proposed or voted on.
+fn payload_expired(env: &Env, payload: &ProposalPayload) -> bool {
+    payload
+        .expiration
+        .is_some_and(|expiration| expiration.is_expired(&env.block))
+}
+
+pub fn execute_prune_nonces(
+    deps: DepsMut,
+    env: Env,
+    nonces: Vec<String>,
+) -> Result<Response, ContractError> {
+    let mut pruned = 0u64;
+    for nonce in nonces {
+        let expired = NONCE_EXPIRATIONS
+            .may_load(deps.storage, &nonce)?
+            .is_some_and(|expiration| expiration.is_expired(&env.block));
+        if expired {
+            NONCES.remove(deps.storage, &nonce);
+            NONCE_EXPIRATIONS.remove(deps.storage, &nonce);
+            pruned += 1;
+        }
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "prune_nonces")
+        .add_attribute("pruned", pruned.to_string()))
+}
+
+/// The votes cast from a batch of vote signatures.
+struct CastSignatures {
+    /// The attributes and messages of the votes cast.
+    response: Response,
+    /// The number of votes cast.
+    votes: usize,
+    /// The index of each signature that was not counted, and why.
+    rejected: Vec<(usize, SignatureRejection)>,
+}
+
+/// Verifies `vote_signatures` against `payload` and casts a vote on
+/// the proposal for each signature that is counted.
+fn cast_signature_votes(
+    mut deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    config: &Config,
+    proposal_id: u64,
+    payload: &ProposalPayload,
+    vote_signatures: &[VoteSignature],
+) -> Result<CastSignatures, ContractError> {
+    let start_height = PROPOSALS.load(deps.storage, proposal_id)?.start_height;
+
+    // Configs migrated from a version without a bech32 prefix can not
+    // derive voter addresses until one is set.
+    if !vote_signatures.is_empty() && config.bech32_prefix.is_empty() {
+        return Err(ContractError::MissingBech32Prefix {});
     }
 
-    let mut p_vote_attributes = vec![];
-    let mut p_vote_messages = vec![];
+    let mut rejected_signatures: Vec<(usize, SignatureRejection)> = vec![];
+    // Signatures which passed verification, along with their
+    // signer's address and vote.
+    let mut accepted_signatures: Vec<(Addr, &VoteSignature, Vote)> = vec![];
+    // Signers are deduplicated by address so that a signer is counted
+    // at most once, no matter how many times their signature appears.
+    let mut signers: HashSet<Addr> = HashSet::new();
+
+    let verified_signatures =
+        verify_messages(deps.as_ref(), vote_signatures, &config.bech32_prefix);
+
+    let signed_payload = create_signed_payload(env, payload)?;
+    let legacy_payload = if config.allow_legacy_signatures {
+        Some(to_json_string(payload)?)
+    } else {
+        None
+    };
 
-    // verify and cast votes
-    for vote_signature in &vote_signatures {
-        let (address, verified) = verify_message(deps.branch(), vote_signature)?;
+    // verify signatures, membership and uniqueness of signers
+    for (index, (vote_signature, verified)) in
+        vote_signatures.iter().zip(verified_signatures).enumerate()
+    {
+        let address = match verified {
+            Ok(address) => address,
+            Err(rejection) => {
+                rejected_signatures.push((index, rejection));
+                continue;
+            }
+        };
         let voter_address = deps.api.addr_validate(address.as_str())?;
 
+        let vote = match signed_vote(
+            env,
+            payload,
+            &signed_payload,
+            legacy_payload.as_deref(),
+            &voter_address,
+            vote_signature,
+        )? {
+            Some(vote) => vote,
+            None => {
+                rejected_signatures.push((index, SignatureRejection::VoteMismatch {}));
+                continue;
+            }
+        };
+
+        // Signers may also have voted in an earlier submission.
+        if !signers.insert(voter_address.clone())
+            || BALLOTS.has(deps.storage, (proposal_id, &voter_address))
+        {
+            rejected_signatures.push((index, SignatureRejection::DuplicateSigner {}));
+            continue;
+        }
+
         // Checking if the current voter is a member with voting power higher than 0
         let voting_power = get_voting_power(
             deps.as_ref(),
             voter_address.clone(),
             &config.dao,
-            Some(proposal.start_height),
+            Some(start_height),
         )?;
-        let is_member = voting_power != Uint128::zero();
-
-        // Match the message_hash wrapped by ADR36 SignDoc and signer address
-        let proposal_payload_adr36 =
-            create_adr36_message(&serde_json_wasm::to_string(&payload).unwrap(), &address);
-        let proposal_payload_hash = compute_sha256_hash(&proposal_payload_adr36.as_bytes());
-
-        // If Signature has been verified and a Member address has been found
-        if verified && is_member {
-            // Compute yes or no vote based on majority previous computed.
-            let vote = Some(if vote_signature.message_hash == proposal_payload_hash {
-                Vote::Yes
-            } else {
-                Vote::No
-            });
-
-            // Call proposal_vote only if vote_option is not None
-            if let Some(vote) = vote {
-                let mut p_vote = proposal_vote(
-                    deps.branch(),
-                    env.clone(),
-                    info.clone(),
-                    id,
-                    voter_address,
-                    vote,
-                    None, // rationale hardcoded to None
-                )?;
-                p_vote_attributes.append(p_vote.attributes.as_mut());
-                p_vote_messages.append(p_vote.messages.as_mut());
-            }
-        } else {
-            // Do nothing, skip this iteration and continue. We didn't recognize the address on members list.
+        if voting_power.is_zero() {
+            rejected_signatures.push((index, SignatureRejection::NonMember {}));
             continue;
         }
+
+        accepted_signatures.push((voter_address, vote_signature, vote));
     }
 
-    let p_execute = proposal_execute(deps.branch(), env, info.clone(), id)?;
+    let votes = accepted_signatures.len();
+    let mut response = Response::default();
 
-    Ok(Response::default()
-        .add_attributes(p_vote_attributes)
-        .add_submessages(p_vote_messages)
-        .add_attributes(p_execute.attributes)
-        .add_submessages(p_execute.messages)
-        .add_submessages(hooks)
-        .add_attribute("action", "propose")
-        .add_attribute("sender", info.sender)
-        .add_attribute("proposal_id", id.to_string())
-        .add_attribute("status", proposal.status.to_string()))
+    // cast votes
+    for (voter_address, vote_signature, vote) in accepted_signatures {
+        let p_vote = proposal_vote(
+            deps.branch(),
+            env.clone(),
+            info.clone(),
+            proposal_id,
+            voter_address,
+            vote,
+            vote_signature.rationale.clone(),
+        )?;
+        response = response
+            .add_attributes(p_vote.attributes)
+            .add_submessages(p_vote.messages);
+    }
+
+    Ok(CastSignatures {
+        response,
+        votes,
+        rejected: rejected_signatures,
+    })
+}
+
+/// Executes the proposal if it has passed and the sender may execute
+/// it. A passed proposal the sender may not execute is left to be
+/// executed with `Execute`. Otherwise it is left open for more
+/// signatures to be submitted.
+fn execute_if_passed(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+    proposal.update_status(&env.block)?;
+    let config = CONFIG.load(deps.storage)?;
+    if proposal.status == Status::Passed
+        && sender_can_execute(deps.as_ref(), &config, &info.sender, proposal.start_height)?
+    {
+        proposal_execute(deps, env, info, proposal_id)
+    } else {
+        Ok(Response::default())
+    }
+}
+
+/// Reports the signatures that were not counted in `response`'s
+/// attributes.
+fn add_rejected_signatures(
+    response: Response,
+    rejected_signatures: Vec<(usize, SignatureRejection)>,
+) -> Response {
+    response
+        .add_attribute("rejected_signatures", rejected_signatures.len().to_string())
+        .add_attributes(
+            rejected_signatures
+                .into_iter()
+                .map(|(index, rejection)| ("rejected_signature", format!("{index}:{rejection}"))),
+        )
+}
+
+/// Returns the vote cast by `vote_signature` on `payload`, or `None`
+/// if its `message_hash` was not computed for this payload. A `No`
+/// vote is only counted when the signer explicitly signed a `No` vote
+/// on this payload, so that signatures of other documents can not be
+/// replayed as votes against it. `signed_payload` and
+/// `legacy_payload` are the documents signed by implicit `Yes` votes.
+/// As those documents do not cover a rationale, implicit `Yes` votes
+/// which carry one are not counted.
+pub fn signed_vote(
+    env: &Env,
+    payload: &ProposalPayload,
+    signed_payload: &str,
+    legacy_payload: Option<&str>,
+    voter_address: &Addr,
+    vote_signature: &VoteSignature,
+) -> StdResult<Option<Vote>> {
+    // Match the message_hash wrapped by ADR36 SignDoc and signer address
+    let signed_hash = |data: &str| {
+        let adr36_message = create_adr36_message(&data.to_string(), &voter_address.to_string());
+        hash_sign_doc(vote_signature.key_type, adr36_message.as_bytes())
+    };
+
+    if let Some(vote) = vote_signature.vote {
+        let signed_vote = create_signed_vote(env, payload, vote, vote_signature.rationale.clone())?;
+        return Ok((vote_signature.message_hash == signed_hash(&signed_vote)).then_some(vote));
+    }
+
+    if vote_signature.rationale.is_some() {
+        return Ok(None);
+    }
+    let signed_proposal = vote_signature.message_hash == signed_hash(signed_payload)
+        || legacy_payload.is_some_and(|legacy| vote_signature.message_hash == signed_hash(legacy));
+
+    Ok(signed_proposal.then_some(Vote::Yes))
+}
+
+/// Verifies `vote_signatures`, returning for each of them either the
+/// address of its signer or the reason it was rejected. ed25519
+/// signatures are verified together with `ed25519_batch_verify`,
+/// falling back to verifying them one by one if the batch contains
+/// an invalid signature.
+pub fn verify_messages(
+    deps: Deps,
+    vote_signatures: &[VoteSignature],
+    hrp: &str,
+) -> Vec<Result<String, SignatureRejection>> {
+    let ed25519_signatures: Vec<&VoteSignature> = vote_signatures
+        .iter()
+        .filter(|vote_signature| {
+            vote_signature.key_type == KeyType::Ed25519
+                && vote_signature.public_key.len() == ED25519_PUBKEY_LEN
+        })
+        .collect();
+    let ed25519_batch_verified = !ed25519_signatures.is_empty()
+        && deps
+            .api
+            .ed25519_batch_verify(
+                &ed25519_signatures
+                    .iter()
+                    .map(|vote_signature| vote_signature.message_hash.as_slice())
+                    .collect::<Vec<_>>(),
+                &ed25519_signatures
+                    .iter()
+                    .map(|vote_signature| vote_signature.signature.as_slice())
+                    .collect::<Vec<_>>(),
+                &ed25519_signatures
+                    .iter()
+                    .map(|vote_signature| vote_signature.public_key.as_slice())
+                    .collect::<Vec<_>>(),
+            )
+            .unwrap_or(false);
+
+    vote_signatures
+        .iter()
+        .map(|vote_signature| {
+            if ed25519_batch_verified && vote_signature.key_type == KeyType::Ed25519 {
+                derive_ed25519_addr_from_pubkey(&vote_signature.public_key, hrp)
+                    .map_err(|_| SignatureRejection::BadKeyLength {})
+            } else {
+                verify_message(deps, vote_signature, hrp)
+            }
+        })
+        .collect()
 }
 
 // This is veryfing the signature for a given publicKey and messageHash.
 // In the context of this contract, is assumed that the signature is generated from an ADR36 compliant message
-fn verify_message(
-    deps: DepsMut,
+// Returns the address of the signer, or the reason the signature was rejected.
+pub fn verify_message(
+    deps: Deps,
     vote_signature: &VoteSignature,
-) -> Result<(String, bool), ContractError> {
-    let voter_address = derive_addr_from_pubkey(&vote_signature.public_key, "osmo")?;
-    let verified = deps
-        .api
-        .secp256k1_verify(
-            vote_signature.message_hash.as_slice(),
-            vote_signature.signature.as_slice(),
-            vote_signature.public_key.as_slice(),
-        )
-        .unwrap();
+    hrp: &str,
+) -> Result<String, SignatureRejection> {
+    let VoteSignature {
+        message_hash,
+        signature,
+        public_key,
+        key_type,
+        ..
+    } = vote_signature;
+
+    // The prefix is validated when the config is set, so deriving
+    // an address may only fail because of the public key.
+    let (voter_address, verified) = match key_type {
+        KeyType::Secp256k1 => {
+            let voter_address = derive_addr_from_pubkey(public_key, hrp)
+                .map_err(|_| SignatureRejection::BadKeyLength {})?;
+            let verified = deps
+                .api
+                .secp256k1_verify(message_hash, signature, public_key)
+                .unwrap_or(false);
+            (voter_address, verified)
+        }
+        KeyType::EthSecp256k1 => {
+            if public_key.len() != 33 && public_key.len() != 65 {
+                return Err(SignatureRejection::BadKeyLength {});
+            }
+            let recovered = recover_eth_pubkey(deps, message_hash, signature)?;
+            let voter_address = derive_eth_addr_from_pubkey(&recovered, hrp)
+                .map_err(|_| SignatureRejection::BadSignature {})?;
+            // The signature is valid if it was produced by the
+            // provided key, in either its compressed or uncompressed
+            // form.
+            let verified = if public_key.len() == 33 {
+                compress_secp256k1_pubkey(&recovered) == *public_key
+            } else {
+                recovered == *public_key
+            };
+            (voter_address, verified)
+        }
+        KeyType::Ed25519 => {
+            let voter_address = derive_ed25519_addr_from_pubkey(public_key, hrp)
+                .map_err(|_| SignatureRejection::BadKeyLength {})?;
+            let verified = deps
+                .api
+                .ed25519_verify(message_hash, signature, public_key)
+                .unwrap_or(false);
+            (voter_address, verified)
+        }
+    };
+    if !verified {
+        return Err(SignatureRejection::BadSignature {});
+    }
+
+    Ok(voter_address)
+}
+
+/// Recovers the uncompressed public key which produced an Ethereum
+/// style signature, i.e. a 64 byte signature followed by a recovery
+/// id.
+fn recover_eth_pubkey(
+    deps: Deps,
+    message_hash: &[u8],
+    signature: &[u8],
+) -> Result<Vec<u8>, SignatureRejection> {
+    let (recovery_id, signature) = signature
+        .split_last()
+        .ok_or(SignatureRejection::BadSignature {})?;
+    if signature.len() != 64 {
+        return Err(SignatureRejection::BadSignature {});
+    }
+    // Ethereum signatures may encode the recovery id as 27 or 28.
+    let recovery_param = match recovery_id {
+        0 | 1 => *recovery_id,
+        27 | 28 => recovery_id - 27,
+        _ => return Err(SignatureRejection::BadSignature {}),
+    };
+    deps.api
+        .secp256k1_recover_pubkey(message_hash, signature, recovery_param)
+        .map_err(|_| SignatureRejection::BadSignature {})
+}
+
+/// Compresses an uncompressed (65 byte) secp256k1 public key.
+fn compress_secp256k1_pubkey(pub_key: &[u8]) -> Vec<u8> {
+    let y_is_odd = pub_key[64] & 1 == 1;
+    let mut compressed = vec![if y_is_odd { 0x03 } else { 0x02 }];
+    compressed.extend_from_slice(&pub_key[1..33]);
+    compressed
+}
 
-    Ok((voter_address, verified))
+/// Returns the JSON document voters sign for `payload`, which binds it
+/// to this chain and proposal module.
+pub fn create_signed_payload(env: &Env, payload: &ProposalPayload) -> StdResult<String> {
+    to_json_string(&SignedPayload {
+        chain_id: env.block.chain_id.clone(),
+        contract_address: env.contract.address.to_string(),
+        payload: payload.clone(),
+    })
+}
+
+/// Returns the JSON document voters sign to cast `vote` on `payload`,
+/// bound to this chain and proposal module.
+pub fn create_signed_vote(
+    env: &Env,
+    payload: &ProposalPayload,
+    vote: Vote,
+    rationale: Option<String>,
+) -> StdResult<String> {
+    to_json_string(&SignedVote {
+        chain_id: env.block.chain_id.clone(),
+        contract_address: env.contract.address.to_string(),
+        payload: payload.clone(),
+        vote,
+        rationale,
+    })
 }
 
 pub fn create_adr36_message(data: &String, signer: &String) -> String {
@@ -410,7 +872,27 @@ pub fn compute_sha256_hash(message: &[u8]) -> Vec<u8> {
     hasher.finalize().to_vec()
 }
 
+pub fn compute_keccak256_hash(message: &[u8]) -> Vec<u8> {
+    Keccak256::digest(message).to_vec()
+}
+
+/// Hashes a signed document the way keys of `key_type` do before
+/// signing it.
+pub fn hash_sign_doc(key_type: KeyType, message: &[u8]) -> Vec<u8> {
+    match key_type {
+        KeyType::Secp256k1 | KeyType::Ed25519 => compute_sha256_hash(message),
+        KeyType::EthSecp256k1 => compute_keccak256_hash(message),
+    }
+}
+
 pub fn derive_addr_from_pubkey(pub_key: &[u8], hrp: &str) -> Result<String, ContractError> {
+    // Compressed or uncompressed secp256k1 public keys.
+    if pub_key.len() != 33 && pub_key.len() != 65 {
+        return Err(ContractError::InvalidPublicKeyLength {
+            length: pub_key.len() as u64,
+        });
+    }
+
     let sha_hash: [u8; 32] = Sha256::digest(pub_key)
         .as_slice()
         .try_into()
@@ -419,9 +901,54 @@ pub fn derive_addr_from_pubkey(pub_key: &[u8], hrp: &str) -> Result<String, Cont
     let rip_hash = Ripemd160::digest(sha_hash);
     let rip_slice: &[u8] = rip_hash.as_slice();
 
-    let addr: String = bech32::encode(hrp, rip_slice.to_base32(), bech32::Variant::Bech32)
-        .map_err(|_| ContractError::VerificationFailed {})?;
-    Ok(addr)
+    encode_bech32_addr(rip_slice, hrp)
+}
+
+/// Derives the address of an Ethereum style secp256k1 key from its
+/// uncompressed (65 byte) form.
+pub fn derive_eth_addr_from_pubkey(pub_key: &[u8], hrp: &str) -> Result<String, ContractError> {
+    if pub_key.len() != 65 || pub_key[0] != 0x04 {
+        return Err(ContractError::InvalidPublicKeyLength {
+            length: pub_key.len() as u64,
+        });
+    }
+
+    let keccak_hash = Keccak256::digest(&pub_key[1..]);
+    encode_bech32_addr(&keccak_hash[12..], hrp)
+}
+
+pub fn derive_ed25519_addr_from_pubkey(pub_key: &[u8], hrp: &str) -> Result<String, ContractError> {
+    if pub_key.len() != ED25519_PUBKEY_LEN {
+        return Err(ContractError::InvalidPublicKeyLength {
+            length: pub_key.len() as u64,
+        });
+    }
+
+    let sha_hash = Sha256::digest(pub_key);
+    encode_bech32_addr(&sha_hash[..20], hrp)
+}
+
+fn encode_bech32_addr(data: &[u8], hrp: &str) -> Result<String, ContractError> {
+    bech32::encode(hrp, data.to_base32(), bech32::Variant::Bech32)
+        .map_err(|_| ContractError::VerificationFailed {})
+}
+
+/// Checks that `prefix` may be used as the human readable part of a
+/// bech32 address.
+pub fn validate_bech32_prefix(prefix: &str) -> Result<(), ContractError> {
+    // Lowercase is enforced as addresses are always derived in
+    // lowercase and bech32 forbids mixed case strings.
+    if prefix.is_empty() || prefix.to_lowercase() != prefix {
+        return Err(ContractError::InvalidBech32Prefix {
+            prefix: prefix.to_string(),
+        });
+    }
+    bech32::encode(prefix, [0u8; 20].to_base32(), bech32::Variant::Bech32).map_err(|_| {
+        ContractError::InvalidBech32Prefix {
+            prefix: prefix.to_string(),
+        }
+    })?;
+    Ok(())
 }
 
 fn proposal_vote(
@@ -533,6 +1060,45 @@ fn proposal_vote(
         .add_attribute("status", prop.status.to_string()))
 }
 
+/// Returns true if `sender` may execute a passed proposal which
+/// started at `start_height`.
+fn sender_can_execute(
+    deps: Deps,
+    config: &Config,
+    sender: &Addr,
+    start_height: u64,
+) -> StdResult<bool> {
+    if !config.only_members_execute {
+        return Ok(true);
+    }
+
+    // Get dao-voting-cw4 contract address
+    let dao_voting_cw4_addr: Addr = deps.querier.query_wasm_smart(
+        config.dao.clone(),
+        &dao_interface::msg::QueryMsg::VotingModule {},
+    )?;
+
+    // Get cw4-group contract address
+    let cw4_group_addr: Addr = deps
+        .querier
+        .query_wasm_smart(dao_voting_cw4_addr, &GroupContract {})?;
+
+    // Get list of members
+    let members: MemberListResponse = deps.querier.query_wasm_smart(
+        cw4_group_addr,
+        &ListMembers {
+            start_after: None,
+            limit: None,
+        },
+    )?;
+
+    let is_member = members.members.iter().any(|member| member.addr == *sender);
+    let power = get_voting_power(deps, sender.clone(), &config.dao, Some(start_height))?;
+
+    // Proposer should be a member and voting weight should be zero
+    Ok(is_member && power.is_zero())
+}
+
 fn proposal_execute(
     deps: DepsMut,
     env: Env,
@@ -546,42 +1112,8 @@ fn proposal_execute(
     let config = CONFIG.load(deps.storage)?;
 
     // determine if this sender can execute
-    let mut sender_can_execute = true;
-    if config.only_members_execute {
-        // Get dao-voting-cw4 contract address
-        let dao_voting_cw4_addr: Addr = deps.querier.query_wasm_smart(
-            config.dao.clone(),
-            &dao_interface::msg::QueryMsg::VotingModule {},
-        )?;
-
-        // Get cw4-group contract address
-        let cw4_group_addr: Addr = deps
-            .querier
-            .query_wasm_smart(dao_voting_cw4_addr, &GroupContract {})?;
-
-        // Get list of members
-        let members: MemberListResponse = deps.querier.query_wasm_smart(
-            cw4_group_addr.clone(),
-            &ListMembers {
-                start_after: None,
-                limit: None,
-            },
-        )?;
-
-        let is_member = members
-            .members
-            .iter()
-            .any(|member| member.addr == info.sender);
-        let power = get_voting_power(
-            deps.as_ref(),
-            info.sender.clone(),
-            &config.dao,
-            Some(prop.start_height),
-        )?;
-
-        // Proposer should be a member and voting weight should be zero
-        sender_can_execute = is_member && power.is_zero();
-    }
+    let sender_can_execute =
+        sender_can_execute(deps.as_ref(), &config, &info.sender, prop.start_height)?;
 
     // Check here that the proposal is passed or timelocked.
     // Allow it to be executed even if it is expired so long
@@ -829,6 +1361,8 @@ pub fn execute_update_config(
     dao: String,
     close_proposal_on_execution_failure: bool,
     veto: Option<VetoConfig>,
+    bech32_prefix: String,
+    allow_legacy_signatures: bool,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -847,6 +1381,8 @@ pub fn execute_update_config(
         veto_config.validate(&deps.as_ref(), &max_voting_period)?;
     };
 
+    validate_bech32_prefix(&bech32_prefix)?;
+
     CONFIG.save(
         deps.storage,
         &Config {
@@ -858,6 +1394,8 @@ pub fn execute_update_config(
             dao,
             close_proposal_on_execution_failure,
             veto,
+            bech32_prefix,
+            allow_legacy_signatures,
         },
     )?;
 
@@ -1017,6 +1555,11 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ProposalCreationPolicy {} => query_creation_policy(deps),
         QueryMsg::ProposalHooks {} => to_json_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
         QueryMsg::VoteHooks {} => to_json_binary(&VOTE_HOOKS.query_hooks(deps)?),
+        QueryMsg::IsNonceUsed { nonce } => to_json_binary(&NONCES.has(deps.storage, &nonce)),
+        QueryMsg::ListNonces { start_after, limit } => query_list_nonces(deps, start_after, limit),
+        QueryMsg::Payload { proposal_id } => {
+            to_json_binary(&PAYLOADS.may_load(deps.storage, proposal_id)?)
+        }
     }
 }
 
@@ -1129,6 +1672,27 @@ pub fn query_list_votes(
     to_json_binary(&VoteListResponse { votes })
 }
 
+pub fn query_list_nonces(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let min = start_after.as_ref().map(Bound::<&String>::exclusive);
+
+    let nonces = NONCES
+        .keys(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|nonce| {
+            let nonce = nonce?;
+            let expiration = NONCE_EXPIRATIONS.may_load(deps.storage, &nonce)?;
+            Ok(NonceInfo { nonce, expiration })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&NonceListResponse { nonces })
+}
+
 pub fn query_info(deps: Deps) -> StdResult<Binary> {
     let info = cw2::get_contract_version(deps.storage)?;
     to_json_binary(&dao_interface::voting::InfoResponse { info })
@@ -1144,6 +1708,7 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
             close_proposal_on_execution_failure,
             pre_propose_info,
             veto,
+            bech32_prefix,
         } => {
             // `CONTRACT_VERSION` here is from the data section of the
             // blob we are migrating to. `version` is from storage. If
@@ -1161,6 +1726,8 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                 veto_config.validate(&deps.as_ref(), &max_voting_period)?;
             };
 
+            validate_bech32_prefix(&bech32_prefix)?;
+
             // Update the stored config to have the new
             // `close_proposal_on_execution_failure` field.
             CONFIG.save(
@@ -1174,6 +1741,9 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                     dao: current_config.dao.clone(),
                     close_proposal_on_execution_failure,
                     veto,
+                    bech32_prefix,
+                    // v1 modules have no signers to migrate.
+                    allow_legacy_signatures: false,
                 },
             )?;
 
@@ -1231,9 +1801,23 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                 .add_attribute("from", "v1")
                 .add_submessages(pre_propose_messages))
         }
-        MigrateMsg::FromCompatible {} => Ok(Response::default()
-            .add_attribute("action", "migrate")
-            .add_attribute("from", "compatible")),
+        MigrateMsg::FromCompatible { bech32_prefix } => {
+            if let Some(bech32_prefix) = &bech32_prefix {
+                validate_bech32_prefix(bech32_prefix)?;
+                CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+                    config.bech32_prefix = bech32_prefix.clone();
+                    Ok(config)
+                })?;
+            }
+
+            Ok(Response::default()
+                .add_attribute("action", "migrate")
+                .add_attribute("from", "compatible")
+                .add_attribute(
+                    "bech32_prefix",
+                    bech32_prefix.unwrap_or_else(|| "_unchanged".to_string()),
+                ))
+        }
     }
 }
 
@@ -1306,5 +1890,7 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
             };
             Ok(Response::new().add_attribute("failed_prepropose_hook", format!("{addr}")))
         }
+        // This module does not send amendment hooks.
+        TaggedReplyId::FailedAmendmentHook(_) => Err(ContractError::InvalidReplyID { id: msg.id }),
     }
 }
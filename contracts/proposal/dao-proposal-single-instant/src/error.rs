@@ -26,8 +26,8 @@ pub enum ContractError {
     #[error("not enough proposal msgs")]
     NotEnoughMsgs {},
 
-    #[error("too many proposal msgs")]
-    TooManyMsgs {},
+    #[error("proposal payload has ({count}) msgs, must be <= ({max}) msgs")]
+    TooManyMsgs { count: u64, max: u64 },
 
     #[error(transparent)]
     ThresholdError(#[from] dao_voting::threshold::ThresholdError),
@@ -38,6 +38,9 @@ pub enum ContractError {
     #[error("nonce has been already used")]
     NonceAlreadyUsed {},
 
+    #[error("proposal payload has expired")]
+    PayloadExpired {},
+
     #[error("no such proposal ({id})")]
     NoSuchProposal { id: u64 },
 
@@ -50,6 +53,12 @@ pub enum ContractError {
     #[error("Proposal ({id}) is expired")]
     Expired { id: u64 },
 
+    #[error("proposal ({id}) is not collecting signatures")]
+    NotCollectingSignatures { id: u64 },
+
+    #[error("proposal ({id}) is not open")]
+    NotOpen { id: u64 },
+
     #[error("not registered to vote (no voting power) at time of proposal creation")]
     NotRegistered {},
 
@@ -105,6 +114,37 @@ pub enum ContractError {
     #[error("Wrong length")]
     WrongLength {},
 
+    #[error("invalid public key length ({length})")]
+    InvalidPublicKeyLength { length: u64 },
+
     #[error("Verification failed")]
     VerificationFailed {},
+
+    #[error("invalid bech32 prefix ({prefix})")]
+    InvalidBech32Prefix { prefix: String },
+
+    #[error("no bech32 prefix is configured, migrate with a bech32_prefix to accept signatures")]
+    MissingBech32Prefix {},
+}
+
+/// The reason a vote signature was skipped while tallying an instant
+/// proposal. Skipped signatures are reported in the response's
+/// `rejected_signature` attributes instead of failing the
+/// transaction.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureRejection {
+    #[error("bad_key_length")]
+    BadKeyLength {},
+
+    #[error("bad_signature")]
+    BadSignature {},
+
+    #[error("non_member")]
+    NonMember {},
+
+    #[error("duplicate_signer")]
+    DuplicateSigner {},
+
+    #[error("vote_mismatch")]
+    VoteMismatch {},
 }
@@ -12,4 +12,4 @@ mod testing;
 pub mod state;
 pub mod v1_state;
 
-pub use crate::error::ContractError;
+pub use crate::error::{ContractError, SignatureRejection};
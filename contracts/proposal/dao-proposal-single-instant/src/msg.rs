@@ -1,16 +1,84 @@
 use cosmwasm_schema::serde::{self, Deserialize, Deserializer, Serializer};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{CosmosMsg, Empty};
-use cw_utils::Duration;
+use cw_utils::{Duration, Expiration};
 use dao_dao_macros::proposal_module_query;
-use dao_voting::{pre_propose::PreProposeInfo, threshold::Threshold, veto::VetoConfig};
+use dao_voting::{
+    pre_propose::PreProposeInfo, threshold::Threshold, veto::VetoConfig, voting::Vote,
+};
+
+/// The maximum number of messages a single proposal payload may
+/// contain.
+pub const MAX_PAYLOAD_MSGS: u64 = 32;
 
 #[cw_serde]
 pub struct ProposalPayload {
+    /// The messages executed by the DAO if the proposal passes. They
+    /// are covered by the same signature and are executed atomically,
+    /// in order. At most `MAX_PAYLOAD_MSGS` messages may be included.
     pub msgs: Vec<CosmosMsg<Empty>>,
     /// Nonce that has been used to sign the message.
     /// This is proposal specific and should be same among same proposal votes.
     pub nonce: String,
+    /// If set, the payload may no longer be proposed, nor may its
+    /// signatures be submitted, once it has expired. Its nonce may
+    /// then be removed with `PruneNonces`. Omitted from the signed
+    /// document if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<Expiration>,
+}
+
+/// The document voters sign. It binds a proposal payload to the chain
+/// and the proposal module it may be executed on, so that signatures
+/// can not be replayed against other deployments of this module.
+#[cw_serde]
+pub struct SignedPayload {
+    /// The chain id of the chain the proposal module is deployed on.
+    pub chain_id: String,
+    /// The address of the proposal module.
+    pub contract_address: String,
+    /// The signed payload.
+    pub payload: ProposalPayload,
+}
+
+/// The document voters sign to cast an explicit vote on a proposal
+/// payload. Unlike a [`SignedPayload`], which always counts as a `Yes`
+/// vote, it allows a signer to vote `No` or `Abstain` and to explain
+/// their vote.
+#[cw_serde]
+pub struct SignedVote {
+    /// The chain id of the chain the proposal module is deployed on.
+    pub chain_id: String,
+    /// The address of the proposal module.
+    pub contract_address: String,
+    /// The payload being voted on.
+    pub payload: ProposalPayload,
+    /// The option the signer votes for.
+    pub vote: Vote,
+    /// An optional rationale for the vote, stored in the signer's
+    /// ballot.
+    pub rationale: Option<String>,
+}
+
+/// The type of key that produced a vote signature.
+#[cw_serde]
+#[derive(Copy, Default)]
+pub enum KeyType {
+    /// Cosmos SDK secp256k1 keys. The address is derived from
+    /// ripemd160(sha256(public_key)) and the message hash is the
+    /// sha256 hash of the signed document.
+    #[default]
+    Secp256k1,
+    /// Ethereum style secp256k1 keys (ethsecp256k1), as used by
+    /// Injective and Evmos. The signature is 65 bytes long with the
+    /// recovery id appended, the address is derived from the last 20
+    /// bytes of keccak256(public_key) and the message hash is the
+    /// keccak256 hash of the signed document.
+    EthSecp256k1,
+    /// ed25519 keys. The address is derived from the first 20 bytes
+    /// of sha256(public_key) and the signature is over the sha256
+    /// hash of the signed document.
+    Ed25519,
 }
 
 /// A vote cast for an instant proposal containing message_hash and message_signature.
@@ -25,6 +93,21 @@ pub struct VoteSignature {
     /// Public key that signed message hash
     #[serde(serialize_with = "as_base64", deserialize_with = "from_base64")]
     pub public_key: Vec<u8>,
+    /// The type of `public_key`. Defaults to Cosmos SDK secp256k1 keys
+    /// if not set.
+    #[serde(default)]
+    pub key_type: KeyType,
+    /// The vote signed by this signature. If set, `message_hash` must
+    /// be the hash of the corresponding [`SignedVote`]. If not set,
+    /// signatures of the proposal's [`SignedPayload`] count as `Yes`
+    /// and any other signature counts as `No`.
+    #[serde(default)]
+    pub vote: Option<Vote>,
+    /// The rationale signed along with `vote`. Must not be set if
+    /// `vote` is not set, as the signer's rationale would not be
+    /// covered by their signature.
+    #[serde(default)]
+    pub rationale: Option<String>,
 }
 
 fn as_base64<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
@@ -103,24 +186,52 @@ pub struct InstantiateMsg {
     /// During this period an oversight account (`veto.vetoer`) can
     /// veto the proposal.
     pub veto: Option<VetoConfig>,
+    /// The bech32 prefix (human readable part) of the chain this
+    /// module is deployed on, for example "osmo" or "juno". It is used
+    /// to derive voter addresses from the public keys of vote
+    /// signatures.
+    pub bech32_prefix: String,
+    /// If set to true signatures over the bare `ProposalPayload` are
+    /// accepted in addition to signatures over a `SignedPayload`. This
+    /// allows signers to migrate to signatures bound to a chain and
+    /// proposal module, but allows their signatures to be replayed on
+    /// other deployments using the same nonce.
+    pub allow_legacy_signatures: bool,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Creates a proposal in the module.
     Propose(SingleChoiceInstantProposalMsg),
+    /// Creates a proposal that collects signatures off-chain. Unlike
+    /// `Propose`, the proposal does not need to pass with the
+    /// signatures it is created with. It stays open for more
+    /// signatures to be submitted with `SubmitSignatures` until it
+    /// passes, and is then executed, or expires.
+    ProposeForSignatures(SingleChoiceInstantProposalMsg),
+    /// Casts the votes of `signatures` on a proposal created with
+    /// `ProposeForSignatures`. If the proposal passes it is executed
+    /// when the sender is allowed to execute proposals, and is
+    /// otherwise left to be executed with `Execute`.
+    SubmitSignatures {
+        /// The ID of the proposal to vote on.
+        proposal_id: u64,
+        /// The signatures to count, of the same form as the
+        /// `vote_signatures` of a proposal.
+        signatures: Vec<VoteSignature>,
+    },
     /// Votes on a proposal. Voting power is determined by the DAO's
     /// voting power module.
-    // Vote {
-    //     /// The ID of the proposal to vote on.
-    //     proposal_id: u64,
-    //     /// The senders position on the proposal.
-    //     vote: Vote,
-    //     /// An optional rationale for why this vote was cast. This can
-    //     /// be updated, set, or removed later by the address casting
-    //     /// the vote.
-    //     rationale: Option<String>,
-    // },
+    Vote {
+        /// The ID of the proposal to vote on.
+        proposal_id: u64,
+        /// The senders position on the proposal.
+        vote: Vote,
+        /// An optional rationale for why this vote was cast. This can
+        /// be updated, set, or removed later by the address casting
+        /// the vote.
+        rationale: Option<String>,
+    },
     /// Updates the sender's rationale for their vote on the specified
     /// proposal. Errors if no vote vote has been cast.
     UpdateRationale {
@@ -129,15 +240,19 @@ pub enum ExecuteMsg {
     },
     /// Causes the messages associated with a passed proposal to be
     /// executed by the DAO.
-    // Execute {
-    //     /// The ID of the proposal to execute.
-    //     proposal_id: u64,
-    // },
+    Execute {
+        /// The ID of the proposal to execute.
+        proposal_id: u64,
+    },
     /// Callable only if veto is configured
-    // Veto {
-    //     /// The ID of the proposal to veto.
-    //     proposal_id: u64,
-    // },
+    Veto {
+        /// The ID of the proposal to veto.
+        proposal_id: u64,
+    },
+    /// Removes used nonces whose payload has expired, so that they
+    /// no longer take up storage. Nonces without an expiration, or
+    /// that have not yet expired, are skipped.
+    PruneNonces { nonces: Vec<String> },
     /// Closes a proposal that has failed (either not passed or timed
     /// out). If applicable this will cause the proposal deposit
     /// associated wth said proposal to be returned.
@@ -183,6 +298,13 @@ pub enum ExecuteMsg {
         /// Optional time delay on proposal execution, during which the
         /// proposal may be vetoed.
         veto: Option<VetoConfig>,
+        /// The bech32 prefix used to derive voter addresses from the
+        /// public keys of vote signatures.
+        bech32_prefix: String,
+        /// If set to true signatures over the bare `ProposalPayload`
+        /// are accepted in addition to signatures over a
+        /// `SignedPayload`.
+        allow_legacy_signatures: bool,
     },
     /// Update's the proposal creation policy used for this
     /// module. Only the DAO may call this method.
@@ -240,6 +362,23 @@ pub enum QueryMsg {
         /// returned.
         limit: Option<u64>,
     },
+    /// Returns true if `nonce` has been used by a proposal.
+    #[returns(bool)]
+    IsNonceUsed { nonce: String },
+    /// Lists the nonces that have been used by proposals, in
+    /// ascending order.
+    #[returns(crate::query::NonceListResponse)]
+    ListNonces {
+        /// The nonce to start listing nonces after.
+        start_after: Option<String>,
+        /// The maximum number of nonces to return. If no limit is
+        /// set a max of 30 nonces will be returned.
+        limit: Option<u64>,
+    },
+    /// Gets the payload signed by voters of a proposal created with
+    /// `ProposeForSignatures`. Returns `None` for other proposals.
+    #[returns(Option<ProposalPayload>)]
+    Payload { proposal_id: u64 },
     /// Returns a voters position on a propsal.
     #[returns(crate::query::VoteResponse)]
     GetVote { proposal_id: u64, voter: String },
@@ -297,6 +436,17 @@ pub enum MigrateMsg {
         ///
         /// optional configuration for veto feature
         veto: Option<VetoConfig>,
+        /// This field was not present in DAO DAO v1. To migrate, a
+        /// value must be specified.
+        ///
+        /// The bech32 prefix used to derive voter addresses from the
+        /// public keys of vote signatures.
+        bech32_prefix: String,
+    },
+    FromCompatible {
+        /// If set, replaces the bech32 prefix used to derive voter
+        /// addresses. Must be set when migrating from a version
+        /// without a bech32 prefix for signatures to be accepted.
+        bech32_prefix: Option<String>,
     },
-    FromCompatible {},
 }
@@ -1,6 +1,7 @@
 use crate::proposal::SingleChoiceProposal;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint128};
+use cw_utils::Expiration;
 use dao_voting::voting::Vote;
 
 /// Information about a proposal returned by proposal queries.
@@ -43,3 +44,17 @@ pub struct VoteListResponse {
 pub struct ProposalListResponse {
     pub proposals: Vec<ProposalResponse>,
 }
+
+/// A nonce used by a proposal.
+#[cw_serde]
+pub struct NonceInfo {
+    pub nonce: String,
+    /// The expiration of the payload that used the nonce, if any.
+    pub expiration: Option<Expiration>,
+}
+
+/// A list of nonces returned by `ListNonces`.
+#[cw_serde]
+pub struct NonceListResponse {
+    pub nonces: Vec<NonceInfo>,
+}
@@ -1,9 +1,10 @@
+use crate::msg::ProposalPayload;
 use crate::proposal::SingleChoiceProposal;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint128};
 use cw_hooks::Hooks;
 use cw_storage_plus::{Item, Map};
-use cw_utils::Duration;
+use cw_utils::{Duration, Expiration};
 use dao_voting::{
     pre_propose::ProposalCreationPolicy, threshold::Threshold, veto::VetoConfig, voting::Vote,
 };
@@ -60,6 +61,19 @@ pub struct Config {
     /// Optional veto configuration. If set to `None`, veto option
     /// is disabled. Otherwise contains the configuration for veto flow.
     pub veto: Option<VetoConfig>,
+    /// The bech32 prefix used to derive voter addresses from the
+    /// public keys of vote signatures. Configs stored before this
+    /// option existed deserialize to an empty prefix, and signatures
+    /// are rejected until one is set by migrating with
+    /// `MigrateMsg::FromCompatible`.
+    #[serde(default)]
+    pub bech32_prefix: String,
+    /// If set to true signatures over the bare `ProposalPayload` are
+    /// accepted in addition to signatures over a `SignedPayload`.
+    /// Configs stored before this option existed deserialize to
+    /// false.
+    #[serde(default)]
+    pub allow_legacy_signatures: bool,
 }
 
 /// The current top level config for the module.  The "config" key was
@@ -80,3 +94,10 @@ pub const CREATION_POLICY: Item<ProposalCreationPolicy> = Item::new("creation_po
 /// This allow to consult the nonce and check if it has been used before without iterating the whole list,
 /// or unwrap_default false to evaluate if it has been used.
 pub const NONCES: Map<&String, bool> = Map::new("nonces");
+/// The expirations of the payloads that used time-bound nonces. Once
+/// expired, a nonce may be removed from `NONCES` as its payload can no
+/// longer be proposed.
+pub const NONCE_EXPIRATIONS: Map<&String, Expiration> = Map::new("nonce_expirations");
+/// The payloads of proposals created with `ProposeForSignatures`,
+/// against which signatures submitted later are verified.
+pub const PAYLOADS: Map<u64, ProposalPayload> = Map::new("payloads");
@@ -180,6 +180,8 @@ pub fn test_executed_prop_state_remains_after_vote_swing() {
             false,
         ),
         close_proposal_on_execution_failure: true,
+        bech32_prefix: "osmo".to_string(),
+        allow_legacy_signatures: false,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -279,6 +281,8 @@ pub fn test_passed_prop_state_remains_after_vote_swing() {
             false,
         ),
         close_proposal_on_execution_failure: true,
+        bech32_prefix: "osmo".to_string(),
+        allow_legacy_signatures: false,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
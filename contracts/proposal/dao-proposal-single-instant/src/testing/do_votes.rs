@@ -141,6 +141,8 @@ where
         only_members_execute: false,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        bech32_prefix: "osmo".to_string(),
+        allow_legacy_signatures: false,
         pre_propose_info,
     };
 
@@ -222,60 +224,60 @@ where
             should_execute,
         } = vote;
         // Vote on the proposal.
-        // let res = app.execute_contract(
-        //     Addr::unchecked(voter.clone()),
-        //     proposal_single.clone(),
-        //     &ExecuteMsg::Vote {
-        //         proposal_id: 1,
-        //         vote: position,
-        //         rationale: None,
-        //     },
-        //     &[],
-        // );
-        // match should_execute {
-        //     ShouldExecute::Yes => {
-        //         assert!(res.is_ok());
-        //         // Check that the vote was recorded correctly.
-        //         let vote: VoteResponse = app
-        //             .wrap()
-        //             .query_wasm_smart(
-        //                 proposal_single.clone(),
-        //                 &QueryMsg::GetVote {
-        //                     proposal_id: 1,
-        //                     voter: voter.clone(),
-        //                 },
-        //             )
-        //             .unwrap();
-        //         let expected = VoteResponse {
-        //             vote: Some(VoteInfo {
-        //                 rationale: None,
-        //                 voter: Addr::unchecked(&voter),
-        //                 vote: position,
-        //                 power: match deposit_config.deposit_info {
-        //                     Some(CheckedDepositInfo {
-        //                         amount,
-        //                         denom: CheckedDenom::Cw20(_),
-        //                         ..
-        //                     }) => {
-        //                         if proposer == voter {
-        //                             weight - amount
-        //                         } else {
-        //                             weight
-        //                         }
-        //                     }
-        //                     // Native token deposits shouldn't impact
-        //                     // expected voting power.
-        //                     _ => weight,
-        //                 },
-        //             }),
-        //         };
-        //         assert_eq!(vote, expected)
-        //     }
-        //     ShouldExecute::No => {
-        //         res.unwrap_err();
-        //     }
-        //     ShouldExecute::Meh => (),
-        // }
+        let res = app.execute_contract(
+            Addr::unchecked(voter.clone()),
+            proposal_single.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id: 1,
+                vote: position,
+                rationale: None,
+            },
+            &[],
+        );
+        match should_execute {
+            ShouldExecute::Yes => {
+                assert!(res.is_ok());
+                // Check that the vote was recorded correctly.
+                let vote: VoteResponse = app
+                    .wrap()
+                    .query_wasm_smart(
+                        proposal_single.clone(),
+                        &QueryMsg::GetVote {
+                            proposal_id: 1,
+                            voter: voter.clone(),
+                        },
+                    )
+                    .unwrap();
+                let expected = VoteResponse {
+                    vote: Some(VoteInfo {
+                        rationale: None,
+                        voter: Addr::unchecked(&voter),
+                        vote: position,
+                        power: match deposit_config.deposit_info {
+                            Some(CheckedDepositInfo {
+                                amount,
+                                denom: CheckedDenom::Cw20(_),
+                                ..
+                            }) => {
+                                if proposer == voter {
+                                    weight - amount
+                                } else {
+                                    weight
+                                }
+                            }
+                            // Native token deposits shouldn't impact
+                            // expected voting power.
+                            _ => weight,
+                        },
+                    }),
+                };
+                assert_eq!(vote, expected)
+            }
+            ShouldExecute::No => {
+                res.unwrap_err();
+            }
+            ShouldExecute::Meh => (),
+        }
     }
 
     let proposal: ProposalResponse = app
@@ -80,6 +80,7 @@ pub(crate) fn make_proposal(
                     payload: ProposalPayload {
                         msgs: msgs.clone(),
                         nonce: NONCE.to_string(),
+                        expiration: None,
                     },
                     proposer: None,
                     vote_signatures: todo!(),
@@ -126,17 +127,17 @@ pub(crate) fn vote_on_proposal(
     proposal_id: u64,
     vote: Vote,
 ) {
-    // app.execute_contract(
-    //     Addr::unchecked(sender),
-    //     proposal_single.clone(),
-    //     &ExecuteMsg::Vote {
-    //         proposal_id,
-    //         vote,
-    //         rationale: None,
-    //     },
-    //     &[],
-    // )
-    // .unwrap();
+    app.execute_contract(
+        Addr::unchecked(sender),
+        proposal_single.clone(),
+        &ExecuteMsg::Vote {
+            proposal_id,
+            vote,
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
 }
 
 pub(crate) fn vote_on_proposal_should_fail(
@@ -146,20 +147,19 @@ pub(crate) fn vote_on_proposal_should_fail(
     proposal_id: u64,
     vote: Vote,
 ) -> ContractError {
-    // app.execute_contract(
-    //     Addr::unchecked(sender),
-    //     proposal_single.clone(),
-    //     &ExecuteMsg::Vote {
-    //         proposal_id,
-    //         vote,
-    //         rationale: None,
-    //     },
-    //     &[],
-    // )
-    // .unwrap_err()
-    // .downcast()
-    // .unwrap()
-    todo!()
+    app.execute_contract(
+        Addr::unchecked(sender),
+        proposal_single.clone(),
+        &ExecuteMsg::Vote {
+            proposal_id,
+            vote,
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap()
 }
 
 pub(crate) fn execute_proposal_should_fail(
@@ -168,16 +168,15 @@ pub(crate) fn execute_proposal_should_fail(
     sender: &str,
     proposal_id: u64,
 ) -> ContractError {
-    // app.execute_contract(
-    //     Addr::unchecked(sender),
-    //     proposal_single.clone(),
-    //     &ExecuteMsg::Execute { proposal_id },
-    //     &[],
-    // )
-    // .unwrap_err()
-    // .downcast()
-    // .unwrap()
-    todo!()
+    app.execute_contract(
+        Addr::unchecked(sender),
+        proposal_single.clone(),
+        &ExecuteMsg::Execute { proposal_id },
+        &[],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap()
 }
 
 pub(crate) fn vote_on_proposal_with_rationale(
@@ -188,17 +187,17 @@ pub(crate) fn vote_on_proposal_with_rationale(
     vote: Vote,
     rationale: Option<String>,
 ) {
-    // app.execute_contract(
-    //     Addr::unchecked(sender),
-    //     proposal_single.clone(),
-    //     &ExecuteMsg::Vote {
-    //         proposal_id,
-    //         vote,
-    //         rationale,
-    //     },
-    //     &[],
-    // )
-    // .unwrap();
+    app.execute_contract(
+        Addr::unchecked(sender),
+        proposal_single.clone(),
+        &ExecuteMsg::Vote {
+            proposal_id,
+            vote,
+            rationale,
+        },
+        &[],
+    )
+    .unwrap();
 }
 
 pub(crate) fn update_rationale(
@@ -226,13 +225,13 @@ pub(crate) fn execute_proposal(
     sender: &str,
     proposal_id: u64,
 ) {
-    // app.execute_contract(
-    //     Addr::unchecked(sender),
-    //     proposal_single.clone(),
-    //     &ExecuteMsg::Execute { proposal_id },
-    //     &[],
-    // )
-    // .unwrap();
+    app.execute_contract(
+        Addr::unchecked(sender),
+        proposal_single.clone(),
+        &ExecuteMsg::Execute { proposal_id },
+        &[],
+    )
+    .unwrap();
 }
 
 pub(crate) fn close_proposal_should_fail(
@@ -70,6 +70,8 @@ pub(crate) fn get_default_token_dao_proposal_module_instantiate(app: &mut App) -
             false,
         ),
         close_proposal_on_execution_failure: true,
+        bech32_prefix: "osmo".to_string(),
+        allow_legacy_signatures: false,
     }
 }
 
@@ -89,6 +91,8 @@ pub(crate) fn get_default_non_token_dao_proposal_module_instantiate(
         allow_revoting: false,
         pre_propose_info: get_pre_propose_info(app, None, false),
         close_proposal_on_execution_failure: true,
+        bech32_prefix: "osmo".to_string(),
+        allow_legacy_signatures: false,
     }
 }
 
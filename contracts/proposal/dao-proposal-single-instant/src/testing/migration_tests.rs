@@ -338,6 +338,7 @@ fn test_v1_v2_full_migration() {
                     new_code_id: v2_proposal_code,
                     msg: to_json_binary(&crate::msg::MigrateMsg::FromV1 {
                         close_proposal_on_execution_failure: true,
+                        bech32_prefix: "osmo".to_string(),
                         pre_propose_info,
                         veto: Some(VetoConfig {
                             timelock_duration: Duration::Height(10),
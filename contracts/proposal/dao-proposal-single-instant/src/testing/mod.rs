@@ -4,7 +4,9 @@ mod do_votes;
 mod execute;
 mod instantiate;
 mod migration_tests;
+mod nonce_tests;
 mod queries;
+mod signature_tests;
 mod test_tube;
 mod tests;
 
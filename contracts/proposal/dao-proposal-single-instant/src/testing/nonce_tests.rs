@@ -0,0 +1,88 @@
+use cosmwasm_std::{
+    from_json,
+    testing::{mock_dependencies, mock_env},
+    Deps,
+};
+use cw_utils::Expiration;
+
+use crate::{
+    contract::{execute_prune_nonces, query},
+    msg::QueryMsg,
+    query::{NonceInfo, NonceListResponse},
+    state::{NONCES, NONCE_EXPIRATIONS},
+};
+
+fn is_nonce_used(deps: Deps, nonce: &str) -> bool {
+    from_json(
+        query(
+            deps,
+            mock_env(),
+            QueryMsg::IsNonceUsed {
+                nonce: nonce.to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_nonce_queries_and_pruning() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    let expired = Expiration::AtHeight(env.block.height);
+    let live = Expiration::AtHeight(env.block.height + 1);
+    for (nonce, expiration) in [("a", Some(expired)), ("b", Some(live)), ("c", None)] {
+        NONCES
+            .save(deps.as_mut().storage, &nonce.to_string(), &true)
+            .unwrap();
+        if let Some(expiration) = expiration {
+            NONCE_EXPIRATIONS
+                .save(deps.as_mut().storage, &nonce.to_string(), &expiration)
+                .unwrap();
+        }
+    }
+
+    assert!(is_nonce_used(deps.as_ref(), "a"));
+    assert!(!is_nonce_used(deps.as_ref(), "d"));
+
+    let nonces: NonceListResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::ListNonces {
+                start_after: Some("a".to_string()),
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        nonces.nonces,
+        vec![
+            NonceInfo {
+                nonce: "b".to_string(),
+                expiration: Some(live),
+            },
+            NonceInfo {
+                nonce: "c".to_string(),
+                expiration: None,
+            },
+        ]
+    );
+
+    // Only expired nonces are pruned.
+    let res = execute_prune_nonces(
+        deps.as_mut(),
+        env,
+        vec!["a".to_string(), "b".to_string(), "c".to_string()],
+    )
+    .unwrap();
+    assert_eq!(res.attributes[1].value, "1");
+    assert!(!is_nonce_used(deps.as_ref(), "a"));
+    assert!(is_nonce_used(deps.as_ref(), "b"));
+    assert!(is_nonce_used(deps.as_ref(), "c"));
+    assert!(!NONCE_EXPIRATIONS.has(&deps.storage, &"a".to_string()));
+}
@@ -0,0 +1,516 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env, mock_info},
+    to_json_string, Addr, Storage, Uint128,
+};
+use cw_utils::Duration;
+use dao_voting::{pre_propose::PreProposeInfo, threshold::Threshold, voting::Vote};
+
+use crate::{
+    contract::{
+        create_adr36_message, create_signed_payload, create_signed_vote, derive_addr_from_pubkey,
+        derive_ed25519_addr_from_pubkey, derive_eth_addr_from_pubkey, hash_sign_doc, instantiate,
+        migrate, signed_vote, validate_bech32_prefix, verify_message, verify_messages,
+    },
+    msg::{InstantiateMsg, KeyType, MigrateMsg, ProposalPayload, VoteSignature},
+    state::CONFIG,
+    ContractError, SignatureRejection,
+};
+
+/// Compressed secp256k1 public key of the private key `1` (the
+/// generator point).
+const GENERATOR_PUBKEY: &str = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+/// Uncompressed form of `GENERATOR_PUBKEY`.
+const GENERATOR_PUBKEY_UNCOMPRESSED: &str = "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+
+/// sha256("instant proposal"), the message hash signed by the keys
+/// below.
+const MESSAGE_HASH: &str = "3d3d1c3b56b46c0bb2dc634c682d591d5c07ee09976c1c9472ebc457083c09d1";
+/// Ethereum style signature of `MESSAGE_HASH` by the private key `1`,
+/// with the recovery id appended.
+const ETH_SIGNATURE: &str = "bb50e2d89a4ed70663d080659fe0ad4b9bc3e06c17a227433966cb59ceee020d0cb87abf27437a27e172ef6fbc8c4c4a62084465b43fea8938f70ac86befe05500";
+/// ed25519 public keys of the seeds `[1u8; 32]` and `[2u8; 32]`, and
+/// their signatures of `MESSAGE_HASH`.
+const ED25519_PUBKEYS: [&str; 2] = [
+    "8a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c",
+    "8139770ea87d175f56a35466c34c7ecccb8d8a91b4ee37a25df60f5b8fc9b394",
+];
+const ED25519_SIGNATURES: [&str; 2] = [
+    "b4a14492b244eca2375dfde8e1632f3ffe3b914215fc7f2bcd361e105fad2b9847b6562c8989e6b32678923cec2d2be06afee3cc83c5a39e2483aafeea712b02",
+    "515f57fd38cdc03cabc92f9c2a411ef359873132734ecdeaeb1eef71c922160baf6085290fb0475c6f269eb82478886af992dc13206e2b60340d834a3a27cf06",
+];
+const ED25519_ADDRS: [&str; 2] = [
+    "osmo1x36slx9at870e9rd53d2405n80s4ff94kp6e6f",
+    "osmo1dguq840stxgz58rd477fhfrjjgf00j4vsz57gp",
+];
+
+fn from_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn ed25519_signature(index: usize) -> VoteSignature {
+    VoteSignature {
+        message_hash: from_hex(MESSAGE_HASH),
+        signature: from_hex(ED25519_SIGNATURES[index]),
+        public_key: from_hex(ED25519_PUBKEYS[index]),
+        key_type: KeyType::Ed25519,
+        vote: None,
+        rationale: None,
+    }
+}
+
+fn instantiate_msg(bech32_prefix: &str) -> InstantiateMsg {
+    InstantiateMsg {
+        threshold: Threshold::AbsoluteCount {
+            threshold: Uint128::new(2),
+        },
+        max_voting_period: Duration::Height(1),
+        min_voting_period: None,
+        only_members_execute: true,
+        allow_revoting: false,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        close_proposal_on_execution_failure: true,
+        veto: None,
+        bech32_prefix: bech32_prefix.to_string(),
+        allow_legacy_signatures: false,
+    }
+}
+
+#[test]
+fn test_derive_addr_from_pubkey_prefixes() {
+    let key = from_hex(GENERATOR_PUBKEY);
+
+    assert_eq!(
+        derive_addr_from_pubkey(&key, "osmo").unwrap(),
+        "osmo1w508d6qejxtdg4y5r3zarvary0c5xw7kjxy2e2"
+    );
+    assert_eq!(
+        derive_addr_from_pubkey(&key, "cosmos").unwrap(),
+        "cosmos1w508d6qejxtdg4y5r3zarvary0c5xw7k6ah60c"
+    );
+    assert_eq!(
+        derive_addr_from_pubkey(&key, "juno").unwrap(),
+        "juno1w508d6qejxtdg4y5r3zarvary0c5xw7kv05pgy"
+    );
+}
+
+#[test]
+fn test_validate_bech32_prefix() {
+    validate_bech32_prefix("osmo").unwrap();
+    validate_bech32_prefix("juno").unwrap();
+
+    for prefix in ["", "OSMO", "os mo"] {
+        assert_eq!(
+            validate_bech32_prefix(prefix).unwrap_err(),
+            ContractError::InvalidBech32Prefix {
+                prefix: prefix.to_string()
+            }
+        );
+    }
+}
+
+#[test]
+fn test_instantiate_bech32_prefix() {
+    for prefix in ["osmo", "juno"] {
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("dao", &[]),
+            instantiate_msg(prefix),
+        )
+        .unwrap();
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        assert_eq!(config.bech32_prefix, prefix);
+    }
+
+    let mut deps = mock_dependencies();
+    let err = instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("dao", &[]),
+        instantiate_msg("Osmo"),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidBech32Prefix {
+            prefix: "Osmo".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_migrate_bech32_prefix() {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("dao", &[]),
+        instantiate_msg("osmo"),
+    )
+    .unwrap();
+
+    // Configs stored before the prefix existed load with an empty
+    // prefix.
+    let config = to_json_string(&CONFIG.load(deps.as_ref().storage).unwrap()).unwrap();
+    let legacy_config = config.replace(r#""bech32_prefix":"osmo","#, "");
+    assert_ne!(config, legacy_config);
+    deps.storage.set(b"config_v2", legacy_config.as_bytes());
+    assert_eq!(
+        CONFIG.load(deps.as_ref().storage).unwrap().bech32_prefix,
+        ""
+    );
+
+    let err = migrate(
+        deps.as_mut(),
+        mock_env(),
+        MigrateMsg::FromCompatible {
+            bech32_prefix: Some("Juno".to_string()),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidBech32Prefix {
+            prefix: "Juno".to_string()
+        }
+    );
+
+    migrate(
+        deps.as_mut(),
+        mock_env(),
+        MigrateMsg::FromCompatible {
+            bech32_prefix: Some("juno".to_string()),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        CONFIG.load(deps.as_ref().storage).unwrap().bech32_prefix,
+        "juno"
+    );
+}
+
+#[test]
+fn test_derive_addr_from_pubkey_bad_length() {
+    assert_eq!(
+        derive_addr_from_pubkey(&[1u8; 20], "osmo").unwrap_err(),
+        ContractError::InvalidPublicKeyLength { length: 20 }
+    );
+}
+
+#[test]
+fn test_verify_message_rejections() {
+    let deps = mock_dependencies();
+
+    let bad_key = VoteSignature {
+        message_hash: vec![1u8; 32],
+        signature: vec![1u8; 64],
+        public_key: vec![2u8; 20],
+        key_type: KeyType::Secp256k1,
+        vote: None,
+        rationale: None,
+    };
+    assert_eq!(
+        verify_message(deps.as_ref(), &bad_key, "osmo").unwrap_err(),
+        SignatureRejection::BadKeyLength {}
+    );
+
+    let bad_signature = VoteSignature {
+        message_hash: vec![1u8; 32],
+        signature: vec![1u8; 64],
+        public_key: from_hex(GENERATOR_PUBKEY),
+        key_type: KeyType::Secp256k1,
+        vote: None,
+        rationale: None,
+    };
+    assert_eq!(
+        verify_message(deps.as_ref(), &bad_signature, "osmo").unwrap_err(),
+        SignatureRejection::BadSignature {}
+    );
+
+    // Malformed signatures are rejected rather than panicking.
+    let malformed_signature = VoteSignature {
+        message_hash: vec![1u8; 3],
+        signature: vec![1u8; 3],
+        public_key: from_hex(GENERATOR_PUBKEY),
+        key_type: KeyType::Secp256k1,
+        vote: None,
+        rationale: None,
+    };
+    assert_eq!(
+        verify_message(deps.as_ref(), &malformed_signature, "osmo").unwrap_err(),
+        SignatureRejection::BadSignature {}
+    );
+}
+
+#[test]
+fn test_derive_eth_addr_from_pubkey() {
+    // The Ethereum address of the private key `1` is
+    // 0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf.
+    assert_eq!(
+        derive_eth_addr_from_pubkey(&from_hex(GENERATOR_PUBKEY_UNCOMPRESSED), "inj").unwrap(),
+        "inj10e0525sfrf53yh2aljmm3sn9jq5njk7lwfmzjf"
+    );
+    // Compressed keys can not be hashed into an address directly.
+    assert_eq!(
+        derive_eth_addr_from_pubkey(&from_hex(GENERATOR_PUBKEY), "inj").unwrap_err(),
+        ContractError::InvalidPublicKeyLength { length: 33 }
+    );
+}
+
+#[test]
+fn test_verify_eth_secp256k1() {
+    let deps = mock_dependencies();
+
+    // Both the compressed and uncompressed forms of the signing key
+    // are accepted.
+    for key in [GENERATOR_PUBKEY, GENERATOR_PUBKEY_UNCOMPRESSED] {
+        let vote_signature = VoteSignature {
+            message_hash: from_hex(MESSAGE_HASH),
+            signature: from_hex(ETH_SIGNATURE),
+            public_key: from_hex(key),
+            key_type: KeyType::EthSecp256k1,
+            vote: None,
+            rationale: None,
+        };
+        assert_eq!(
+            verify_message(deps.as_ref(), &vote_signature, "inj").unwrap(),
+            "inj10e0525sfrf53yh2aljmm3sn9jq5njk7lwfmzjf"
+        );
+    }
+
+    // A signature by another key is rejected.
+    let wrong_key = VoteSignature {
+        message_hash: from_hex(MESSAGE_HASH),
+        signature: from_hex(ETH_SIGNATURE),
+        public_key: from_hex(&GENERATOR_PUBKEY.replacen("02", "03", 1)),
+        key_type: KeyType::EthSecp256k1,
+        vote: None,
+        rationale: None,
+    };
+    assert_eq!(
+        verify_message(deps.as_ref(), &wrong_key, "inj").unwrap_err(),
+        SignatureRejection::BadSignature {}
+    );
+
+    // As is a signature without a recovery id.
+    let no_recovery_id = VoteSignature {
+        message_hash: from_hex(MESSAGE_HASH),
+        signature: from_hex(&ETH_SIGNATURE[..128]),
+        public_key: from_hex(GENERATOR_PUBKEY),
+        key_type: KeyType::EthSecp256k1,
+        vote: None,
+        rationale: None,
+    };
+    assert_eq!(
+        verify_message(deps.as_ref(), &no_recovery_id, "inj").unwrap_err(),
+        SignatureRejection::BadSignature {}
+    );
+}
+
+#[test]
+fn test_verify_ed25519() {
+    let deps = mock_dependencies();
+
+    for index in 0..2 {
+        assert_eq!(
+            derive_ed25519_addr_from_pubkey(&from_hex(ED25519_PUBKEYS[index]), "osmo").unwrap(),
+            ED25519_ADDRS[index]
+        );
+        assert_eq!(
+            verify_message(deps.as_ref(), &ed25519_signature(index), "osmo").unwrap(),
+            ED25519_ADDRS[index]
+        );
+    }
+
+    // The signature of another key is rejected.
+    let swapped = VoteSignature {
+        signature: from_hex(ED25519_SIGNATURES[1]),
+        ..ed25519_signature(0)
+    };
+    assert_eq!(
+        verify_message(deps.as_ref(), &swapped, "osmo").unwrap_err(),
+        SignatureRejection::BadSignature {}
+    );
+
+    let bad_key = VoteSignature {
+        public_key: from_hex(GENERATOR_PUBKEY),
+        ..ed25519_signature(0)
+    };
+    assert_eq!(
+        verify_message(deps.as_ref(), &bad_key, "osmo").unwrap_err(),
+        SignatureRejection::BadKeyLength {}
+    );
+}
+
+#[test]
+fn test_verify_messages_mixed_key_types() {
+    let deps = mock_dependencies();
+
+    let eth_signature = VoteSignature {
+        message_hash: from_hex(MESSAGE_HASH),
+        signature: from_hex(ETH_SIGNATURE),
+        public_key: from_hex(GENERATOR_PUBKEY),
+        key_type: KeyType::EthSecp256k1,
+        vote: None,
+        rationale: None,
+    };
+
+    // All ed25519 signatures are valid, so they are verified as a
+    // batch.
+    let verified = verify_messages(
+        deps.as_ref(),
+        &[
+            ed25519_signature(0),
+            eth_signature.clone(),
+            ed25519_signature(1),
+        ],
+        "osmo",
+    );
+    assert_eq!(
+        verified,
+        vec![
+            Ok(ED25519_ADDRS[0].to_string()),
+            Ok(
+                derive_eth_addr_from_pubkey(&from_hex(GENERATOR_PUBKEY_UNCOMPRESSED), "osmo")
+                    .unwrap()
+            ),
+            Ok(ED25519_ADDRS[1].to_string()),
+        ]
+    );
+
+    // An invalid ed25519 signature fails the batch, after which
+    // signatures are verified one by one.
+    let verified = verify_messages(
+        deps.as_ref(),
+        &[
+            ed25519_signature(0),
+            VoteSignature {
+                signature: from_hex(ED25519_SIGNATURES[0]),
+                ..ed25519_signature(1)
+            },
+            eth_signature,
+        ],
+        "osmo",
+    );
+    assert_eq!(verified[0], Ok(ED25519_ADDRS[0].to_string()));
+    assert_eq!(verified[1], Err(SignatureRejection::BadSignature {}));
+    assert!(verified[2].is_ok());
+}
+
+#[test]
+fn test_signed_payload_binds_domain() {
+    let payload = ProposalPayload {
+        msgs: vec![],
+        nonce: "1".to_string(),
+        expiration: None,
+    };
+    let env = mock_env();
+    assert_eq!(
+        create_signed_payload(&env, &payload).unwrap(),
+        format!(
+            r#"{{"chain_id":"{}","contract_address":"{}","payload":{{"msgs":[],"nonce":"1"}}}}"#,
+            env.block.chain_id, env.contract.address
+        )
+    );
+
+    let mut other_chain = mock_env();
+    other_chain.block.chain_id = "juno-1".to_string();
+    assert_ne!(
+        create_signed_payload(&other_chain, &payload).unwrap(),
+        create_signed_payload(&env, &payload).unwrap()
+    );
+}
+
+#[test]
+fn test_signed_vote_document() {
+    let payload = ProposalPayload {
+        msgs: vec![],
+        nonce: "1".to_string(),
+        expiration: None,
+    };
+    let env = mock_env();
+    assert_eq!(
+        create_signed_vote(&env, &payload, Vote::Abstain, Some("why".to_string())).unwrap(),
+        format!(
+            r#"{{"chain_id":"{}","contract_address":"{}","payload":{{"msgs":[],"nonce":"1"}},"vote":"abstain","rationale":"why"}}"#,
+            env.block.chain_id, env.contract.address
+        )
+    );
+
+    // The vote and its rationale are both covered by the signature.
+    assert_ne!(
+        create_signed_vote(&env, &payload, Vote::Yes, None).unwrap(),
+        create_signed_vote(&env, &payload, Vote::No, None).unwrap()
+    );
+    assert_ne!(
+        create_signed_vote(&env, &payload, Vote::Yes, None).unwrap(),
+        create_signed_vote(&env, &payload, Vote::Yes, Some("why".to_string())).unwrap()
+    );
+}
+
+#[test]
+fn test_signed_vote_rejects_other_documents() {
+    let env = mock_env();
+    let voter = Addr::unchecked("voter");
+    let payload = ProposalPayload {
+        msgs: vec![],
+        nonce: "1".to_string(),
+        expiration: None,
+    };
+    let other_payload = ProposalPayload {
+        nonce: "2".to_string(),
+        ..payload.clone()
+    };
+    let signed_payload = create_signed_payload(&env, &payload).unwrap();
+    let signature = |document: &str, vote: Option<Vote>| VoteSignature {
+        message_hash: hash_sign_doc(
+            KeyType::Secp256k1,
+            create_adr36_message(&document.to_string(), &voter.to_string()).as_bytes(),
+        ),
+        signature: vec![],
+        public_key: vec![],
+        key_type: KeyType::Secp256k1,
+        vote,
+        rationale: None,
+    };
+    let vote_of = |vote_signature: &VoteSignature| {
+        signed_vote(
+            &env,
+            &payload,
+            &signed_payload,
+            None,
+            &voter,
+            vote_signature,
+        )
+        .unwrap()
+    };
+
+    assert_eq!(vote_of(&signature(&signed_payload, None)), Some(Vote::Yes));
+
+    // A signature collected for another payload can not be replayed
+    // as a vote against this one.
+    let other_document = create_signed_payload(&env, &other_payload).unwrap();
+    assert_eq!(vote_of(&signature(&other_document, None)), None);
+    assert_eq!(vote_of(&signature(&other_document, Some(Vote::No))), None);
+
+    // An implicit Yes may not carry a rationale, as it is not covered
+    // by the signature.
+    assert_eq!(
+        vote_of(&VoteSignature {
+            rationale: Some("unsigned".to_string()),
+            ..signature(&signed_payload, None)
+        }),
+        None
+    );
+
+    // No is only counted when explicitly signed for this payload.
+    let no_document = create_signed_vote(&env, &payload, Vote::No, None).unwrap();
+    assert_eq!(
+        vote_of(&signature(&no_document, Some(Vote::No))),
+        Some(Vote::No)
+    );
+}
@@ -2,12 +2,15 @@
 pub mod test_tube {
     use cosmwasm_std::testing::mock_dependencies;
     use cosmwasm_std::{to_json_binary, to_json_string, Api, BankMsg, Coin, CosmosMsg, Uint128};
-    use cw_utils::Duration;
+    use cw_utils::{Duration, Expiration};
     use dao_interface::msg::InstantiateMsg as InstantiateMsgCore;
     use dao_interface::state::Admin;
     use dao_interface::state::ModuleInstantiateInfo;
     use dao_voting::pre_propose::PreProposeInfo;
+    use dao_voting::status::Status;
     use dao_voting::threshold::Threshold;
+    use dao_voting::veto::VetoConfig;
+    use dao_voting::voting::Vote;
     use dao_voting_cw4::msg::GroupContract;
     use osmosis_test_tube::osmosis_std::types::cosmos::bank::v1beta1::{
         MsgSend, QueryBalanceRequest,
@@ -21,8 +24,10 @@ pub mod test_tube {
 
     use crate::contract::{compute_sha256_hash, create_adr36_message};
     use crate::msg::{
-        ExecuteMsg, InstantiateMsg, ProposalPayload, SingleChoiceInstantProposalMsg, VoteSignature,
+        ExecuteMsg, InstantiateMsg, KeyType, ProposalPayload, QueryMsg, SignedPayload, SignedVote,
+        SingleChoiceInstantProposalMsg, VoteSignature,
     };
+    use crate::query::{ProposalResponse, VoteResponse};
 
     /// Init constants
     const SLUG_DAO_DAO_CORE: &str = "dao_dao_core";
@@ -34,6 +39,29 @@ pub mod test_tube {
     const INITIAL_BALANCE_AMOUNT: u128 = 1_000_000_000_000_000u128;
     const INITIAL_BALANCE_DENOM: &str = "ugov";
     pub const NONCE: &str = "123456";
+    const CHAIN_ID: &str = "osmosis-1";
+
+    fn default_instantiate_msg() -> InstantiateMsg {
+        InstantiateMsg {
+            threshold: Threshold::AbsoluteCount {
+                threshold: Uint128::new(2u128),
+            },
+            // TODO: Create an additional test variant as below
+            // threshold: Threshold::ThresholdQuorum {
+            //     threshold: PercentageThreshold,
+            //     quorum: PercentageThreshold,
+            // },
+            max_voting_period: Duration::Height(1), // 1 block only to make it expire after the proposing block
+            min_voting_period: None,
+            only_members_execute: true,
+            allow_revoting: false,
+            pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+            close_proposal_on_execution_failure: true,
+            veto: None,
+            bech32_prefix: "osmo".to_string(),
+            allow_legacy_signatures: false,
+        }
+    }
 
     pub fn test_init(
         voters_number: u32,
@@ -42,6 +70,18 @@ pub mod test_tube {
         HashMap<&'static str, String>,
         SigningAccount,
         Vec<SigningAccount>,
+    ) {
+        test_init_with_msg(voters_number, default_instantiate_msg())
+    }
+
+    pub fn test_init_with_msg(
+        voters_number: u32,
+        prop_module_instantiate_msg: InstantiateMsg,
+    ) -> (
+        OsmosisTestApp,
+        HashMap<&'static str, String>,
+        SigningAccount,
+        Vec<SigningAccount>,
     ) {
         // Create new osmosis appchain instance
         let app = OsmosisTestApp::new();
@@ -115,23 +155,6 @@ pub mod test_tube {
                 initial_members,
             },
         };
-        let prop_module_instantiate_msg = InstantiateMsg {
-            threshold: Threshold::AbsoluteCount {
-                threshold: Uint128::new(2u128),
-            },
-            // TODO: Create an additional test variant as below
-            // threshold: Threshold::ThresholdQuorum {
-            //     threshold: PercentageThreshold,
-            //     quorum: PercentageThreshold,
-            // },
-            max_voting_period: Duration::Height(1), // 1 block only to make it expire after the proposing block
-            min_voting_period: None,
-            only_members_execute: true,
-            allow_revoting: false,
-            pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
-            close_proposal_on_execution_failure: true,
-            veto: None,
-        };
         let dao_dao_core_instantiate_msg = InstantiateMsgCore {
             admin: Some(admin.address()),
             name: "DAO DAO Core".to_string(),
@@ -324,24 +347,29 @@ pub mod test_tube {
             ProposalPayload {
                 msgs: vec![execute_propose_msg_pass.clone()],
                 nonce: NONCE.to_string(),
+                expiration: None,
             },
             // A <- will pass! 2/2 majority
             ProposalPayload {
                 msgs: vec![execute_propose_msg_pass.clone()],
                 nonce: NONCE.to_string(),
+                expiration: None,
             },
             // B <- all the rest doesnt count
             ProposalPayload {
                 msgs: vec![execute_propose_msg_fail.clone()],
                 nonce: NONCE.to_string(),
+                expiration: None,
             },
             ProposalPayload {
                 msgs: vec![execute_propose_msg_fail.clone()],
                 nonce: NONCE.to_string(),
+                expiration: None,
             },
             ProposalPayload {
                 msgs: vec![execute_propose_msg_fail.clone()],
                 nonce: NONCE.to_string(),
+                expiration: None,
             },
         ];
 
@@ -350,7 +378,10 @@ pub mod test_tube {
             // Ensure that there's a message for each voter
             if let Some(clear_message) = payloads.get(index) {
                 let clear_message_adr = create_adr36_message(
-                    &to_json_string(&clear_message).unwrap(),
+                    &signed_payload_json(
+                        contracts.get(SLUG_DAO_PROPOSAL_SINGLE_INSTANT).unwrap(),
+                        clear_message,
+                    ),
                     &voter.address(),
                 );
                 let message_hash = compute_sha256_hash(clear_message_adr.as_bytes());
@@ -363,6 +394,9 @@ pub mod test_tube {
                     message_hash,
                     signature: signature.as_ref().to_vec(),
                     public_key: voter.public_key().to_bytes(),
+                    key_type: KeyType::Secp256k1,
+                    vote: None,
+                    rationale: None,
                 });
             } else {
                 // Do nothing in the case where there's no message for a voter
@@ -379,6 +413,7 @@ pub mod test_tube {
                     payload: ProposalPayload {
                         msgs: vec![execute_propose_msg_pass.clone()],
                         nonce: NONCE.to_string(),
+                        expiration: None,
                     },
                     proposer: None,
                     vote_signatures,
@@ -444,6 +479,9 @@ pub mod test_tube {
                     message_hash,
                     signature: signature.as_ref().to_vec(),
                     public_key: voter.public_key().to_bytes(),
+                    key_type: KeyType::Secp256k1,
+                    vote: None,
+                    rationale: None,
                 });
             } else {
                 // Do nothing in the case where there's no message for a voter
@@ -460,6 +498,7 @@ pub mod test_tube {
                     payload: ProposalPayload {
                         msgs: vec![execute_propose_msg.clone()],
                         nonce: NONCE.to_string(),
+                        expiration: None,
                     },
                     proposer: None,
                     vote_signatures,
@@ -469,9 +508,10 @@ pub mod test_tube {
             )
             .unwrap_err();
 
-        // Assert that the response is an error of a specific type
+        // The signatures are not of the proposal's payload, so they are
+        // rejected and no votes are cast.
         assert!(
-            matches!(execute_propose_resp, ExecuteError { msg } if msg.contains("failed to execute message; message index: 0: proposal is not in 'passed' state: execute wasm contract failed"))
+            matches!(execute_propose_resp, ExecuteError { msg } if msg.contains("Not possible to reach required (passing) threshold"))
         );
     }
 
@@ -518,6 +558,9 @@ pub mod test_tube {
                     message_hash,
                     signature: signature.as_ref().to_vec(),
                     public_key: voter.public_key().to_bytes(),
+                    key_type: KeyType::Secp256k1,
+                    vote: None,
+                    rationale: None,
                 });
             } else {
                 // Do nothing in the case where there's no message for a voter
@@ -534,6 +577,7 @@ pub mod test_tube {
                     payload: ProposalPayload {
                         msgs: vec![execute_propose_msg.clone()],
                         nonce: NONCE.to_string(),
+                        expiration: None,
                     },
                     proposer: None,
                     vote_signatures,
@@ -591,11 +635,13 @@ pub mod test_tube {
             ProposalPayload {
                 msgs: vec![execute_propose_msg_pass.clone()],
                 nonce: NONCE.to_string(),
+                expiration: None,
             },
             // A <- will pass! 2/2 majority
             ProposalPayload {
                 msgs: vec![execute_propose_msg_pass.clone()],
                 nonce: NONCE.to_string(),
+                expiration: None,
             },
         ];
 
@@ -604,7 +650,10 @@ pub mod test_tube {
             // Ensure that there's a message for each voter
             if let Some(clear_message) = payloads.get(index) {
                 let clear_message_adr = create_adr36_message(
-                    &to_json_string(&clear_message).unwrap(),
+                    &signed_payload_json(
+                        contracts.get(SLUG_DAO_PROPOSAL_SINGLE_INSTANT).unwrap(),
+                        clear_message,
+                    ),
                     &voter.address(),
                 );
                 let message_hash = compute_sha256_hash(clear_message_adr.as_bytes());
@@ -617,6 +666,9 @@ pub mod test_tube {
                     message_hash,
                     signature: signature.as_ref().to_vec(),
                     public_key: voter.public_key().to_bytes(),
+                    key_type: KeyType::Secp256k1,
+                    vote: None,
+                    rationale: None,
                 });
             } else {
                 // Do nothing in the case where there's no message for a voter
@@ -633,6 +685,7 @@ pub mod test_tube {
                 payload: ProposalPayload {
                     msgs: vec![execute_propose_msg_pass.clone()],
                     nonce: NONCE.to_string(),
+                    expiration: None,
                 },
                 proposer: None,
                 vote_signatures: vote_signatures.clone(),
@@ -653,6 +706,7 @@ pub mod test_tube {
                     payload: ProposalPayload {
                         msgs: vec![execute_propose_msg_pass.clone()],
                         nonce: NONCE.to_string(),
+                        expiration: None,
                     },
                     proposer: None,
                     vote_signatures,
@@ -667,6 +721,1003 @@ pub mod test_tube {
         );
     }
 
+    /// Returns the document a voter signs for `payload` when voting on
+    /// the proposal module at `proposal_module`.
+    fn signed_payload_json(proposal_module: &str, payload: &ProposalPayload) -> String {
+        to_json_string(&SignedPayload {
+            chain_id: CHAIN_ID.to_string(),
+            contract_address: proposal_module.to_string(),
+            payload: payload.clone(),
+        })
+        .unwrap()
+    }
+
+    /// Signs the ADR-36 wrapped `payload`, bound to the instant
+    /// proposal module in `contracts`, with the voter's key.
+    fn sign_payload(
+        voter: &SigningAccount,
+        contracts: &HashMap<&'static str, String>,
+        payload: &ProposalPayload,
+    ) -> VoteSignature {
+        let document = signed_payload_json(
+            contracts.get(SLUG_DAO_PROPOSAL_SINGLE_INSTANT).unwrap(),
+            payload,
+        );
+        sign_document(voter, &document)
+    }
+
+    /// Signs an explicit `vote` on `payload`, bound to the instant
+    /// proposal module in `contracts`, with the voter's key.
+    fn sign_vote(
+        voter: &SigningAccount,
+        contracts: &HashMap<&'static str, String>,
+        payload: &ProposalPayload,
+        vote: Vote,
+        rationale: Option<String>,
+    ) -> VoteSignature {
+        let document = to_json_string(&SignedVote {
+            chain_id: CHAIN_ID.to_string(),
+            contract_address: contracts
+                .get(SLUG_DAO_PROPOSAL_SINGLE_INSTANT)
+                .unwrap()
+                .clone(),
+            payload: payload.clone(),
+            vote,
+            rationale: rationale.clone(),
+        })
+        .unwrap();
+        VoteSignature {
+            vote: Some(vote),
+            rationale,
+            ..sign_document(voter, &document)
+        }
+    }
+
+    /// Signs the ADR-36 wrapped `document` with the voter's key.
+    fn sign_document(voter: &SigningAccount, document: &str) -> VoteSignature {
+        let clear_message_adr = create_adr36_message(&document.to_string(), &voter.address());
+        let message_hash = compute_sha256_hash(clear_message_adr.as_bytes());
+        let signature = voter
+            .signing_key()
+            .sign(clear_message_adr.as_bytes())
+            .unwrap();
+        VoteSignature {
+            message_hash,
+            signature: signature.as_ref().to_vec(),
+            public_key: voter.public_key().to_bytes(),
+            key_type: KeyType::Secp256k1,
+            vote: None,
+            rationale: None,
+        }
+    }
+
+    #[test]
+    #[ignore]
+    /// Test case of a proposal executing a batch of messages atomically.
+    fn test_dao_proposal_single_instant_ok_batch() {
+        let (app, contracts, admin, voters) = test_init(2);
+        let bank = Bank::new(&app);
+        let wasm = Wasm::new(&app);
+        let treasury = contracts.get(SLUG_DAO_DAO_CORE).unwrap().clone();
+
+        // Fund the treasury.
+        let bank_send_amount = 1000u128;
+        bank.send(
+            MsgSend {
+                from_address: admin.address(),
+                to_address: treasury.clone(),
+                amount: vec![v1beta1::Coin {
+                    denom: INITIAL_BALANCE_DENOM.to_string(),
+                    amount: bank_send_amount.to_string(),
+                }],
+            },
+            &admin,
+        )
+        .unwrap();
+
+        // Split the treasury funds between the two voters.
+        let payload = ProposalPayload {
+            msgs: voters
+                .iter()
+                .map(|voter| {
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: voter.address(),
+                        amount: vec![Coin {
+                            denom: INITIAL_BALANCE_DENOM.to_string(),
+                            amount: Uint128::new(bank_send_amount / 2),
+                        }],
+                    })
+                })
+                .collect(),
+            nonce: NONCE.to_string(),
+            expiration: None,
+        };
+        let vote_signatures = voters
+            .iter()
+            .map(|voter| sign_payload(voter, &contracts, &payload))
+            .collect();
+
+        wasm.execute(
+            contracts.get(SLUG_DAO_PROPOSAL_SINGLE_INSTANT).unwrap(),
+            &ExecuteMsg::Propose(SingleChoiceInstantProposalMsg {
+                title: "Title".to_string(),
+                description: "Description".to_string(),
+                payload,
+                proposer: None,
+                vote_signatures,
+            }),
+            &vec![],
+            &admin,
+        )
+        .unwrap();
+
+        for voter in voters.iter() {
+            let balance = bank
+                .query_balance(&QueryBalanceRequest {
+                    address: voter.address(),
+                    denom: INITIAL_BALANCE_DENOM.to_string(),
+                })
+                .unwrap()
+                .balance
+                .expect("failed to query balance");
+            assert_eq!(balance.amount, (bank_send_amount / 2).to_string());
+        }
+        let treasury_balance = bank
+            .query_balance(&QueryBalanceRequest {
+                address: treasury,
+                denom: INITIAL_BALANCE_DENOM.to_string(),
+            })
+            .unwrap()
+            .balance
+            .expect("failed to query balance");
+        assert_eq!(treasury_balance.amount, "0");
+    }
+
+    #[test]
+    #[ignore]
+    /// Test case of malformed and non-member signatures being skipped
+    /// and reported, instead of aborting the proposal.
+    fn test_dao_proposal_single_instant_rejected_signatures() {
+        let (app, contracts, admin, voters) = test_init(2);
+        let bank = Bank::new(&app);
+        let wasm = Wasm::new(&app);
+
+        let bank_send_amount = 1000u128;
+        bank.send(
+            MsgSend {
+                from_address: admin.address(),
+                to_address: contracts.get(SLUG_DAO_DAO_CORE).unwrap().clone(),
+                amount: vec![v1beta1::Coin {
+                    denom: INITIAL_BALANCE_DENOM.to_string(),
+                    amount: bank_send_amount.to_string(),
+                }],
+            },
+            &admin,
+        )
+        .unwrap();
+
+        let payload = ProposalPayload {
+            msgs: vec![CosmosMsg::Bank(BankMsg::Send {
+                to_address: admin.address(),
+                amount: vec![Coin {
+                    denom: INITIAL_BALANCE_DENOM.to_string(),
+                    amount: Uint128::new(bank_send_amount),
+                }],
+            })],
+            nonce: NONCE.to_string(),
+            expiration: None,
+        };
+        let mut vote_signatures: Vec<VoteSignature> = voters
+            .iter()
+            .map(|voter| sign_payload(voter, &contracts, &payload))
+            .collect();
+        // 2: signature from an account with no voting power.
+        vote_signatures.push(sign_payload(&admin, &contracts, &payload));
+        // 3: signature that does not match its public key.
+        vote_signatures.push(VoteSignature {
+            signature: vote_signatures[0].signature.clone(),
+            ..vote_signatures[1].clone()
+        });
+        // 4: truncated public key.
+        vote_signatures.push(VoteSignature {
+            public_key: vec![2u8; 20],
+            ..vote_signatures[0].clone()
+        });
+
+        let resp = wasm
+            .execute(
+                contracts.get(SLUG_DAO_PROPOSAL_SINGLE_INSTANT).unwrap(),
+                &ExecuteMsg::Propose(SingleChoiceInstantProposalMsg {
+                    title: "Title".to_string(),
+                    description: "Description".to_string(),
+                    payload,
+                    proposer: None,
+                    vote_signatures,
+                }),
+                &vec![],
+                &admin,
+            )
+            .unwrap();
+
+        let rejected: Vec<String> = resp
+            .events
+            .iter()
+            .filter(|event| event.ty == "wasm")
+            .flat_map(|event| event.attributes.iter())
+            .filter(|attr| attr.key == "rejected_signature")
+            .map(|attr| attr.value.clone())
+            .collect();
+        assert_eq!(
+            rejected,
+            vec![
+                "2:non_member".to_string(),
+                "3:bad_signature".to_string(),
+                "4:bad_key_length".to_string(),
+            ]
+        );
+    }
+
+    /// Funds the treasury and returns a payload sending those funds
+    /// back to the admin.
+    fn fund_treasury_payload(
+        app: &OsmosisTestApp,
+        contracts: &HashMap<&'static str, String>,
+        admin: &SigningAccount,
+    ) -> ProposalPayload {
+        let bank_send_amount = 1000u128;
+        Bank::new(app)
+            .send(
+                MsgSend {
+                    from_address: admin.address(),
+                    to_address: contracts.get(SLUG_DAO_DAO_CORE).unwrap().clone(),
+                    amount: vec![v1beta1::Coin {
+                        denom: INITIAL_BALANCE_DENOM.to_string(),
+                        amount: bank_send_amount.to_string(),
+                    }],
+                },
+                admin,
+            )
+            .unwrap();
+
+        ProposalPayload {
+            msgs: vec![CosmosMsg::Bank(BankMsg::Send {
+                to_address: admin.address(),
+                amount: vec![Coin {
+                    denom: INITIAL_BALANCE_DENOM.to_string(),
+                    amount: Uint128::new(bank_send_amount),
+                }],
+            })],
+            nonce: NONCE.to_string(),
+            expiration: None,
+        }
+    }
+
+    #[test]
+    #[ignore]
+    /// Test case of a single signer submitting their signature twice
+    /// to reach an absolute count threshold of two. The duplicate is
+    /// not counted, so the proposal is left open, and passes once the
+    /// other member votes on-chain.
+    fn test_dao_proposal_single_instant_duplicate_signer_left_open() {
+        let (app, contracts, admin, voters) = test_init_with_msg(
+            2,
+            InstantiateMsg {
+                max_voting_period: Duration::Height(10),
+                ..default_instantiate_msg()
+            },
+        );
+        let wasm = Wasm::new(&app);
+        let proposal_module = contracts.get(SLUG_DAO_PROPOSAL_SINGLE_INSTANT).unwrap();
+        let payload = fund_treasury_payload(&app, &contracts, &admin);
+        let query_status = || {
+            wasm.query::<QueryMsg, ProposalResponse>(
+                proposal_module,
+                &QueryMsg::Proposal { proposal_id: 1 },
+            )
+            .unwrap()
+            .proposal
+            .status
+        };
+
+        let signature = sign_payload(&voters[0], &contracts, &payload);
+        wasm.execute(
+            proposal_module,
+            &ExecuteMsg::Propose(SingleChoiceInstantProposalMsg {
+                title: "Title".to_string(),
+                description: "Description".to_string(),
+                payload,
+                proposer: None,
+                vote_signatures: vec![signature.clone(), signature],
+            }),
+            &vec![],
+            &admin,
+        )
+        .unwrap();
+        assert_eq!(query_status(), Status::Open);
+
+        wasm.execute(
+            proposal_module,
+            &ExecuteMsg::Vote {
+                proposal_id: 1,
+                vote: Vote::Yes,
+                rationale: None,
+            },
+            &vec![],
+            &voters[1],
+        )
+        .unwrap();
+        assert_eq!(query_status(), Status::Passed);
+
+        wasm.execute(
+            proposal_module,
+            &ExecuteMsg::Execute { proposal_id: 1 },
+            &vec![],
+            &admin,
+        )
+        .unwrap();
+        assert_eq!(query_status(), Status::Executed);
+    }
+
+    #[test]
+    #[ignore]
+    /// Test case of duplicate signatures mixed with unique ones. Only
+    /// the first signature of each signer is counted, and signatures
+    /// of a different payload are rejected rather than counted as
+    /// `No`.
+    fn test_dao_proposal_single_instant_mixed_duplicate_signers() {
+        let (app, contracts, admin, voters) = test_init(3);
+        let wasm = Wasm::new(&app);
+        let payload = fund_treasury_payload(&app, &contracts, &admin);
+        let other_payload = ProposalPayload {
+            msgs: vec![CosmosMsg::Bank(BankMsg::Send {
+                to_address: voters[2].address(),
+                amount: vec![Coin {
+                    denom: INITIAL_BALANCE_DENOM.to_string(),
+                    amount: Uint128::new(1),
+                }],
+            })],
+            nonce: NONCE.to_string(),
+            expiration: None,
+        };
+
+        let vote_signatures = vec![
+            sign_payload(&voters[0], &contracts, &payload),
+            sign_payload(&voters[0], &contracts, &payload),
+            sign_payload(&voters[1], &contracts, &payload),
+            sign_payload(&voters[1], &contracts, &other_payload),
+            sign_payload(&voters[2], &contracts, &other_payload),
+            sign_payload(&voters[2], &contracts, &other_payload),
+        ];
+
+        let resp = wasm
+            .execute(
+                contracts.get(SLUG_DAO_PROPOSAL_SINGLE_INSTANT).unwrap(),
+                &ExecuteMsg::Propose(SingleChoiceInstantProposalMsg {
+                    title: "Title".to_string(),
+                    description: "Description".to_string(),
+                    payload,
+                    proposer: None,
+                    vote_signatures,
+                }),
+                &vec![],
+                &admin,
+            )
+            .unwrap();
+
+        let attribute_values = |key: &str| -> Vec<String> {
+            resp.events
+                .iter()
+                .filter(|event| event.ty == "wasm")
+                .flat_map(|event| event.attributes.iter())
+                .filter(|attr| attr.key == key)
+                .map(|attr| attr.value.clone())
+                .collect()
+        };
+        assert_eq!(
+            attribute_values("rejected_signature"),
+            vec![
+                "1:duplicate_signer".to_string(),
+                "3:vote_mismatch".to_string(),
+                "4:vote_mismatch".to_string(),
+                "5:vote_mismatch".to_string(),
+            ]
+        );
+        // One ballot per signer of the proposal's payload.
+        assert_eq!(
+            attribute_values("position"),
+            vec!["yes".to_string(), "yes".to_string()]
+        );
+    }
+
+    #[test]
+    #[ignore]
+    /// Test case of signatures bound to another chain or another
+    /// contract. They are rejected, so the proposal does not pass.
+    fn test_dao_proposal_single_instant_ko_foreign_domain() {
+        let (app, contracts, admin, voters) = test_init(2);
+        let wasm = Wasm::new(&app);
+        let payload = fund_treasury_payload(&app, &contracts, &admin);
+
+        let foreign_documents = [
+            // Replayed from another chain.
+            to_json_string(&SignedPayload {
+                chain_id: "osmo-test-5".to_string(),
+                contract_address: contracts
+                    .get(SLUG_DAO_PROPOSAL_SINGLE_INSTANT)
+                    .unwrap()
+                    .clone(),
+                payload: payload.clone(),
+            })
+            .unwrap(),
+            // Replayed from another contract.
+            signed_payload_json(contracts.get(SLUG_DAO_DAO_CORE).unwrap(), &payload),
+        ];
+        for document in foreign_documents {
+            let vote_signatures = voters
+                .iter()
+                .map(|voter| sign_document(voter, &document))
+                .collect();
+            let execute_propose_resp = wasm
+                .execute(
+                    contracts.get(SLUG_DAO_PROPOSAL_SINGLE_INSTANT).unwrap(),
+                    &ExecuteMsg::Propose(SingleChoiceInstantProposalMsg {
+                        title: "Title".to_string(),
+                        description: "Description".to_string(),
+                        payload: payload.clone(),
+                        proposer: None,
+                        vote_signatures,
+                    }),
+                    &vec![],
+                    &admin,
+                )
+                .unwrap_err();
+            assert!(
+                matches!(execute_propose_resp, ExecuteError { msg } if msg.contains("Not possible to reach required (passing) threshold"))
+            );
+        }
+    }
+
+    #[test]
+    #[ignore]
+    /// Test case of signatures over the bare payload. They are only
+    /// accepted when `allow_legacy_signatures` is set.
+    fn test_dao_proposal_single_instant_legacy_signatures() {
+        for allow_legacy_signatures in [false, true] {
+            let (app, contracts, admin, voters) = test_init_with_msg(
+                2,
+                InstantiateMsg {
+                    allow_legacy_signatures,
+                    ..default_instantiate_msg()
+                },
+            );
+            let wasm = Wasm::new(&app);
+            let payload = fund_treasury_payload(&app, &contracts, &admin);
+
+            let legacy_document = to_json_string(&payload).unwrap();
+            let vote_signatures = voters
+                .iter()
+                .map(|voter| sign_document(voter, &legacy_document))
+                .collect();
+            let execute_propose_resp = wasm.execute(
+                contracts.get(SLUG_DAO_PROPOSAL_SINGLE_INSTANT).unwrap(),
+                &ExecuteMsg::Propose(SingleChoiceInstantProposalMsg {
+                    title: "Title".to_string(),
+                    description: "Description".to_string(),
+                    payload,
+                    proposer: None,
+                    vote_signatures,
+                }),
+                &vec![],
+                &admin,
+            );
+            assert_eq!(execute_propose_resp.is_ok(), allow_legacy_signatures);
+        }
+    }
+
+    #[test]
+    #[ignore]
+    /// Test case of signers casting explicit votes with a rationale.
+    /// A signature whose vote does not match the signed document is
+    /// rejected rather than counted as `No`.
+    fn test_dao_proposal_single_instant_explicit_votes() {
+        let (app, contracts, admin, voters) = test_init(4);
+        let wasm = Wasm::new(&app);
+        let payload = fund_treasury_payload(&app, &contracts, &admin);
+
+        let mut mismatched = sign_vote(&voters[3], &contracts, &payload, Vote::Yes, None);
+        mismatched.vote = Some(Vote::No);
+        let vote_signatures = vec![
+            sign_vote(
+                &voters[0],
+                &contracts,
+                &payload,
+                Vote::Yes,
+                Some("refund the admin".to_string()),
+            ),
+            sign_payload(&voters[1], &contracts, &payload),
+            sign_vote(
+                &voters[2],
+                &contracts,
+                &payload,
+                Vote::Abstain,
+                Some("conflict of interest".to_string()),
+            ),
+            mismatched,
+        ];
+
+        let resp = wasm
+            .execute(
+                contracts.get(SLUG_DAO_PROPOSAL_SINGLE_INSTANT).unwrap(),
+                &ExecuteMsg::Propose(SingleChoiceInstantProposalMsg {
+                    title: "Title".to_string(),
+                    description: "Description".to_string(),
+                    payload,
+                    proposer: None,
+                    vote_signatures,
+                }),
+                &vec![],
+                &admin,
+            )
+            .unwrap();
+        let rejected: Vec<String> = resp
+            .events
+            .iter()
+            .filter(|event| event.ty == "wasm")
+            .flat_map(|event| event.attributes.iter())
+            .filter(|attr| attr.key == "rejected_signature")
+            .map(|attr| attr.value.clone())
+            .collect();
+        assert_eq!(rejected, vec!["3:vote_mismatch".to_string()]);
+        // The reported status is the one after the votes are cast and
+        // the proposal is executed.
+        assert!(resp.events.iter().any(|event| event
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "status" && attr.value == "executed")));
+
+        let get_vote = |voter: &SigningAccount| {
+            wasm.query::<QueryMsg, VoteResponse>(
+                contracts.get(SLUG_DAO_PROPOSAL_SINGLE_INSTANT).unwrap(),
+                &QueryMsg::GetVote {
+                    proposal_id: 1,
+                    voter: voter.address(),
+                },
+            )
+            .unwrap()
+            .vote
+        };
+        let yes = get_vote(&voters[0]).unwrap();
+        assert_eq!(yes.vote, Vote::Yes);
+        assert_eq!(yes.rationale, Some("refund the admin".to_string()));
+        let implicit_yes = get_vote(&voters[1]).unwrap();
+        assert_eq!(implicit_yes.vote, Vote::Yes);
+        assert_eq!(implicit_yes.rationale, None);
+        let abstain = get_vote(&voters[2]).unwrap();
+        assert_eq!(abstain.vote, Vote::Abstain);
+        assert_eq!(abstain.rationale, Some("conflict of interest".to_string()));
+        assert!(get_vote(&voters[3]).is_none());
+    }
+
+    #[test]
+    #[ignore]
+    /// Test case of a proposal collecting its signatures over several
+    /// transactions before passing and being executed.
+    fn test_dao_proposal_single_instant_submit_signatures() {
+        let (app, contracts, admin, voters) = test_init_with_msg(
+            2,
+            InstantiateMsg {
+                max_voting_period: Duration::Height(10),
+                ..default_instantiate_msg()
+            },
+        );
+        let wasm = Wasm::new(&app);
+        let proposal_module = contracts.get(SLUG_DAO_PROPOSAL_SINGLE_INSTANT).unwrap();
+        let payload = fund_treasury_payload(&app, &contracts, &admin);
+        let query_status = || {
+            wasm.query::<QueryMsg, ProposalResponse>(
+                proposal_module,
+                &QueryMsg::Proposal { proposal_id: 1 },
+            )
+            .unwrap()
+            .proposal
+            .status
+        };
+
+        // The first signature does not reach the threshold, so the
+        // proposal is left open.
+        let first_signature = sign_payload(&voters[0], &contracts, &payload);
+        wasm.execute(
+            proposal_module,
+            &ExecuteMsg::ProposeForSignatures(SingleChoiceInstantProposalMsg {
+                title: "Title".to_string(),
+                description: "Description".to_string(),
+                payload: payload.clone(),
+                proposer: None,
+                vote_signatures: vec![first_signature.clone()],
+            }),
+            &vec![],
+            &admin,
+        )
+        .unwrap();
+        assert_eq!(query_status(), Status::Open);
+        assert_eq!(
+            wasm.query::<QueryMsg, Option<ProposalPayload>>(
+                proposal_module,
+                &QueryMsg::Payload { proposal_id: 1 },
+            )
+            .unwrap(),
+            Some(payload.clone())
+        );
+
+        // Signers who already voted are not counted again.
+        let resp = wasm
+            .execute(
+                proposal_module,
+                &ExecuteMsg::SubmitSignatures {
+                    proposal_id: 1,
+                    signatures: vec![first_signature],
+                },
+                &vec![],
+                &admin,
+            )
+            .unwrap();
+        assert!(resp
+            .events
+            .iter()
+            .any(|event| event.attributes.iter().any(
+                |attr| attr.key == "rejected_signature" && attr.value == "0:duplicate_signer"
+            )));
+        assert_eq!(query_status(), Status::Open);
+
+        // The second signature passes the proposal, which is executed.
+        wasm.execute(
+            proposal_module,
+            &ExecuteMsg::SubmitSignatures {
+                proposal_id: 1,
+                signatures: vec![sign_payload(&voters[1], &contracts, &payload)],
+            },
+            &vec![],
+            &admin,
+        )
+        .unwrap();
+        assert_eq!(query_status(), Status::Executed);
+
+        let err = wasm
+            .execute(
+                proposal_module,
+                &ExecuteMsg::SubmitSignatures {
+                    proposal_id: 1,
+                    signatures: vec![],
+                },
+                &vec![],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(matches!(err, ExecuteError { msg } if msg.contains("proposal (1) is not open")));
+    }
+
+    #[test]
+    #[ignore]
+    /// Test case of signatures submitted by an address which may not
+    /// execute proposals. The votes are counted, and the passed
+    /// proposal is left to be executed by a member.
+    fn test_dao_proposal_single_instant_submit_signatures_non_member() {
+        let (app, contracts, admin, voters) = test_init_with_msg(
+            2,
+            InstantiateMsg {
+                max_voting_period: Duration::Height(10),
+                ..default_instantiate_msg()
+            },
+        );
+        let wasm = Wasm::new(&app);
+        let proposal_module = contracts.get(SLUG_DAO_PROPOSAL_SINGLE_INSTANT).unwrap();
+        let payload = fund_treasury_payload(&app, &contracts, &admin);
+        let non_member = app
+            .init_account(&[Coin::new(INITIAL_BALANCE_AMOUNT, "uosmo")])
+            .unwrap();
+        let query_status = || {
+            wasm.query::<QueryMsg, ProposalResponse>(
+                proposal_module,
+                &QueryMsg::Proposal { proposal_id: 1 },
+            )
+            .unwrap()
+            .proposal
+            .status
+        };
+
+        wasm.execute(
+            proposal_module,
+            &ExecuteMsg::ProposeForSignatures(SingleChoiceInstantProposalMsg {
+                title: "Title".to_string(),
+                description: "Description".to_string(),
+                payload: payload.clone(),
+                proposer: None,
+                vote_signatures: vec![sign_payload(&voters[0], &contracts, &payload)],
+            }),
+            &vec![],
+            &admin,
+        )
+        .unwrap();
+
+        wasm.execute(
+            proposal_module,
+            &ExecuteMsg::SubmitSignatures {
+                proposal_id: 1,
+                signatures: vec![sign_payload(&voters[1], &contracts, &payload)],
+            },
+            &vec![],
+            &non_member,
+        )
+        .unwrap();
+        assert!(wasm
+            .query::<QueryMsg, VoteResponse>(
+                proposal_module,
+                &QueryMsg::GetVote {
+                    proposal_id: 1,
+                    voter: voters[1].address(),
+                },
+            )
+            .unwrap()
+            .vote
+            .is_some());
+        assert_eq!(query_status(), Status::Passed);
+
+        wasm.execute(
+            proposal_module,
+            &ExecuteMsg::Execute { proposal_id: 1 },
+            &vec![],
+            &admin,
+        )
+        .unwrap();
+        assert_eq!(query_status(), Status::Executed);
+    }
+
+    #[test]
+    #[ignore]
+    /// Test case of signatures submitted after the proposal's voting
+    /// period, or for a proposal that is not collecting signatures.
+    fn test_dao_proposal_single_instant_submit_signatures_ko() {
+        let (app, contracts, admin, voters) = test_init(2);
+        let wasm = Wasm::new(&app);
+        let proposal_module = contracts.get(SLUG_DAO_PROPOSAL_SINGLE_INSTANT).unwrap();
+        let payload = fund_treasury_payload(&app, &contracts, &admin);
+
+        // The voting period is a single block, so the proposal has
+        // expired by the time signatures are submitted.
+        wasm.execute(
+            proposal_module,
+            &ExecuteMsg::ProposeForSignatures(SingleChoiceInstantProposalMsg {
+                title: "Title".to_string(),
+                description: "Description".to_string(),
+                payload: payload.clone(),
+                proposer: None,
+                vote_signatures: vec![],
+            }),
+            &vec![],
+            &admin,
+        )
+        .unwrap();
+        let err = wasm
+            .execute(
+                proposal_module,
+                &ExecuteMsg::SubmitSignatures {
+                    proposal_id: 1,
+                    signatures: vec![sign_payload(&voters[0], &contracts, &payload)],
+                },
+                &vec![],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(matches!(err, ExecuteError { msg } if msg.contains("Proposal (1) is expired")));
+
+        let err = wasm
+            .execute(
+                proposal_module,
+                &ExecuteMsg::SubmitSignatures {
+                    proposal_id: 2,
+                    signatures: vec![],
+                },
+                &vec![],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(matches!(err, ExecuteError { msg } if msg.contains("no such proposal (2)")));
+    }
+
+    #[test]
+    #[ignore]
+    /// Test case of a proposal collecting on-chain votes, which is
+    /// then executed separately.
+    fn test_dao_proposal_single_instant_vote_and_execute() {
+        let (app, contracts, admin, voters) = test_init_with_msg(
+            2,
+            InstantiateMsg {
+                max_voting_period: Duration::Height(10),
+                ..default_instantiate_msg()
+            },
+        );
+        let wasm = Wasm::new(&app);
+        let proposal_module = contracts.get(SLUG_DAO_PROPOSAL_SINGLE_INSTANT).unwrap();
+        let payload = fund_treasury_payload(&app, &contracts, &admin);
+        let query_status = || {
+            wasm.query::<QueryMsg, ProposalResponse>(
+                proposal_module,
+                &QueryMsg::Proposal { proposal_id: 1 },
+            )
+            .unwrap()
+            .proposal
+            .status
+        };
+
+        wasm.execute(
+            proposal_module,
+            &ExecuteMsg::ProposeForSignatures(SingleChoiceInstantProposalMsg {
+                title: "Title".to_string(),
+                description: "Description".to_string(),
+                payload,
+                proposer: None,
+                vote_signatures: vec![],
+            }),
+            &vec![],
+            &admin,
+        )
+        .unwrap();
+
+        for voter in voters.iter() {
+            wasm.execute(
+                proposal_module,
+                &ExecuteMsg::Vote {
+                    proposal_id: 1,
+                    vote: Vote::Yes,
+                    rationale: None,
+                },
+                &vec![],
+                voter,
+            )
+            .unwrap();
+        }
+        assert_eq!(query_status(), Status::Passed);
+
+        wasm.execute(
+            proposal_module,
+            &ExecuteMsg::Execute { proposal_id: 1 },
+            &vec![],
+            &admin,
+        )
+        .unwrap();
+        assert_eq!(query_status(), Status::Executed);
+    }
+
+    #[test]
+    #[ignore]
+    /// Test case of an instant proposal passing into its veto
+    /// timelock. It is left there instead of failing, and may be
+    /// executed once the timelock ends.
+    fn test_dao_proposal_single_instant_veto_timelock() {
+        let (app, contracts, admin, voters) = test_init_with_msg(
+            2,
+            InstantiateMsg {
+                veto: Some(VetoConfig {
+                    timelock_duration: Duration::Height(2),
+                    vetoer: "osmo1x36slx9at870e9rd53d2405n80s4ff94kp6e6f".to_string(),
+                    early_execute: false,
+                    veto_before_passed: false,
+                }),
+                ..default_instantiate_msg()
+            },
+        );
+        let wasm = Wasm::new(&app);
+        let proposal_module = contracts.get(SLUG_DAO_PROPOSAL_SINGLE_INSTANT).unwrap();
+        let payload = fund_treasury_payload(&app, &contracts, &admin);
+        let query_status = || {
+            wasm.query::<QueryMsg, ProposalResponse>(
+                proposal_module,
+                &QueryMsg::Proposal { proposal_id: 1 },
+            )
+            .unwrap()
+            .proposal
+            .status
+        };
+
+        let vote_signatures = voters
+            .iter()
+            .map(|voter| sign_payload(voter, &contracts, &payload))
+            .collect();
+        wasm.execute(
+            proposal_module,
+            &ExecuteMsg::Propose(SingleChoiceInstantProposalMsg {
+                title: "Title".to_string(),
+                description: "Description".to_string(),
+                payload,
+                proposer: None,
+                vote_signatures,
+            }),
+            &vec![],
+            &admin,
+        )
+        .unwrap();
+        assert!(matches!(query_status(), Status::VetoTimelock { .. }));
+
+        let err = wasm
+            .execute(
+                proposal_module,
+                &ExecuteMsg::Execute { proposal_id: 1 },
+                &vec![],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(matches!(err, ExecuteError { msg } if msg.contains("The proposal is timelocked")));
+
+        // Let the voting period and the timelock end.
+        for _ in 0..3 {
+            app.increase_time(5);
+        }
+        wasm.execute(
+            proposal_module,
+            &ExecuteMsg::Execute { proposal_id: 1 },
+            &vec![],
+            &admin,
+        )
+        .unwrap();
+        assert_eq!(query_status(), Status::Executed);
+    }
+
+    #[test]
+    #[ignore]
+    /// Test case of time-bound payloads. An expired payload can no
+    /// longer be proposed, and used nonces can be queried.
+    fn test_dao_proposal_single_instant_payload_expiration() {
+        let (app, contracts, admin, voters) = test_init(2);
+        let wasm = Wasm::new(&app);
+        let proposal_module = contracts.get(SLUG_DAO_PROPOSAL_SINGLE_INSTANT).unwrap();
+        let height = app.get_block_height() as u64;
+
+        let propose = |payload: ProposalPayload| {
+            let vote_signatures = voters
+                .iter()
+                .map(|voter| sign_payload(voter, &contracts, &payload))
+                .collect();
+            wasm.execute(
+                proposal_module,
+                &ExecuteMsg::Propose(SingleChoiceInstantProposalMsg {
+                    title: "Title".to_string(),
+                    description: "Description".to_string(),
+                    payload,
+                    proposer: None,
+                    vote_signatures,
+                }),
+                &vec![],
+                &admin,
+            )
+        };
+        let is_nonce_used = |nonce: &str| {
+            wasm.query::<QueryMsg, bool>(
+                proposal_module,
+                &QueryMsg::IsNonceUsed {
+                    nonce: nonce.to_string(),
+                },
+            )
+            .unwrap()
+        };
+
+        let stale_payload = ProposalPayload {
+            expiration: Some(Expiration::AtHeight(height)),
+            ..fund_treasury_payload(&app, &contracts, &admin)
+        };
+        let err = propose(stale_payload).unwrap_err();
+        assert!(
+            matches!(err, ExecuteError { msg } if msg.contains("proposal payload has expired"))
+        );
+        assert!(!is_nonce_used(NONCE));
+
+        let payload = ProposalPayload {
+            expiration: Some(Expiration::AtHeight(height + 100)),
+            ..fund_treasury_payload(&app, &contracts, &admin)
+        };
+        propose(payload).unwrap();
+        assert!(is_nonce_used(NONCE));
+    }
+
     #[test]
     #[ignore]
     fn test_secp256k1_verify() {
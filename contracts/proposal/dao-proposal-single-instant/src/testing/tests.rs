@@ -1,7 +1,7 @@
 use std::ops::Add;
 
 use crate::{
-    msg::{ProposalPayload, SingleChoiceInstantProposalMsg as ProposeMsg},
+    msg::{ProposalPayload, SingleChoiceInstantProposalMsg as ProposeMsg, MAX_PAYLOAD_MSGS},
     testing::test_tube::test_tube::NONCE,
 };
 use cosmwasm_std::{
@@ -359,13 +359,13 @@ fn test_proposal_message_execution() {
 
     // Can't use library function because we expect this to fail due
     // to insufficent balance in the bank module.
-    // app.execute_contract(
-    //     Addr::unchecked(CREATOR_ADDR),
-    //     proposal_module.clone(),
-    //     &ExecuteMsg::Execute { proposal_id },
-    //     &[],
-    // )
-    // .unwrap_err();
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        proposal_module.clone(),
+        &ExecuteMsg::Execute { proposal_id },
+        &[],
+    )
+    .unwrap_err();
     let proposal = query_proposal(&app, &proposal_module, proposal_id);
     assert_eq!(proposal.proposal.status, Status::Passed);
 
@@ -466,31 +466,31 @@ fn test_proposal_message_timelock_execution() -> anyhow::Result<()> {
 
     // vetoer can't execute when timelock is active and
     // early execute not enabled.
-    // let err: ContractError = app
-    //     .execute_contract(
-    //         Addr::unchecked("oversight"),
-    //         proposal_module.clone(),
-    //         &ExecuteMsg::Execute { proposal_id },
-    //         &[],
-    //     )
-    //     .unwrap_err()
-    //     .downcast()
-    //     .unwrap();
-    // assert_eq!(err, ContractError::VetoError(VetoError::NoEarlyExecute {}));
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("oversight"),
+            proposal_module.clone(),
+            &ExecuteMsg::Execute { proposal_id },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::VetoError(VetoError::NoEarlyExecute {}));
 
     // Proposal cannot be excuted before timelock expires
-    // let err: ContractError = app
-    //     .execute_contract(
-    //         Addr::unchecked(CREATOR_ADDR),
-    //         proposal_module.clone(),
-    //         &ExecuteMsg::Execute { proposal_id },
-    //         &[],
-    //     )
-    //     .unwrap_err()
-    //     .downcast()
-    //     .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Execute { proposal_id },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
 
-    // assert_eq!(err, ContractError::VetoError(VetoError::Timelocked {}));
+    assert_eq!(err, ContractError::VetoError(VetoError::Timelocked {}));
 
     // Time passes
     app.update_block(|block| {
@@ -554,17 +554,17 @@ fn test_open_proposal_veto_unauthorized() {
     );
 
     // only the vetoer can veto
-    // let err: ContractError = app
-    //     .execute_contract(
-    //         Addr::unchecked("not-oversight"),
-    //         proposal_module.clone(),
-    //         &ExecuteMsg::Veto { proposal_id },
-    //         &[],
-    //     )
-    //     .unwrap_err()
-    //     .downcast()
-    //     .unwrap();
-    // assert_eq!(err, ContractError::VetoError(VetoError::Unauthorized {}));
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("not-oversight"),
+            proposal_module.clone(),
+            &ExecuteMsg::Veto { proposal_id },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::VetoError(VetoError::Unauthorized {}));
 }
 
 // open proposal can only be vetoed if `veto_before_passed` flag is enabled
@@ -615,20 +615,20 @@ fn test_open_proposal_veto_with_early_veto_flag_disabled() {
         ],
     );
 
-    // let err: ContractError = app
-    //     .execute_contract(
-    //         Addr::unchecked("oversight"),
-    //         proposal_module.clone(),
-    //         &ExecuteMsg::Veto { proposal_id },
-    //         &[],
-    //     )
-    //     .unwrap_err()
-    //     .downcast()
-    //     .unwrap();
-    // assert_eq!(
-    //     err,
-    //     ContractError::VetoError(VetoError::NoVetoBeforePassed {})
-    // );
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("oversight"),
+            proposal_module.clone(),
+            &ExecuteMsg::Veto { proposal_id },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::VetoError(VetoError::NoVetoBeforePassed {})
+    );
 }
 
 #[test]
@@ -672,20 +672,20 @@ fn test_open_proposal_veto_with_no_timelock() {
         ],
     );
 
-    // let err: ContractError = app
-    //     .execute_contract(
-    //         Addr::unchecked("oversight"),
-    //         proposal_module.clone(),
-    //         &ExecuteMsg::Veto { proposal_id },
-    //         &[],
-    //     )
-    //     .unwrap_err()
-    //     .downcast()
-    //     .unwrap();
-    // assert_eq!(
-    //     err,
-    //     ContractError::VetoError(VetoError::NoVetoConfiguration {})
-    // );
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("oversight"),
+            proposal_module.clone(),
+            &ExecuteMsg::Veto { proposal_id },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::VetoError(VetoError::NoVetoConfiguration {})
+    );
 }
 
 // if proposal is not open or timelocked, attempts to veto should
@@ -737,34 +737,34 @@ fn test_vetoed_proposal_veto() {
         ],
     );
 
-    // app.execute_contract(
-    //     Addr::unchecked("oversight"),
-    //     proposal_module.clone(),
-    //     &ExecuteMsg::Veto { proposal_id },
-    //     &[],
-    // )
-    // .unwrap();
+    app.execute_contract(
+        Addr::unchecked("oversight"),
+        proposal_module.clone(),
+        &ExecuteMsg::Veto { proposal_id },
+        &[],
+    )
+    .unwrap();
 
-    // let proposal = query_proposal(&app, &proposal_module, proposal_id);
-    // assert_eq!(proposal.proposal.status, Status::Vetoed {});
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Vetoed {});
 
-    // let err: ContractError = app
-    //     .execute_contract(
-    //         Addr::unchecked("oversight"),
-    //         proposal_module.clone(),
-    //         &ExecuteMsg::Veto { proposal_id },
-    //         &[],
-    //     )
-    //     .unwrap_err()
-    //     .downcast()
-    //     .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("oversight"),
+            proposal_module.clone(),
+            &ExecuteMsg::Veto { proposal_id },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
 
-    // assert_eq!(
-    //     ContractError::VetoError(VetoError::InvalidProposalStatus {
-    //         status: "vetoed".to_string()
-    //     }),
-    //     err,
-    // );
+    assert_eq!(
+        ContractError::VetoError(VetoError::InvalidProposalStatus {
+            status: "vetoed".to_string()
+        }),
+        err,
+    );
 }
 
 #[test]
@@ -814,13 +814,13 @@ fn test_open_proposal_veto_early() {
         ],
     );
 
-    // app.execute_contract(
-    //     Addr::unchecked("oversight"),
-    //     proposal_module.clone(),
-    //     &ExecuteMsg::Veto { proposal_id },
-    //     &[],
-    // )
-    // .unwrap();
+    app.execute_contract(
+        Addr::unchecked("oversight"),
+        proposal_module.clone(),
+        &ExecuteMsg::Veto { proposal_id },
+        &[],
+    )
+    .unwrap();
 
     let proposal = query_proposal(&app, &proposal_module, proposal_id);
     assert_eq!(proposal.proposal.status, Status::Vetoed {});
@@ -900,18 +900,18 @@ fn test_timelocked_proposal_veto_unauthorized() -> anyhow::Result<()> {
         }
     );
 
-    // let err: ContractError = app
-    //     .execute_contract(
-    //         Addr::unchecked("not-oversight"),
-    //         proposal_module.clone(),
-    //         &ExecuteMsg::Veto { proposal_id },
-    //         &[],
-    //     )
-    //     .unwrap_err()
-    //     .downcast()
-    //     .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("not-oversight"),
+            proposal_module.clone(),
+            &ExecuteMsg::Veto { proposal_id },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
 
-    // assert_eq!(err, ContractError::VetoError(VetoError::Unauthorized {}),);
+    assert_eq!(err, ContractError::VetoError(VetoError::Unauthorized {}),);
     let proposal = query_proposal(&app, &proposal_module, proposal_id);
     assert_eq!(
         proposal.proposal.status,
@@ -1001,18 +1001,18 @@ fn test_timelocked_proposal_veto_expired_timelock() -> anyhow::Result<()> {
     );
     app.update_block(|b| b.time = b.time.plus_seconds(604800 + 200));
 
-    // let err: ContractError = app
-    //     .execute_contract(
-    //         Addr::unchecked("oversight"),
-    //         proposal_module.clone(),
-    //         &ExecuteMsg::Veto { proposal_id },
-    //         &[],
-    //     )
-    //     .unwrap_err()
-    //     .downcast()
-    //     .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("oversight"),
+            proposal_module.clone(),
+            &ExecuteMsg::Veto { proposal_id },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
 
-    // assert_eq!(err, ContractError::VetoError(VetoError::TimelockExpired {}),);
+    assert_eq!(err, ContractError::VetoError(VetoError::TimelockExpired {}),);
 
     Ok(())
 }
@@ -1085,18 +1085,18 @@ fn test_timelocked_proposal_execute_no_early_exec() -> anyhow::Result<()> {
         }
     );
 
-    // let err: ContractError = app
-    //     .execute_contract(
-    //         Addr::unchecked("oversight"),
-    //         proposal_module.clone(),
-    //         &ExecuteMsg::Execute { proposal_id },
-    //         &[],
-    //     )
-    //     .unwrap_err()
-    //     .downcast()
-    //     .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("oversight"),
+            proposal_module.clone(),
+            &ExecuteMsg::Execute { proposal_id },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
 
-    // assert_eq!(err, ContractError::VetoError(VetoError::NoEarlyExecute {}),);
+    assert_eq!(err, ContractError::VetoError(VetoError::NoEarlyExecute {}),);
 
     Ok(())
 }
@@ -1175,13 +1175,13 @@ fn test_timelocked_proposal_execute_early() -> anyhow::Result<()> {
         .is_expired(&app.block_info()));
     mint_natives(&mut app, core_addr.as_str(), coins(10, "ujuno"));
 
-    // app.execute_contract(
-    //     Addr::unchecked("oversight"),
-    //     proposal_module.clone(),
-    //     &ExecuteMsg::Execute { proposal_id },
-    //     &[],
-    // )
-    // .unwrap();
+    app.execute_contract(
+        Addr::unchecked("oversight"),
+        proposal_module.clone(),
+        &ExecuteMsg::Execute { proposal_id },
+        &[],
+    )
+    .unwrap();
 
     let proposal = query_proposal(&app, &proposal_module, proposal_id);
     assert_eq!(proposal.proposal.status, Status::Executed {});
@@ -1263,18 +1263,18 @@ fn test_timelocked_proposal_execute_active_timelock_unauthorized() -> anyhow::Re
         .after(&app.block_info())
         .is_expired(&app.block_info()));
 
-    // let err: ContractError = app
-    //     .execute_contract(
-    //         Addr::unchecked(CREATOR_ADDR),
-    //         proposal_module.clone(),
-    //         &ExecuteMsg::Execute { proposal_id },
-    //         &[],
-    //     )
-    //     .unwrap_err()
-    //     .downcast()
-    //     .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Execute { proposal_id },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
 
-    // assert_eq!(err, ContractError::VetoError(VetoError::Timelocked {}),);
+    assert_eq!(err, ContractError::VetoError(VetoError::Timelocked {}),);
 
     Ok(())
 }
@@ -1351,13 +1351,13 @@ fn test_timelocked_proposal_execute_expired_timelock_not_vetoer() -> anyhow::Res
     assert!(expiration.is_expired(&app.block_info()));
     mint_natives(&mut app, core_addr.as_str(), coins(10, "ujuno"));
 
-    // app.execute_contract(
-    //     Addr::unchecked(CREATOR_ADDR),
-    //     proposal_module.clone(),
-    //     &ExecuteMsg::Execute { proposal_id },
-    //     &[],
-    // )
-    // .unwrap();
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        proposal_module.clone(),
+        &ExecuteMsg::Execute { proposal_id },
+        &[],
+    )
+    .unwrap();
 
     let proposal = query_proposal(&app, &proposal_module, proposal_id);
     assert_eq!(proposal.proposal.status, Status::Executed {},);
@@ -1417,20 +1417,20 @@ fn test_proposal_message_timelock_veto() -> anyhow::Result<()> {
     assert_eq!(native_balance, Uint128::zero());
 
     // Vetoer can't veto early
-    // let err: ContractError = app
-    //     .execute_contract(
-    //         Addr::unchecked("oversight"),
-    //         proposal_module.clone(),
-    //         &ExecuteMsg::Veto { proposal_id },
-    //         &[],
-    //     )
-    //     .unwrap_err()
-    //     .downcast()
-    //     .unwrap();
-    // assert_eq!(
-    //     err,
-    //     ContractError::VetoError(VetoError::NoVetoBeforePassed {})
-    // );
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("oversight"),
+            proposal_module.clone(),
+            &ExecuteMsg::Veto { proposal_id },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::VetoError(VetoError::NoVetoBeforePassed {})
+    );
 
     // Vote on proposal to pass it
     vote_on_proposal(
@@ -1456,26 +1456,26 @@ fn test_proposal_message_timelock_veto() -> anyhow::Result<()> {
     mint_natives(&mut app, core_addr.as_str(), coins(10, "ujuno"));
 
     // Non-vetoer cannot veto
-    // let err: ContractError = app
-    //     .execute_contract(
-    //         Addr::unchecked(CREATOR_ADDR),
-    //         proposal_module.clone(),
-    //         &ExecuteMsg::Veto { proposal_id },
-    //         &[],
-    //     )
-    //     .unwrap_err()
-    //     .downcast()
-    //     .unwrap();
-    // assert_eq!(err, ContractError::VetoError(VetoError::Unauthorized {}));
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Veto { proposal_id },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::VetoError(VetoError::Unauthorized {}));
 
     // Oversite vetos prop
-    // app.execute_contract(
-    //     Addr::unchecked("oversight"),
-    //     proposal_module.clone(),
-    //     &ExecuteMsg::Veto { proposal_id },
-    //     &[],
-    // )
-    // .unwrap();
+    app.execute_contract(
+        Addr::unchecked("oversight"),
+        proposal_module.clone(),
+        &ExecuteMsg::Veto { proposal_id },
+        &[],
+    )
+    .unwrap();
 
     let proposal = query_proposal(&app, &proposal_module, proposal_id);
     assert_eq!(proposal.proposal.status, Status::Vetoed);
@@ -1628,13 +1628,13 @@ fn test_proposal_message_timelock_veto_before_passed() {
     assert_eq!(proposal.proposal.status, Status::Open);
 
     // Oversite vetos prop
-    // app.execute_contract(
-    //     Addr::unchecked("oversight"),
-    //     proposal_module.clone(),
-    //     &ExecuteMsg::Veto { proposal_id },
-    //     &[],
-    // )
-    // .unwrap();
+    app.execute_contract(
+        Addr::unchecked("oversight"),
+        proposal_module.clone(),
+        &ExecuteMsg::Veto { proposal_id },
+        &[],
+    )
+    .unwrap();
 
     let proposal = query_proposal(&app, &proposal_module, proposal_id);
     assert_eq!(proposal.proposal.status, Status::Vetoed);
@@ -1643,8 +1643,8 @@ fn test_proposal_message_timelock_veto_before_passed() {
 
     // // Proposal can be executed early by vetoer
     // execute_proposal(&mut app, &proposal_module, "oversight", proposal_id);
-    // let proposal = query_proposal(&app, &proposal_module, proposal_id);
-    // assert_eq!(proposal.proposal.status, Status::Executed);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Executed);
 }
 
 #[test]
@@ -1726,17 +1726,17 @@ fn test_veto_only_members_execute_proposal() -> anyhow::Result<()> {
     assert_eq!(proposal.proposal.status, Status::Passed);
 
     // Proposal cannot be executed by vetoer once timelock expired
-    // let err: ContractError = app
-    //     .execute_contract(
-    //         Addr::unchecked("oversight"),
-    //         proposal_module.clone(),
-    //         &ExecuteMsg::Execute { proposal_id },
-    //         &[],
-    //     )
-    //     .unwrap_err()
-    //     .downcast()
-    //     .unwrap();
-    // assert_eq!(err, ContractError::Unauthorized {});
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("oversight"),
+            proposal_module.clone(),
+            &ExecuteMsg::Execute { proposal_id },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
 
     // Proposal can be executed by member once timelock expired
     execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
@@ -1961,6 +1961,8 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                bech32_prefix: "osmo".to_string(),
+                allow_legacy_signatures: false,
             })
             .unwrap(),
             funds: vec![],
@@ -1995,6 +1997,8 @@ fn test_update_config() {
             allow_revoting: false,
             dao: core_addr.clone(),
             close_proposal_on_execution_failure: false,
+            bech32_prefix: "osmo".to_string(),
+            allow_legacy_signatures: false,
         }
     );
 
@@ -2014,6 +2018,8 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                bech32_prefix: "osmo".to_string(),
+                allow_legacy_signatures: false,
             },
             &[],
         )
@@ -2043,6 +2049,8 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                bech32_prefix: "osmo".to_string(),
+                allow_legacy_signatures: false,
             },
             &[],
         )
@@ -2293,6 +2301,7 @@ fn test_active_threshold_absolute() {
                 payload: ProposalPayload {
                     msgs: vec![],
                     nonce: NONCE.to_string(),
+                    expiration: None,
                 },
                 proposer: None,
                 vote_signatures: todo!(),
@@ -2335,6 +2344,7 @@ fn test_active_threshold_absolute() {
                 payload: ProposalPayload {
                     msgs: vec![],
                     nonce: NONCE.to_string(),
+                    expiration: None,
                 },
                 proposer: None,
                 vote_signatures: todo!(),
@@ -2382,6 +2392,7 @@ fn test_active_threshold_percent() {
                 payload: ProposalPayload {
                     msgs: vec![],
                     nonce: NONCE.to_string(),
+                    expiration: None,
                 },
                 proposer: None,
                 vote_signatures: todo!(),
@@ -2425,6 +2436,7 @@ fn test_active_threshold_percent() {
                 payload: ProposalPayload {
                     msgs: vec![],
                     nonce: NONCE.to_string(),
+                    expiration: None,
                 },
                 proposer: None,
                 vote_signatures: todo!(),
@@ -2626,6 +2638,8 @@ fn test_allow_revoting_config_changes() {
             allow_revoting: false,
             dao: core_addr.to_string(),
             close_proposal_on_execution_failure: false,
+            bech32_prefix: "osmo".to_string(),
+            allow_legacy_signatures: false,
         },
         &[],
     )
@@ -2929,6 +2943,8 @@ fn test_proposal_count_initialized_to_zero() {
             allow_revoting: false,
             pre_propose_info,
             close_proposal_on_execution_failure: true,
+            bech32_prefix: "osmo".to_string(),
+            allow_legacy_signatures: false,
         },
         Some(vec![
             Cw20Coin {
@@ -2976,7 +2992,10 @@ fn test_migrate_from_compatible() {
         CosmosMsg::Wasm(WasmMsg::Migrate {
             contract_addr: proposal_module.to_string(),
             new_code_id,
-            msg: to_json_binary(&MigrateMsg::FromCompatible {}).unwrap(),
+            msg: to_json_binary(&MigrateMsg::FromCompatible {
+                bech32_prefix: None,
+            })
+            .unwrap(),
         }),
     )
     .unwrap();
@@ -2989,7 +3008,14 @@ fn test_migrate_from_compatible() {
 pub fn test_migrate_updates_version() {
     let mut deps = mock_dependencies();
     cw2::set_contract_version(&mut deps.storage, "my-contract", "old-version").unwrap();
-    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
+    migrate(
+        deps.as_mut(),
+        mock_env(),
+        MigrateMsg::FromCompatible {
+            bech32_prefix: None,
+        },
+    )
+    .unwrap();
     let version = cw2::get_contract_version(&deps.storage).unwrap();
     assert_eq!(version.version, CONTRACT_VERSION);
     assert_eq!(version.contract, CONTRACT_NAME);
@@ -3337,6 +3363,8 @@ fn test_execution_failed() {
             dao: config.dao.into_string(),
             // Disable.
             close_proposal_on_execution_failure: false,
+            bech32_prefix: config.bech32_prefix,
+            allow_legacy_signatures: config.allow_legacy_signatures,
         },
         &[],
     )
@@ -3349,17 +3377,17 @@ fn test_execution_failed() {
         proposal_id,
         Vote::Yes,
     );
-    // let err: StdError = app
-    //     .execute_contract(
-    //         Addr::unchecked(CREATOR_ADDR),
-    //         proposal_module.clone(),
-    //         &ExecuteMsg::Execute { proposal_id },
-    //         &[],
-    //     )
-    //     .unwrap_err()
-    //     .downcast()
-    //     .unwrap();
-    // assert!(matches!(err, StdError::Overflow { .. }));
+    let err: StdError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Execute { proposal_id },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, StdError::Overflow { .. }));
 
     // Even though this proposal was created before the config change
     // was made it still gets retroactively applied.
@@ -3457,6 +3485,65 @@ fn test_proposal_too_large() {
     // ))
 }
 
+#[test]
+fn test_propose_msgs_bounds() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let bank_send: CosmosMsg = BankMsg::Send {
+        to_address: CREATOR_ADDR.to_string(),
+        amount: coins(1, "ujuno"),
+    }
+    .into();
+
+    let propose = |msgs: Vec<CosmosMsg>| {
+        ExecuteMsg::Propose(ProposeMsg {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            payload: ProposalPayload {
+                msgs,
+                nonce: NONCE.to_string(),
+                expiration: None,
+            },
+            proposer: None,
+            vote_signatures: vec![],
+        })
+    };
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &propose(vec![]),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NotEnoughMsgs {});
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module,
+            &propose(vec![bank_send; MAX_PAYLOAD_MSGS as usize + 1]),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::TooManyMsgs {
+            count: MAX_PAYLOAD_MSGS + 1,
+            max: MAX_PAYLOAD_MSGS,
+        }
+    );
+}
+
 #[test]
 fn test_vote_not_registered() {
     let CommonTest {
@@ -3493,6 +3580,7 @@ fn test_proposal_creation_permissions() {
                 payload: ProposalPayload {
                     msgs: vec![],
                     nonce: NONCE.to_string(),
+                    expiration: None,
                 },
                 proposer: None,
                 vote_signatures: todo!(),
@@ -3522,6 +3610,7 @@ fn test_proposal_creation_permissions() {
                 payload: ProposalPayload {
                     msgs: vec![],
                     nonce: NONCE.to_string(),
+                    expiration: None,
                 },
                 proposer: None,
                 vote_signatures: todo!(),
@@ -3556,6 +3645,7 @@ fn test_proposal_creation_permissions() {
                 payload: ProposalPayload {
                     msgs: vec![],
                     nonce: NONCE.to_string(),
+                    expiration: None,
                 },
                 proposer: Some("ekez".to_string()),
                 vote_signatures: todo!(),
@@ -4077,27 +4167,27 @@ pub fn test_not_allow_voting_on_expired_proposal() {
     assert_eq!(proposal.proposal.votes.yes, Uint128::zero());
 
     // attempt to vote past the expiration date
-    // let err: ContractError = app
-    //     .execute_contract(
-    //         Addr::unchecked(CREATOR_ADDR),
-    //         proposal_module.clone(),
-    //         &ExecuteMsg::Vote {
-    //             proposal_id,
-    //             vote: Vote::Yes,
-    //             rationale: None,
-    //         },
-    //         &[],
-    //     )
-    //     .unwrap_err()
-    //     .downcast()
-    //     .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id,
+                vote: Vote::Yes,
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
 
     // assert the vote got rejected and did not count
     // towards the votes
     let proposal = query_proposal(&app, &proposal_module, proposal_id);
     assert_eq!(proposal.proposal.status, Status::Rejected);
     assert_eq!(proposal.proposal.votes.yes, Uint128::zero());
-    // assert!(matches!(err, ContractError::Expired { id: _proposal_id }));
+    assert!(matches!(err, ContractError::Expired { id: _proposal_id }));
 }
 
 #[test]
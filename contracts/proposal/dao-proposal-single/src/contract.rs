@@ -1,18 +1,22 @@
+use std::ops::Add;
+
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     to_json_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply,
-    Response, StdResult, Storage, SubMsg, WasmMsg,
+    Response, StdError, StdResult, Storage, SubMsg, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
 use cw_hooks::Hooks;
 use cw_storage_plus::Bound;
 use cw_utils::{parse_reply_instantiate_data, Duration};
 use dao_hooks::proposal::{
-    new_proposal_hooks, proposal_completed_hooks, proposal_status_changed_hooks,
+    new_proposal_hooks, proposal_amended_hooks, proposal_completed_hooks,
+    proposal_status_changed_hooks,
 };
 use dao_hooks::vote::new_vote_hooks;
 use dao_interface::voting::IsActiveResponse;
+use dao_voting::delegation::{tally_delegated_vote, DelegatedVote};
 use dao_voting::pre_propose::{PreProposeInfo, ProposalCreationPolicy};
 use dao_voting::proposal::{
     SingleChoiceProposeMsg as ProposeMsg, DEFAULT_LIMIT, MAX_PROPOSAL_SIZE,
@@ -21,13 +25,18 @@ use dao_voting::reply::{
     failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id, TaggedReplyId,
 };
 use dao_voting::status::Status;
-use dao_voting::threshold::Threshold;
+use dao_voting::threshold::{validate_percentage, PercentageThreshold, Threshold};
 use dao_voting::veto::{VetoConfig, VetoError};
-use dao_voting::voting::{get_total_power, get_voting_power, validate_voting_period, Vote, Votes};
+use dao_voting::voting::{
+    get_total_power, get_voting_power, validate_voting_period, Vote, VoteWeighting, Votes,
+};
 
 use crate::msg::MigrateMsg;
 use crate::proposal::{next_proposal_id, SingleChoiceProposal};
-use crate::state::{Config, CREATION_POLICY};
+use crate::state::{
+    Config, ProposalRevision, AMENDMENT_HOOKS, CREATION_POLICY, DELEGATED_BALLOTS,
+    DELEGATION_MODULE, PROPOSAL_REVISIONS,
+};
 use crate::v1_state::{
     v1_duration_to_v2, v1_expiration_to_v2, v1_status_to_v2, v1_threshold_to_v2, v1_votes_to_v2,
 };
@@ -36,7 +45,9 @@ use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::advance_proposal_id,
     query::ProposalListResponse,
-    query::{ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
+    query::{
+        ProposalResponse, ProposalRevisionsResponse, VoteInfo, VoteListResponse, VoteResponse,
+    },
     state::{Ballot, BALLOTS, CONFIG, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS},
 };
 use cw_proposal_single_v1 as v1;
@@ -58,6 +69,11 @@ pub fn instantiate(
 
     let (min_voting_period, max_voting_period) =
         validate_voting_period(msg.min_voting_period, msg.max_voting_period)?;
+    validate_optimistic_config(
+        &msg.objection_threshold,
+        &msg.review_period,
+        &max_voting_period,
+    )?;
 
     let (initial_policy, pre_propose_messages) = msg
         .pre_propose_info
@@ -77,6 +93,9 @@ pub fn instantiate(
         allow_revoting: msg.allow_revoting,
         close_proposal_on_execution_failure: msg.close_proposal_on_execution_failure,
         veto: msg.veto,
+        vote_weighting: msg.vote_weighting,
+        objection_threshold: msg.objection_threshold,
+        review_period: msg.review_period,
     };
 
     // Initialize proposal count to zero so that queries return zero
@@ -104,7 +123,23 @@ pub fn execute(
             description,
             msgs,
             proposer,
-        }) => execute_propose(deps, env, info.sender, title, description, msgs, proposer),
+            immutable,
+        }) => execute_propose(
+            deps,
+            env,
+            info.sender,
+            title,
+            description,
+            msgs,
+            proposer,
+            immutable,
+        ),
+        ExecuteMsg::Amend {
+            proposal_id,
+            title,
+            description,
+            msgs,
+        } => execute_amend(deps, env, info, proposal_id, title, description, msgs),
         ExecuteMsg::Vote {
             proposal_id,
             vote,
@@ -125,6 +160,9 @@ pub fn execute(
             dao,
             close_proposal_on_execution_failure,
             veto,
+            vote_weighting,
+            objection_threshold,
+            review_period,
         } => execute_update_config(
             deps,
             info,
@@ -136,6 +174,9 @@ pub fn execute(
             dao,
             close_proposal_on_execution_failure,
             veto,
+            vote_weighting,
+            objection_threshold,
+            review_period,
         ),
         ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
             execute_update_proposal_creation_policy(deps, info, new_info)
@@ -150,10 +191,18 @@ pub fn execute(
         ExecuteMsg::RemoveVoteHook { address } => {
             execute_remove_vote_hook(deps, env, info, address)
         }
+        ExecuteMsg::AddAmendmentHook { address } => execute_add_amendment_hook(deps, info, address),
+        ExecuteMsg::RemoveAmendmentHook { address } => {
+            execute_remove_amendment_hook(deps, info, address)
+        }
         ExecuteMsg::Veto { proposal_id } => execute_veto(deps, env, info, proposal_id),
+        ExecuteMsg::UpdateDelegationModule { module } => {
+            execute_update_delegation_module(deps, info, module)
+        }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn execute_propose(
     deps: DepsMut,
     env: Env,
@@ -162,6 +211,7 @@ pub fn execute_propose(
     description: String,
     msgs: Vec<CosmosMsg<Empty>>,
     proposer: Option<String>,
+    immutable: bool,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
@@ -200,10 +250,31 @@ pub fn execute_propose(
         return Err(ContractError::InactiveDao {});
     }
 
-    let expiration = config.max_voting_period.after(&env.block);
+    // If a review period is configured, the voting period starts once
+    // it has passed.
+    let voting_start = config
+        .review_period
+        .map(|review_period| review_period.after(&env.block));
+    let (min_voting_period, expiration) = match voting_start {
+        Some(voting_start) => (
+            config
+                .min_voting_period
+                .map(|min| voting_start.add(min))
+                .transpose()?,
+            voting_start.add(config.max_voting_period)?,
+        ),
+        None => (
+            config.min_voting_period.map(|min| min.after(&env.block)),
+            config.max_voting_period.after(&env.block),
+        ),
+    };
 
     let total_power = get_total_power(deps.as_ref(), &config.dao, Some(env.block.height))?;
 
+    // Proposals created by approval pre-propose modules are
+    // immutable, as an amendment would bypass the approval.
+    let amendable = !immutable;
+
     let proposal = {
         // Limit mutability to this block.
         let mut proposal = SingleChoiceProposal {
@@ -211,7 +282,7 @@ pub fn execute_propose(
             description,
             proposer: proposer.clone(),
             start_height: env.block.height,
-            min_voting_period: config.min_voting_period.map(|min| min.after(&env.block)),
+            min_voting_period,
             expiration,
             threshold: config.threshold,
             total_power,
@@ -220,6 +291,10 @@ pub fn execute_propose(
             votes: Votes::zero(),
             allow_revoting: config.allow_revoting,
             veto: config.veto,
+            vote_weighting: config.vote_weighting,
+            objection_threshold: config.objection_threshold,
+            voting_start,
+            amendable,
         };
         // Update the proposal's status. Addresses case where proposal
         // expires on the same block as it is created.
@@ -262,6 +337,93 @@ pub fn execute_propose(
         .add_attribute("status", proposal.status.to_string()))
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn execute_amend(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    title: Option<String>,
+    description: Option<String>,
+    msgs: Option<Vec<CosmosMsg<Empty>>>,
+) -> Result<Response, ContractError> {
+    let mut prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    if prop.proposer != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Proposals may only be amended during their review period, when
+    // no votes may be cast, or before anyone has voted on them, so
+    // that no vote is ever counted for a version of a proposal other
+    // than the one it was cast on. Optimistic proposals pass without
+    // votes, so they may only be amended during their review period.
+    prop.update_status(&env.block)?;
+    let in_review = prop
+        .voting_start
+        .is_some_and(|voting_start| !voting_start.is_expired(&env.block));
+    let unvoted = prop.objection_threshold.is_none()
+        && BALLOTS
+            .prefix(proposal_id)
+            .keys(deps.storage, None, None, Order::Ascending)
+            .next()
+            .is_none();
+    if !prop.amendable || !(in_review || unvoted) || prop.status != Status::Open {
+        return Err(ContractError::NotAmendable { id: proposal_id });
+    }
+
+    let revision = PROPOSAL_REVISIONS
+        .prefix(proposal_id)
+        .keys(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map_or(0, |last| last + 1);
+    PROPOSAL_REVISIONS.save(
+        deps.storage,
+        (proposal_id, revision),
+        &ProposalRevision {
+            revision,
+            title: prop.title.clone(),
+            description: prop.description.clone(),
+            msgs: prop.msgs.clone(),
+            amended_at_height: env.block.height,
+        },
+    )?;
+
+    if let Some(title) = title {
+        prop.title = title;
+    }
+    if let Some(description) = description {
+        prop.description = description;
+    }
+    if let Some(msgs) = msgs {
+        prop.msgs = msgs;
+    }
+
+    // Amendments are subject to the same size limit as new
+    // proposals.
+    let proposal_size = cosmwasm_std::to_json_vec(&prop)?.len() as u64;
+    if proposal_size > MAX_PROPOSAL_SIZE {
+        return Err(ContractError::ProposalTooLarge {
+            size: proposal_size,
+            max: MAX_PROPOSAL_SIZE,
+        });
+    }
+
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+
+    let hooks = proposal_amended_hooks(AMENDMENT_HOOKS, deps.storage, proposal_id, revision + 1)?;
+
+    Ok(Response::default()
+        .add_submessages(hooks)
+        .add_attribute("action", "amend")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("revision", (revision + 1).to_string()))
+}
+
 pub fn execute_veto(
     deps: DepsMut,
     env: Env,
@@ -450,7 +612,7 @@ pub fn execute_execute(
 }
 
 pub fn execute_vote(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     proposal_id: u64,
@@ -472,16 +634,50 @@ pub fn execute_vote(
     if prop.expiration.is_expired(&env.block) {
         return Err(ContractError::Expired { id: proposal_id });
     }
+    if let Some(voting_start) = prop.voting_start {
+        if !voting_start.is_expired(&env.block) {
+            return Err(ContractError::VotingNotStarted { id: proposal_id });
+        }
+    }
 
-    let vote_power = get_voting_power(
-        deps.as_ref(),
-        info.sender.clone(),
+    // Delegators who have not voted themselves have their voting
+    // power cast by their delegate.
+    let registry = DELEGATION_MODULE.may_load(deps.storage)?;
+    let DelegatedVote {
+        own_weight,
+        vote_power,
+        total_power_reduction,
+        overridden_delegate,
+    } = tally_delegated_vote(
+        deps.branch(),
+        &BALLOTS,
+        &DELEGATED_BALLOTS,
+        registry.as_ref(),
         &config.dao,
-        Some(prop.start_height),
+        &info.sender,
+        proposal_id,
+        prop.start_height,
+        prop.vote_weighting,
     )?;
     if vote_power.is_zero() {
         return Err(ContractError::NotRegistered {});
     }
+    prop.total_power = prop
+        .total_power
+        .checked_sub(total_power_reduction)
+        .map_err(StdError::overflow)?;
+
+    // If a delegate's ballot is carrying the sender's voting power,
+    // the sender's vote overrides it.
+    if let Some(delegate) = overridden_delegate {
+        let mut ballot = BALLOTS.load(deps.storage, (proposal_id, &delegate))?;
+        ballot.power = ballot
+            .power
+            .checked_sub(own_weight)
+            .map_err(StdError::overflow)?;
+        prop.votes.remove_vote(ballot.vote, own_weight);
+        BALLOTS.save(deps.storage, (proposal_id, &delegate), &ballot)?;
+    }
 
     BALLOTS.update(deps.storage, (proposal_id, &info.sender), |bal| match bal {
         Some(current_ballot) => {
@@ -634,6 +830,9 @@ pub fn execute_update_config(
     dao: String,
     close_proposal_on_execution_failure: bool,
     veto: Option<VetoConfig>,
+    vote_weighting: VoteWeighting,
+    objection_threshold: Option<PercentageThreshold>,
+    review_period: Option<Duration>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -646,6 +845,7 @@ pub fn execute_update_config(
 
     let (min_voting_period, max_voting_period) =
         validate_voting_period(min_voting_period, max_voting_period)?;
+    validate_optimistic_config(&objection_threshold, &review_period, &max_voting_period)?;
 
     // if veto is configured, validate its fields
     if let Some(veto_config) = &veto {
@@ -663,6 +863,9 @@ pub fn execute_update_config(
             dao,
             close_proposal_on_execution_failure,
             veto,
+            vote_weighting,
+            objection_threshold,
+            review_period,
         },
     )?;
 
@@ -691,6 +894,53 @@ pub fn execute_update_proposal_creation_policy(
         .add_attribute("new_policy", format!("{initial_policy:?}")))
 }
 
+pub fn execute_update_delegation_module(
+    deps: DepsMut,
+    info: MessageInfo,
+    module: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    match module {
+        Some(module) => {
+            let module = deps.api.addr_validate(&module)?;
+            DELEGATION_MODULE.save(deps.storage, &module)?;
+        }
+        None => DELEGATION_MODULE.remove(deps.storage),
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "update_delegation_module")
+        .add_attribute("sender", info.sender)
+        .add_attribute(
+            "module",
+            DELEGATION_MODULE
+                .may_load(deps.storage)?
+                .map_or_else(|| "_none".to_string(), Addr::into_string),
+        ))
+}
+
+/// Validates the optimistic proposal settings. Review periods extend
+/// the voting period, so must use the same units.
+fn validate_optimistic_config(
+    objection_threshold: &Option<PercentageThreshold>,
+    review_period: &Option<Duration>,
+    max_voting_period: &Duration,
+) -> Result<(), ContractError> {
+    if let Some(objection_threshold) = objection_threshold {
+        validate_percentage(objection_threshold)?;
+    }
+    match (review_period, max_voting_period) {
+        (None, _)
+        | (Some(Duration::Time(_)), Duration::Time(_))
+        | (Some(Duration::Height(_)), Duration::Height(_)) => Ok(()),
+        _ => Err(ContractError::ReviewPeriodUnitsConflict {}),
+    }
+}
+
 pub fn add_hook(
     hooks: Hooks,
     storage: &mut dyn Storage,
@@ -797,6 +1047,46 @@ pub fn execute_remove_vote_hook(
         .add_attribute("address", address))
 }
 
+pub fn execute_add_amendment_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        // Only DAO can add hooks
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let validated_address = deps.api.addr_validate(&address)?;
+
+    add_hook(AMENDMENT_HOOKS, deps.storage, validated_address)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "add_amendment_hook")
+        .add_attribute("address", address))
+}
+
+pub fn execute_remove_amendment_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        // Only DAO can remove hooks
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let validated_address = deps.api.addr_validate(&address)?;
+
+    remove_hook(AMENDMENT_HOOKS, deps.storage, validated_address)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "remove_amendment_hook")
+        .add_attribute("address", address))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -821,7 +1111,14 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         } => query_reverse_proposals(deps, env, start_before, limit),
         QueryMsg::ProposalCreationPolicy {} => query_creation_policy(deps),
         QueryMsg::ProposalHooks {} => to_json_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
+        QueryMsg::AmendmentHooks {} => to_json_binary(&AMENDMENT_HOOKS.query_hooks(deps)?),
         QueryMsg::VoteHooks {} => to_json_binary(&VOTE_HOOKS.query_hooks(deps)?),
+        QueryMsg::DelegationModule {} => to_json_binary(&DELEGATION_MODULE.may_load(deps.storage)?),
+        QueryMsg::ProposalRevisions {
+            proposal_id,
+            start_after,
+            limit,
+        } => query_proposal_revisions(deps, proposal_id, start_after, limit),
     }
 }
 
@@ -934,6 +1231,25 @@ pub fn query_list_votes(
     to_json_binary(&VoteListResponse { votes })
 }
 
+pub fn query_proposal_revisions(
+    deps: Deps,
+    proposal_id: u64,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let min = start_after.map(Bound::<u64>::exclusive);
+
+    let revisions = PROPOSAL_REVISIONS
+        .prefix(proposal_id)
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| item.map(|(_, revision)| revision))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&ProposalRevisionsResponse { revisions })
+}
+
 pub fn query_info(deps: Deps) -> StdResult<Binary> {
     let info = cw2::get_contract_version(deps.storage)?;
     to_json_binary(&dao_interface::voting::InfoResponse { info })
@@ -979,6 +1295,9 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                     dao: current_config.dao.clone(),
                     close_proposal_on_execution_failure,
                     veto,
+                    vote_weighting: VoteWeighting::default(),
+                    objection_threshold: None,
+                    review_period: None,
                 },
             )?;
 
@@ -1024,6 +1343,10 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                         votes: v1_votes_to_v2(prop.votes),
                         allow_revoting: prop.allow_revoting,
                         veto: None,
+                        vote_weighting: VoteWeighting::default(),
+                        objection_threshold: None,
+                        voting_start: None,
+                        amendable: false,
                     };
 
                     PROPOSALS
@@ -1111,5 +1434,9 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
             };
             Ok(Response::new().add_attribute("failed_prepropose_hook", format!("{addr}")))
         }
+        TaggedReplyId::FailedAmendmentHook(idx) => {
+            let addr = AMENDMENT_HOOKS.remove_hook_by_index(deps.storage, idx)?;
+            Ok(Response::new().add_attribute("removed_amendment_hook", format!("{addr}:{idx}")))
+        }
     }
 }
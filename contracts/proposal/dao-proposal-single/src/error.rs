@@ -71,6 +71,15 @@ pub enum ContractError {
     #[error("min voting period must be less than or equal to max voting period")]
     InvalidMinVotingPeriod {},
 
+    #[error("review_period and max_voting_period must have the same units (height or time)")]
+    ReviewPeriodUnitsConflict {},
+
+    #[error("voting on proposal ({id}) has not started")]
+    VotingNotStarted { id: u64 },
+
+    #[error("proposal ({id}) can only be amended by its proposer during its review period or before it has been voted on")]
+    NotAmendable { id: u64 },
+
     #[error(
         "pre-propose modules must specify a proposer. lacking one, no proposer should be specified"
     )]
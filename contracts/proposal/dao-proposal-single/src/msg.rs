@@ -1,9 +1,13 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{CosmosMsg, Empty};
 use cw_utils::Duration;
 use dao_dao_macros::proposal_module_query;
 use dao_voting::{
-    pre_propose::PreProposeInfo, proposal::SingleChoiceProposeMsg, threshold::Threshold,
-    veto::VetoConfig, voting::Vote,
+    pre_propose::PreProposeInfo,
+    proposal::SingleChoiceProposeMsg,
+    threshold::{PercentageThreshold, Threshold},
+    veto::VetoConfig,
+    voting::{Vote, VoteWeighting},
 };
 
 #[cw_serde]
@@ -44,12 +48,41 @@ pub struct InstantiateMsg {
     /// During this period an oversight account (`veto.vetoer`) can
     /// veto the proposal.
     pub veto: Option<VetoConfig>,
+    /// How votes are weighted by voting power. Quadratic weighting
+    /// counts the integer square root of a voter's voting power.
+    /// Defaults to linear weighting.
+    #[serde(default)]
+    pub vote_weighting: VoteWeighting,
+    /// If set, proposals are optimistic: they pass at expiration
+    /// unless their no votes exceed this percentage of the total
+    /// voting power. `None` disables optimistic proposals.
+    #[serde(default)]
+    pub objection_threshold: Option<PercentageThreshold>,
+    /// If set, proposals may not be voted on until this period after
+    /// their creation has passed, during which their proposer may
+    /// amend them. Must use the same units as `max_voting_period`.
+    #[serde(default)]
+    pub review_period: Option<Duration>,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Creates a proposal in the module.
     Propose(SingleChoiceProposeMsg),
+    /// Amends an open proposal, recording its previous version as a
+    /// revision. Only the proposer may call this method, and only
+    /// during the proposal's review period or before any votes have
+    /// been cast on it. Fields that are `None` are left unchanged.
+    Amend {
+        /// The ID of the proposal to amend.
+        proposal_id: u64,
+        /// The new title of the proposal.
+        title: Option<String>,
+        /// The new description of the proposal.
+        description: Option<String>,
+        /// The new messages to execute should the proposal pass.
+        msgs: Option<Vec<CosmosMsg<Empty>>>,
+    },
     /// Votes on a proposal. Voting power is determined by the DAO's
     /// voting power module.
     Vote {
@@ -124,6 +157,17 @@ pub enum ExecuteMsg {
         /// Optional time delay on proposal execution, during which the
         /// proposal may be vetoed.
         veto: Option<VetoConfig>,
+        /// How votes are weighted by voting power. This will only
+        /// apply to proposals created after the config update.
+        vote_weighting: VoteWeighting,
+        /// The objection threshold of optimistic proposals. `None`
+        /// disables optimistic proposals. This will only apply to
+        /// proposals created after the config update.
+        objection_threshold: Option<PercentageThreshold>,
+        /// The review period before voting starts. Must use the same
+        /// units as `max_voting_period`. This will only apply to
+        /// proposals created after the config update.
+        review_period: Option<Duration>,
     },
     /// Update's the proposal creation policy used for this
     /// module. Only the DAO may call this method.
@@ -143,6 +187,21 @@ pub enum ExecuteMsg {
     AddVoteHook { address: String },
     /// Removed a consumer of vote hooks.
     RemoveVoteHook { address: String },
+    /// Adds an address as a consumer of amendment hooks. Consumers of
+    /// amendment hooks have a `ProposalAmended` proposal hook message
+    /// executed on them whenever a proposal is amended. These are not
+    /// sent to consumers of proposal hooks, which may not be able to
+    /// parse them. If a consumer contract errors when handling a hook
+    /// message it will be removed from the list of consumers.
+    AddAmendmentHook { address: String },
+    /// Removes a consumer of amendment hooks.
+    RemoveAmendmentHook { address: String },
+    /// Sets the vote delegation registry used by this module. While
+    /// set, a vote carries the voting power of the voter's
+    /// delegators at the proposal's start height, unless those
+    /// delegators vote themselves. `None` disables delegated
+    /// voting. Only the DAO may call this method.
+    UpdateDelegationModule { module: Option<String> },
 }
 
 #[proposal_module_query]
@@ -209,6 +268,25 @@ pub enum QueryMsg {
     /// Lists all of the consumers of vote hooks for this module.
     #[returns(::cw_hooks::HooksResponse)]
     VoteHooks {},
+    /// Lists all of the consumers of amendment hooks for this module.
+    #[returns(::cw_hooks::HooksResponse)]
+    AmendmentHooks {},
+    /// Gets the vote delegation registry used by this module, if
+    /// any.
+    #[returns(::std::option::Option<::cosmwasm_std::Addr>)]
+    DelegationModule {},
+    /// Lists the previous versions of a proposal that has been
+    /// amended, in ascending order of revision number.
+    #[returns(crate::query::ProposalRevisionsResponse)]
+    ProposalRevisions {
+        /// The proposal to list the revisions of.
+        proposal_id: u64,
+        /// The revision number to start listing revisions after.
+        start_after: Option<u64>,
+        /// The maximum number of revisions to return. If no limit is
+        /// specified a max of 30 are returned.
+        limit: Option<u64>,
+    },
 }
 
 #[cw_serde]
@@ -8,7 +8,9 @@ use cw_utils::Expiration;
 use dao_voting::status::Status;
 use dao_voting::threshold::{PercentageThreshold, Threshold};
 use dao_voting::veto::VetoConfig;
-use dao_voting::voting::{does_vote_count_fail, does_vote_count_pass, Votes};
+use dao_voting::voting::{
+    compare_vote_count, does_vote_count_fail, does_vote_count_pass, VoteCmp, VoteWeighting, Votes,
+};
 
 #[cw_serde]
 pub struct SingleChoiceProposal {
@@ -46,6 +48,27 @@ pub struct SingleChoiceProposal {
     /// Optional veto configuration. If set to `None`, veto option
     /// is disabled. Otherwise contains the configuration for veto flow.
     pub veto: Option<VetoConfig>,
+    /// How votes on this proposal are weighted by voting power. In
+    /// quadratic mode `total_power` is reduced as voters are counted
+    /// so that it remains an upper bound on the votes that may be
+    /// cast.
+    #[serde(default)]
+    pub vote_weighting: VoteWeighting,
+    /// If set, this proposal is optimistic. It passes at expiration
+    /// unless its no votes exceed this percentage of `total_power`,
+    /// and `threshold` is ignored.
+    #[serde(default)]
+    pub objection_threshold: Option<PercentageThreshold>,
+    /// If set, votes may not be cast on this proposal until this has
+    /// expired.
+    #[serde(default)]
+    pub voting_start: Option<Expiration>,
+    /// Whether the proposer may amend this proposal before
+    /// `voting_start`, or before any votes have been cast on it.
+    /// Proposals created through an approval pre-propose module may
+    /// not be amended, as the amendment would not be approved.
+    #[serde(default)]
+    pub amendable: bool,
 }
 
 pub fn next_proposal_id(store: &dyn Storage) -> StdResult<u64> {
@@ -121,6 +144,11 @@ impl SingleChoiceProposal {
         if self.allow_revoting && !self.expiration.is_expired(block) {
             return false;
         }
+        // Optimistic proposals pass at expiration if they have not
+        // been objected to.
+        if let Some(objection_threshold) = self.objection_threshold {
+            return self.expiration.is_expired(block) && !self.is_objected(objection_threshold);
+        }
         // If the min voting period is set and not expired the
         // proposal can not yet be passed. This gives DAO members some
         // time to remove liquidity / scheme on a recovery plan if a
@@ -166,6 +194,11 @@ impl SingleChoiceProposal {
         if self.allow_revoting && !self.expiration.is_expired(block) {
             return false;
         }
+        // No votes can not be removed without revoting, so an
+        // objection rejects an optimistic proposal immediately.
+        if let Some(objection_threshold) = self.objection_threshold {
+            return self.is_objected(objection_threshold);
+        }
 
         match self.threshold {
             Threshold::AbsolutePercentage {
@@ -270,6 +303,24 @@ impl SingleChoiceProposal {
             }
         }
     }
+
+    /// Returns true if enough no votes have been cast to reject an
+    /// optimistic proposal. No votes must exceed the objection
+    /// threshold, so a proposal with exactly the threshold of no votes
+    /// is not rejected.
+    fn is_objected(&self, objection_threshold: PercentageThreshold) -> bool {
+        if self.total_power.is_zero() {
+            return false;
+        }
+        match objection_threshold {
+            PercentageThreshold::Majority {} => {
+                self.votes.no.full_mul(2u64) > self.total_power.into()
+            }
+            PercentageThreshold::Percent(percent) => {
+                compare_vote_count(self.votes.no, VoteCmp::Greater, self.total_power, percent)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -310,6 +361,10 @@ mod test {
             status: Status::Open,
             threshold,
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
+            objection_threshold: None,
+            voting_start: None,
+            amendable: false,
             total_power,
             votes,
         };
@@ -1126,6 +1181,57 @@ mod test {
         ));
     }
 
+    #[test]
+    fn test_optimistic_proposal() {
+        // The threshold is ignored for optimistic proposals.
+        let threshold = Threshold::AbsoluteCount {
+            threshold: Uint128::new(100),
+        };
+        let votes = Votes {
+            yes: Uint128::zero(),
+            no: Uint128::new(2),
+            abstain: Uint128::new(1),
+        };
+        let optimistic = |votes: Votes, is_expired: bool, allow_revoting: bool| {
+            let (mut prop, block) = setup_prop(
+                threshold.clone(),
+                votes,
+                Uint128::new(10),
+                is_expired,
+                true,
+                allow_revoting,
+            );
+            prop.objection_threshold = Some(PercentageThreshold::Percent(Decimal::percent(30)));
+            (prop.is_passed(&block), prop.is_rejected(&block))
+        };
+
+        // Open until expiration, then passed.
+        assert_eq!(optimistic(votes.clone(), false, false), (false, false));
+        assert_eq!(optimistic(votes.clone(), true, false), (true, false));
+
+        // 3 of 10 no votes only reaches the objection threshold, which
+        // does not reject the proposal.
+        let at_threshold = Votes {
+            no: Uint128::new(3),
+            ..votes.clone()
+        };
+        assert_eq!(
+            optimistic(at_threshold.clone(), false, false),
+            (false, false)
+        );
+        assert_eq!(optimistic(at_threshold, true, false), (true, false));
+
+        // 4 of 10 no votes exceeds the objection threshold, rejecting
+        // the proposal immediately unless revoting is enabled.
+        let objected = Votes {
+            no: Uint128::new(4),
+            ..votes
+        };
+        assert_eq!(optimistic(objected.clone(), false, false), (false, true));
+        assert_eq!(optimistic(objected.clone(), false, true), (false, false));
+        assert_eq!(optimistic(objected, true, true), (false, true));
+    }
+
     #[test]
     fn test_proposal_ids_advance() {
         // do they advance, lets find out!
@@ -1,4 +1,5 @@
 use crate::proposal::SingleChoiceProposal;
+use crate::state::ProposalRevision;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint128};
 use dao_voting::voting::Vote;
@@ -43,3 +44,10 @@ pub struct VoteListResponse {
 pub struct ProposalListResponse {
     pub proposals: Vec<ProposalResponse>,
 }
+
+/// A list of a proposal's previous versions returned by
+/// `ProposalRevisions`.
+#[cw_serde]
+pub struct ProposalRevisionsResponse {
+    pub revisions: Vec<ProposalRevision>,
+}
@@ -1,10 +1,13 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, CosmosMsg, Empty, Uint128};
 use cw_hooks::Hooks;
 use cw_storage_plus::{Item, Map};
 use cw_utils::Duration;
 use dao_voting::{
-    pre_propose::ProposalCreationPolicy, threshold::Threshold, veto::VetoConfig, voting::Vote,
+    pre_propose::ProposalCreationPolicy,
+    threshold::{PercentageThreshold, Threshold},
+    veto::VetoConfig,
+    voting::{Vote, VoteWeighting},
 };
 
 use crate::proposal::SingleChoiceProposal;
@@ -61,6 +64,33 @@ pub struct Config {
     /// Optional veto configuration. If set to `None`, veto option
     /// is disabled. Otherwise contains the configuration for veto flow.
     pub veto: Option<VetoConfig>,
+    /// How votes are weighted by voting power. Configs saved before
+    /// this field existed deserialize to linear weighting.
+    #[serde(default)]
+    pub vote_weighting: VoteWeighting,
+    /// If set, proposals are optimistic. They pass at expiration
+    /// unless their no votes exceed this percentage of the total
+    /// voting power, and `threshold` is ignored.
+    #[serde(default)]
+    pub objection_threshold: Option<PercentageThreshold>,
+    /// If set, proposals may not be voted on for this amount of time
+    /// after their creation, giving their proposer a chance to amend
+    /// them. The voting period starts once the review period ends.
+    #[serde(default)]
+    pub review_period: Option<Duration>,
+}
+
+/// A previous version of a proposal, replaced by an amendment.
+#[cw_serde]
+pub struct ProposalRevision {
+    /// The revision number. The proposal as it was created is
+    /// revision 0.
+    pub revision: u64,
+    pub title: String,
+    pub description: String,
+    pub msgs: Vec<CosmosMsg<Empty>>,
+    /// The block height at which this revision was amended.
+    pub amended_at_height: u64,
 }
 
 /// The current top level config for the module.  The "config" key was
@@ -74,6 +104,17 @@ pub const BALLOTS: Map<(u64, &Addr), Ballot> = Map::new("ballots");
 pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
 /// Consumers of vote hooks.
 pub const VOTE_HOOKS: Hooks = Hooks::new("vote_hooks");
+/// Consumers of proposal amended hooks.
+pub const AMENDMENT_HOOKS: Hooks = Hooks::new("amendment_hooks");
 /// The address of the pre-propose module associated with this
 /// proposal module (if any).
 pub const CREATION_POLICY: Item<ProposalCreationPolicy> = Item::new("creation_policy");
+/// The vote delegation registry used by this module, if any.
+pub const DELEGATION_MODULE: Item<Addr> = Item::new("delegation_module");
+/// Maps a proposal and a delegator to the delegate whose ballot
+/// carries the delegator's voting power on that proposal. Removed
+/// once the delegator votes themselves.
+pub const DELEGATED_BALLOTS: Map<(u64, &Addr), Addr> = Map::new("delegated_ballots");
+/// Previous versions of amended proposals, keyed by proposal ID and
+/// revision number.
+pub const PROPOSAL_REVISIONS: Map<(u64, u64), ProposalRevision> = Map::new("proposal_revisions");
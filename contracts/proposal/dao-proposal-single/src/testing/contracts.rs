@@ -106,3 +106,12 @@ pub(crate) fn cw4_voting_contract() -> Box<dyn Contract<Empty>> {
     .with_reply(dao_voting_cw4::contract::reply);
     Box::new(contract)
 }
+
+pub(crate) fn proposal_hook_counter_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_proposal_hook_counter::contract::execute,
+        dao_proposal_hook_counter::contract::instantiate,
+        dao_proposal_hook_counter::contract::query,
+    );
+    Box::new(contract)
+}
@@ -13,6 +13,7 @@ use dao_voting::{
     deposit::{CheckedDepositInfo, UncheckedDepositInfo},
     status::Status,
     threshold::Threshold,
+    voting::VoteWeighting,
 };
 
 use crate::{
@@ -142,6 +143,9 @@ where
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
         pre_propose_info,
+        vote_weighting: VoteWeighting::Linear {},
+        objection_threshold: None,
+        review_period: None,
     };
 
     let core_addr = setup_governance(&mut app, instantiate, Some(initial_balances));
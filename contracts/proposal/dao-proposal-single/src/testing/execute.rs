@@ -78,6 +78,7 @@ pub(crate) fn make_proposal(
                     description: "description".to_string(),
                     msgs: msgs.clone(),
                     proposer: None,
+                    immutable: false,
                 }),
                 &[],
             )
@@ -10,6 +10,7 @@ use dao_voting::{
     deposit::{DepositRefundPolicy, UncheckedDepositInfo, VotingModuleTokenType},
     pre_propose::PreProposeInfo,
     threshold::{ActiveThreshold, PercentageThreshold, Threshold::ThresholdQuorum},
+    voting::VoteWeighting,
 };
 use dao_voting_cw4::msg::GroupContract;
 
@@ -70,6 +71,9 @@ pub(crate) fn get_default_token_dao_proposal_module_instantiate(app: &mut App) -
             false,
         ),
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
+        objection_threshold: None,
+        review_period: None,
     }
 }
 
@@ -89,6 +93,9 @@ pub(crate) fn get_default_non_token_dao_proposal_module_instantiate(
         allow_revoting: false,
         pre_propose_info: get_pre_propose_info(app, None, false),
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
+        objection_threshold: None,
+        review_period: None,
     }
 }
 
@@ -26,19 +26,21 @@ use dao_voting::{
         mask_proposal_hook_index, mask_vote_hook_index,
     },
     status::Status,
-    threshold::{ActiveThreshold, PercentageThreshold, Threshold},
+    threshold::{ActiveThreshold, PercentageThreshold, Threshold, ThresholdError},
     veto::{VetoConfig, VetoError},
-    voting::{Vote, Votes},
+    voting::{Vote, VoteWeighting, Votes},
 };
 
 use crate::{
     contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION},
     msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
     proposal::SingleChoiceProposal,
-    query::{ProposalResponse, VoteInfo},
+    query::{ProposalResponse, ProposalRevisionsResponse, VoteInfo},
     state::Config,
     testing::{
-        contracts::{pre_propose_single_contract, proposal_single_contract},
+        contracts::{
+            pre_propose_single_contract, proposal_hook_counter_contract, proposal_single_contract,
+        },
         execute::{
             add_proposal_hook, add_proposal_hook_should_fail, add_vote_hook,
             add_vote_hook_should_fail, close_proposal, close_proposal_should_fail,
@@ -130,6 +132,10 @@ fn test_simple_propose_staked_balances() {
         msgs: vec![],
         status: Status::Open,
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
+        objection_threshold: None,
+        voting_start: None,
+        amendable: false,
         votes: Votes::zero(),
     };
 
@@ -180,6 +186,10 @@ fn test_simple_proposal_cw4_voting() {
         msgs: vec![],
         status: Status::Open,
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
+        objection_threshold: None,
+        voting_start: None,
+        amendable: false,
         votes: Votes::zero(),
     };
 
@@ -286,6 +296,10 @@ fn test_instantiate_with_non_voting_module_cw20_deposit() {
         status: Status::Open,
         votes: Votes::zero(),
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
+        objection_threshold: None,
+        voting_start: None,
+        amendable: false,
     };
 
     assert_eq!(created.proposal, expected);
@@ -1957,6 +1971,9 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                vote_weighting: VoteWeighting::Linear {},
+                objection_threshold: None,
+                review_period: None,
             })
             .unwrap(),
             funds: vec![],
@@ -1991,6 +2008,9 @@ fn test_update_config() {
             allow_revoting: false,
             dao: core_addr.clone(),
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
+            objection_threshold: None,
+            review_period: None,
         }
     );
 
@@ -2010,6 +2030,9 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                vote_weighting: VoteWeighting::Linear {},
+                objection_threshold: None,
+                review_period: None,
             },
             &[],
         )
@@ -2039,6 +2062,9 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                vote_weighting: VoteWeighting::Linear {},
+                objection_threshold: None,
+                review_period: None,
             },
             &[],
         )
@@ -2127,7 +2153,11 @@ fn test_anyone_may_propose_and_proposal_listing() {
                     no: Uint128::zero(),
                     abstain: Uint128::zero()
                 },
-                veto: None
+                veto: None,
+                vote_weighting: VoteWeighting::Linear {},
+                objection_threshold: None,
+                voting_start: None,
+                amendable: false,
             }
         }
     )
@@ -2288,6 +2318,7 @@ fn test_active_threshold_absolute() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                immutable: false,
             }),
             &[],
         )
@@ -2326,6 +2357,7 @@ fn test_active_threshold_absolute() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                immutable: false,
             }),
             &[],
         )
@@ -2369,6 +2401,7 @@ fn test_active_threshold_percent() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                immutable: false,
             }),
             &[],
         )
@@ -2408,6 +2441,7 @@ fn test_active_threshold_percent() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                immutable: false,
             }),
             &[],
         )
@@ -2606,6 +2640,9 @@ fn test_allow_revoting_config_changes() {
             allow_revoting: false,
             dao: core_addr.to_string(),
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
+            objection_threshold: None,
+            review_period: None,
         },
         &[],
     )
@@ -2909,6 +2946,9 @@ fn test_proposal_count_initialized_to_zero() {
             allow_revoting: false,
             pre_propose_info,
             close_proposal_on_execution_failure: true,
+            vote_weighting: VoteWeighting::Linear {},
+            objection_threshold: None,
+            review_period: None,
         },
         Some(vec![
             Cw20Coin {
@@ -3317,6 +3357,9 @@ fn test_execution_failed() {
             dao: config.dao.into_string(),
             // Disable.
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
+            objection_threshold: None,
+            review_period: None,
         },
         &[],
     )
@@ -3381,6 +3424,10 @@ fn test_reply_proposal_mock() {
                 msgs: vec![],
                 status: Status::Open,
                 veto: None,
+                vote_weighting: VoteWeighting::Linear {},
+                objection_threshold: None,
+                voting_start: None,
+                amendable: false,
                 votes: Votes::zero(),
             },
         )
@@ -3421,6 +3468,7 @@ fn test_proposal_too_large() {
                 description: "a".repeat(MAX_PROPOSAL_SIZE as usize),
                 msgs: vec![],
                 proposer: None,
+                immutable: false,
             }),
             &[],
         )
@@ -3472,6 +3520,7 @@ fn test_proposal_creation_permissions() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                immutable: false,
             }),
             &[],
         )
@@ -3497,6 +3546,7 @@ fn test_proposal_creation_permissions() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                immutable: false,
             }),
             &[],
         )
@@ -3527,6 +3577,7 @@ fn test_proposal_creation_permissions() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: Some("ekez".to_string()),
+                immutable: false,
             }),
             &[],
         )
@@ -4087,3 +4138,693 @@ fn test_proposal_count_goes_up() {
     let next = query_next_proposal_id(&app, &proposal_module);
     assert_eq!(next, 3);
 }
+
+#[test]
+fn test_delegated_voting() {
+    let mut app = App::default();
+    let instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(10),
+            },
+            Cw20Coin {
+                address: "delegate".to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "early".to_string(),
+                amount: Uint128::new(3),
+            },
+            Cw20Coin {
+                address: "late".to_string(),
+                amount: Uint128::new(2),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let registry_id = app.store_code(dao_testing::contracts::dao_vote_delegation_contract());
+    let registry = app
+        .instantiate_contract(
+            registry_id,
+            core_addr.clone(),
+            &dao_vote_delegation::msg::InstantiateMsg {
+                dao: core_addr.to_string(),
+                max_delegators: 10,
+            },
+            &[],
+            "registry",
+            None,
+        )
+        .unwrap();
+
+    // Only the DAO may set the delegation module.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::UpdateDelegationModule {
+                module: Some(registry.to_string()),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &ExecuteMsg::UpdateDelegationModule {
+            module: Some(registry.to_string()),
+        },
+        &[],
+    )
+    .unwrap();
+    let module: Option<Addr> = app
+        .wrap()
+        .query_wasm_smart(&proposal_module, &QueryMsg::DelegationModule {})
+        .unwrap();
+    assert_eq!(module, Some(registry.clone()));
+
+    app.execute_contract(
+        Addr::unchecked("delegate"),
+        registry.clone(),
+        &dao_vote_delegation::msg::ExecuteMsg::Register {
+            min_delegated_power: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let delegate = |app: &mut App, delegator: &str| {
+        app.execute_contract(
+            Addr::unchecked(delegator),
+            registry.clone(),
+            &dao_vote_delegation::msg::ExecuteMsg::Delegate {
+                delegate: "delegate".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    };
+
+    delegate(&mut app, "early");
+    app.update_block(next_block);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+
+    // Delegations made after the proposal's start height are not
+    // counted.
+    delegate(&mut app, "late");
+    app.update_block(next_block);
+
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        "delegate",
+        proposal_id,
+        Vote::Yes,
+    );
+    let vote = query_vote(&app, &proposal_module, "delegate", proposal_id);
+    assert_eq!(vote.vote.unwrap().power, Uint128::new(4));
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.votes.yes, Uint128::new(4));
+
+    // A delegator's own vote overrides their delegate's.
+    vote_on_proposal(&mut app, &proposal_module, "early", proposal_id, Vote::No);
+    let vote = query_vote(&app, &proposal_module, "delegate", proposal_id);
+    assert_eq!(vote.vote.unwrap().power, Uint128::new(1));
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.votes.yes, Uint128::new(1));
+    assert_eq!(proposal.proposal.votes.no, Uint128::new(3));
+
+    vote_on_proposal(&mut app, &proposal_module, "late", proposal_id, Vote::Yes);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.votes.yes, Uint128::new(3));
+    assert_eq!(proposal.proposal.votes.no, Uint128::new(3));
+
+    // Delegators who have already voted are not counted in their
+    // delegate's vote.
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(&mut app, &proposal_module, "early", proposal_id, Vote::No);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        "delegate",
+        proposal_id,
+        Vote::Yes,
+    );
+    let vote = query_vote(&app, &proposal_module, "delegate", proposal_id);
+    assert_eq!(vote.vote.unwrap().power, Uint128::new(3));
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.votes.yes, Uint128::new(3));
+    assert_eq!(proposal.proposal.votes.no, Uint128::new(3));
+
+    // Removing the delegation module stops delegated voting.
+    app.execute_contract(
+        core_addr,
+        proposal_module.clone(),
+        &ExecuteMsg::UpdateDelegationModule { module: None },
+        &[],
+    )
+    .unwrap();
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        "delegate",
+        proposal_id,
+        Vote::Yes,
+    );
+    let vote = query_vote(&app, &proposal_module, "delegate", proposal_id);
+    assert_eq!(vote.vote.unwrap().power, Uint128::new(1));
+}
+
+#[test]
+fn test_quadratic_voting() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.threshold = Threshold::AbsolutePercentage {
+        percentage: PercentageThreshold::Majority {},
+    };
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(49),
+            },
+            Cw20Coin {
+                address: "whale".to_string(),
+                amount: Uint128::new(100),
+            },
+            Cw20Coin {
+                address: "minnow".to_string(),
+                amount: Uint128::new(36),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    // Proposals keep the weighting they were created with.
+    let linear_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+
+    let config = query_proposal_config(&app, &proposal_module);
+    app.execute_contract(
+        core_addr,
+        proposal_module.clone(),
+        &ExecuteMsg::UpdateConfig {
+            threshold: config.threshold,
+            max_voting_period: config.max_voting_period,
+            min_voting_period: config.min_voting_period,
+            only_members_execute: config.only_members_execute,
+            allow_revoting: config.allow_revoting,
+            dao: config.dao.to_string(),
+            close_proposal_on_execution_failure: config.close_proposal_on_execution_failure,
+            veto: config.veto,
+            vote_weighting: VoteWeighting::Quadratic {},
+            objection_threshold: None,
+            review_period: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let config = query_proposal_config(&app, &proposal_module);
+    assert_eq!(config.vote_weighting, VoteWeighting::Quadratic {});
+
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        linear_id,
+        Vote::Yes,
+    );
+    let vote = query_vote(&app, &proposal_module, CREATOR_ADDR, linear_id);
+    assert_eq!(vote.vote.unwrap().power, Uint128::new(49));
+    let proposal = query_proposal(&app, &proposal_module, linear_id);
+    assert_eq!(proposal.proposal.total_power, Uint128::new(185));
+
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(
+        proposal.proposal.vote_weighting,
+        VoteWeighting::Quadratic {}
+    );
+    assert_eq!(proposal.proposal.total_power, Uint128::new(185));
+
+    // Counted voters contribute their weight, rather than their
+    // voting power, to the proposal's total power.
+    vote_on_proposal(&mut app, &proposal_module, "whale", proposal_id, Vote::No);
+    let vote = query_vote(&app, &proposal_module, "whale", proposal_id);
+    assert_eq!(vote.vote.unwrap().power, Uint128::new(10));
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.votes.no, Uint128::new(10));
+    assert_eq!(proposal.proposal.total_power, Uint128::new(95));
+
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.total_power, Uint128::new(53));
+    assert_eq!(proposal.proposal.status, Status::Open);
+
+    // 7 + 6 yes votes out of a total power of 23.
+    vote_on_proposal(&mut app, &proposal_module, "minnow", proposal_id, Vote::Yes);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.votes.yes, Uint128::new(13));
+    assert_eq!(proposal.proposal.total_power, Uint128::new(23));
+    assert_eq!(proposal.proposal.status, Status::Passed);
+}
+
+/// Sets the objection threshold and review period of
+/// `proposal_module`, leaving the rest of its config unchanged.
+fn update_optimistic_config(
+    app: &mut App,
+    sender: &Addr,
+    proposal_module: &Addr,
+    objection_threshold: Option<PercentageThreshold>,
+    review_period: Option<Duration>,
+) -> Result<(), ContractError> {
+    let config = query_proposal_config(app, proposal_module);
+    app.execute_contract(
+        sender.clone(),
+        proposal_module.clone(),
+        &ExecuteMsg::UpdateConfig {
+            threshold: config.threshold,
+            max_voting_period: config.max_voting_period,
+            min_voting_period: config.min_voting_period,
+            only_members_execute: config.only_members_execute,
+            allow_revoting: config.allow_revoting,
+            dao: config.dao.to_string(),
+            close_proposal_on_execution_failure: config.close_proposal_on_execution_failure,
+            veto: config.veto,
+            vote_weighting: config.vote_weighting,
+            objection_threshold,
+            review_period,
+        },
+        &[],
+    )
+    .map(|_| ())
+    .map_err(|e| e.downcast().unwrap())
+}
+
+#[test]
+fn test_optimistic_proposals() -> anyhow::Result<()> {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    let veto_config = VetoConfig {
+        timelock_duration: Duration::Time(100),
+        vetoer: "oversight".to_string(),
+        early_execute: false,
+        veto_before_passed: false,
+    };
+    instantiate.veto = Some(veto_config.clone());
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(70),
+            },
+            Cw20Coin {
+                address: "objector".to_string(),
+                amount: Uint128::new(30),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    // Only the DAO may set the objection threshold.
+    let err = update_optimistic_config(
+        &mut app,
+        &Addr::unchecked(CREATOR_ADDR),
+        &proposal_module,
+        Some(PercentageThreshold::Percent(Decimal::percent(25))),
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let err = update_optimistic_config(
+        &mut app,
+        &core_addr,
+        &proposal_module,
+        Some(PercentageThreshold::Percent(Decimal::zero())),
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ThresholdError(ThresholdError::ZeroThreshold {})
+    );
+
+    update_optimistic_config(
+        &mut app,
+        &core_addr,
+        &proposal_module,
+        Some(PercentageThreshold::Percent(Decimal::percent(25))),
+        None,
+    )
+    .unwrap();
+    let config = query_proposal_config(&app, &proposal_module);
+    assert_eq!(
+        config.objection_threshold,
+        Some(PercentageThreshold::Percent(Decimal::percent(25)))
+    );
+
+    let unopposed_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    let opposed_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+
+    // 30 of 100 voting power objecting rejects the proposal.
+    vote_on_proposal(&mut app, &proposal_module, "objector", opposed_id, Vote::No);
+    let proposal = query_proposal(&app, &proposal_module, opposed_id);
+    assert_eq!(proposal.proposal.status, Status::Rejected);
+    close_proposal(&mut app, &proposal_module, CREATOR_ADDR, opposed_id);
+
+    // Without objections a proposal stays open until it expires, and
+    // then enters the veto timelock.
+    let proposal = query_proposal(&app, &proposal_module, unopposed_id);
+    assert_eq!(proposal.proposal.status, Status::Open);
+    app.update_block(|block| {
+        block.time = block.time.plus_seconds(604800);
+    });
+    let proposal = query_proposal(&app, &proposal_module, unopposed_id);
+    assert_eq!(
+        proposal.proposal.status,
+        Status::VetoTimelock {
+            expiration: proposal
+                .proposal
+                .expiration
+                .add(veto_config.timelock_duration)?,
+        }
+    );
+
+    app.update_block(|block| {
+        block.time = block.time.plus_seconds(100);
+    });
+    let proposal = query_proposal(&app, &proposal_module, unopposed_id);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, unopposed_id);
+    let proposal = query_proposal(&app, &proposal_module, unopposed_id);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+
+    Ok(())
+}
+
+#[test]
+fn test_amend_proposal() {
+    let mut app = App::default();
+    let instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(10),
+            },
+            Cw20Coin {
+                address: "voter".to_string(),
+                amount: Uint128::new(5),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let hook_counter_id = app.store_code(proposal_hook_counter_contract());
+    let hook_counter = app
+        .instantiate_contract(
+            hook_counter_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &dao_proposal_hook_counter::msg::InstantiateMsg {
+                should_error: false,
+            },
+            &[],
+            "hook counter",
+            None,
+        )
+        .unwrap();
+    add_proposal_hook(
+        &mut app,
+        &proposal_module,
+        core_addr.as_str(),
+        hook_counter.as_str(),
+    );
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &ExecuteMsg::AddAmendmentHook {
+            address: hook_counter.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let hooks: HooksResponse = app
+        .wrap()
+        .query_wasm_smart(&proposal_module, &QueryMsg::AmendmentHooks {})
+        .unwrap();
+    assert_eq!(hooks.hooks, vec![hook_counter.to_string()]);
+
+    // The review period must use the same units as the voting period.
+    let err = update_optimistic_config(
+        &mut app,
+        &core_addr,
+        &proposal_module,
+        None,
+        Some(Duration::Height(10)),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ReviewPeriodUnitsConflict {});
+
+    update_optimistic_config(
+        &mut app,
+        &core_addr,
+        &proposal_module,
+        None,
+        Some(Duration::Time(100)),
+    )
+    .unwrap();
+
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    let voting_start = Duration::Time(100).after(&app.block_info());
+    assert_eq!(proposal.proposal.voting_start, Some(voting_start));
+    assert_eq!(
+        proposal.proposal.expiration,
+        voting_start.add(Duration::Time(604800)).unwrap()
+    );
+
+    // Votes may not be cast during the review period.
+    let err =
+        vote_on_proposal_should_fail(&mut app, &proposal_module, "voter", proposal_id, Vote::Yes);
+    assert_eq!(err, ContractError::VotingNotStarted { id: proposal_id });
+
+    // Only the proposer may amend a proposal.
+    let amend = |app: &mut App, sender: &str, title: &str| {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            proposal_module.clone(),
+            &ExecuteMsg::Amend {
+                proposal_id,
+                title: Some(title.to_string()),
+                description: None,
+                msgs: None,
+            },
+            &[],
+        )
+    };
+    let err: ContractError = amend(&mut app, "voter", "hijacked")
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    amend(&mut app, CREATOR_ADDR, "amended").unwrap();
+    app.update_block(next_block);
+    amend(&mut app, CREATOR_ADDR, "amended again").unwrap();
+
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.title, "amended again");
+    assert_eq!(proposal.proposal.description, "description");
+    let amended: dao_proposal_hook_counter::msg::CountResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &hook_counter,
+            &dao_proposal_hook_counter::msg::QueryMsg::AmendedCounter {},
+        )
+        .unwrap();
+    // Amendments are only sent to amendment hooks, and not to the
+    // counter's proposal hook registration.
+    assert_eq!(amended.count, 2);
+
+    let revisions: ProposalRevisionsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &proposal_module,
+            &QueryMsg::ProposalRevisions {
+                proposal_id,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        revisions
+            .revisions
+            .iter()
+            .map(|revision| (revision.revision, revision.title.as_str()))
+            .collect::<Vec<_>>(),
+        vec![(0, "title"), (1, "amended")]
+    );
+    assert_eq!(
+        revisions.revisions[1].amended_at_height,
+        app.block_info().height
+    );
+
+    let revisions: ProposalRevisionsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &proposal_module,
+            &QueryMsg::ProposalRevisions {
+                proposal_id,
+                start_after: Some(0),
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(revisions.revisions.len(), 1);
+
+    // Once votes have been cast the proposal can not be amended.
+    app.update_block(|block| block.time = block.time.plus_seconds(100));
+    vote_on_proposal(&mut app, &proposal_module, "voter", proposal_id, Vote::Yes);
+    let err: ContractError = amend(&mut app, CREATOR_ADDR, "too late")
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NotAmendable { id: proposal_id });
+}
+
+#[test]
+fn test_amend_proposal_without_review_period() {
+    let mut app = App::default();
+    let instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(10),
+            },
+            Cw20Coin {
+                address: "voter".to_string(),
+                amount: Uint128::new(5),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    let amend = |app: &mut App, title: &str| -> Result<_, ContractError> {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Amend {
+                proposal_id,
+                title: Some(title.to_string()),
+                description: None,
+                msgs: None,
+            },
+            &[],
+        )
+        .map_err(|e| e.downcast().unwrap())
+    };
+
+    // Without a review period a proposal may be amended until the
+    // first vote is cast on it.
+    amend(&mut app, "amended").unwrap();
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.title, "amended");
+
+    vote_on_proposal(&mut app, &proposal_module, "voter", proposal_id, Vote::Yes);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Open);
+    assert_eq!(
+        amend(&mut app, "too late").unwrap_err(),
+        ContractError::NotAmendable { id: proposal_id }
+    );
+}
+
+#[test]
+fn test_amend_optimistic_proposal() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.objection_threshold = Some(PercentageThreshold::Percent(Decimal::percent(25)));
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    // Without a review period optimistic proposals can not be amended,
+    // even before any objections have been cast.
+    let unreviewed_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+
+    update_optimistic_config(
+        &mut app,
+        &core_addr,
+        &proposal_module,
+        Some(PercentageThreshold::Percent(Decimal::percent(25))),
+        Some(Duration::Time(100)),
+    )
+    .unwrap();
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+
+    let amend = |app: &mut App, proposal_id: u64| -> Result<_, ContractError> {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Amend {
+                proposal_id,
+                title: None,
+                description: None,
+                msgs: Some(vec![BankMsg::Send {
+                    to_address: CREATOR_ADDR.to_string(),
+                    amount: coins(10, "ujuno"),
+                }
+                .into()]),
+            },
+            &[],
+        )
+        .map_err(|e| e.downcast().unwrap())
+    };
+
+    assert_eq!(
+        amend(&mut app, unreviewed_id).unwrap_err(),
+        ContractError::NotAmendable { id: unreviewed_id }
+    );
+    amend(&mut app, proposal_id).unwrap();
+
+    // An optimistic proposal passes without votes, so it may not be
+    // amended once voting has started, even though no votes have been
+    // cast.
+    app.update_block(|block| block.time = block.time.plus_seconds(100));
+    assert_eq!(
+        amend(&mut app, proposal_id).unwrap_err(),
+        ContractError::NotAmendable { id: proposal_id }
+    );
+    app.update_block(|block| block.time = block.time.plus_seconds(604799));
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Open);
+    assert_eq!(
+        amend(&mut app, proposal_id).unwrap_err(),
+        ContractError::NotAmendable { id: proposal_id }
+    );
+}
@@ -10,7 +10,8 @@ use dao_hooks::{proposal::ProposalHookMsg, vote::VoteHookMsg};
 use crate::error::ContractError;
 use crate::msg::{CountResponse, ExecuteMsg, InstantiateMsg, QueryMsg};
 use crate::state::{
-    Config, CONFIG, PROPOSAL_COUNTER, STAKE_COUNTER, STATUS_CHANGED_COUNTER, VOTE_COUNTER,
+    Config, AMENDED_COUNTER, CONFIG, PROPOSAL_COUNTER, STAKE_COUNTER, STATUS_CHANGED_COUNTER,
+    VOTE_COUNTER,
 };
 
 const CONTRACT_NAME: &str = "crates.io:proposal-hooks-counter";
@@ -33,6 +34,7 @@ pub fn instantiate(
     STAKE_COUNTER.save(deps.storage, &Uint128::zero())?;
     VOTE_COUNTER.save(deps.storage, &0)?;
     STATUS_CHANGED_COUNTER.save(deps.storage, &0)?;
+    AMENDED_COUNTER.save(deps.storage, &0)?;
     Ok(Response::new().add_attribute("action", "instantiate"))
 }
 
@@ -74,6 +76,11 @@ pub fn execute_proposal_hook(
             count = count.checked_add(1).unwrap_or_default();
             STATUS_CHANGED_COUNTER.save(deps.storage, &count)?;
         }
+        ProposalHookMsg::ProposalAmended { .. } => {
+            let mut count = AMENDED_COUNTER.load(deps.storage)?;
+            count = count.checked_add(1).unwrap_or_default();
+            AMENDED_COUNTER.save(deps.storage, &count)?;
+        }
     }
 
     Ok(Response::new().add_attribute("action", "proposal_hook"))
@@ -131,5 +138,8 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::VoteCounter {} => to_json_binary(&CountResponse {
             count: VOTE_COUNTER.load(deps.storage)?,
         }),
+        QueryMsg::AmendedCounter {} => to_json_binary(&CountResponse {
+            count: AMENDED_COUNTER.load(deps.storage)?,
+        }),
     }
 }
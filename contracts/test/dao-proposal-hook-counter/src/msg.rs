@@ -25,6 +25,8 @@ pub enum QueryMsg {
     ProposalCounter {},
     #[returns(u64)]
     StatusChangedCounter {},
+    #[returns(u64)]
+    AmendedCounter {},
 }
 
 #[cw_serde]
@@ -8,7 +8,7 @@ use dao_interface::state::{Admin, ModuleInstantiateInfo};
 use dao_voting::{
     pre_propose::PreProposeInfo,
     threshold::{PercentageThreshold, Threshold},
-    voting::Vote,
+    voting::{Vote, VoteWeighting},
 };
 
 use crate::msg::{CountResponse, InstantiateMsg, QueryMsg};
@@ -156,6 +156,9 @@ fn test_counters() {
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         close_proposal_on_execution_failure: true,
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
+        objection_threshold: None,
+        review_period: None,
     };
 
     let governance_addr =
@@ -260,6 +263,7 @@ fn test_counters() {
             description: "This is a simple text proposal".to_string(),
             msgs: vec![],
             proposer: None,
+            immutable: false,
         }),
         &[],
     )
@@ -360,6 +364,7 @@ fn test_counters() {
             description: "This is a simple text proposal 2nd".to_string(),
             msgs: vec![],
             proposer: None,
+            immutable: false,
         }),
         &[],
     )
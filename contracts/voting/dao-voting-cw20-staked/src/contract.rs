@@ -7,14 +7,18 @@ use cosmwasm_std::{
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
 use cw20::{Cw20Coin, TokenInfoResponse};
 use cw_utils::parse_reply_instantiate_data;
+use dao_hooks::stake::StakeChangedHookMsg;
 use dao_interface::voting::IsActiveResponse;
-use dao_voting::threshold::{ActiveThreshold, ActiveThresholdResponse};
+use dao_voting::{
+    conviction::ConvictionConfig,
+    threshold::{ActiveThreshold, ActiveThresholdResponse},
+};
 use std::convert::TryInto;
 
 use crate::error::ContractError;
 use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, StakingInfo, TokenInfo};
 use crate::state::{
-    ACTIVE_THRESHOLD, DAO, STAKING_CONTRACT, STAKING_CONTRACT_CODE_ID,
+    ACTIVE_THRESHOLD, CONVICTION, DAO, STAKING_CONTRACT, STAKING_CONTRACT_CODE_ID,
     STAKING_CONTRACT_UNSTAKING_DURATION, TOKEN,
 };
 
@@ -193,6 +197,10 @@ pub fn execute(
         ExecuteMsg::UpdateActiveThreshold { new_threshold } => {
             execute_update_active_threshold(deps, env, info, new_threshold)
         }
+        ExecuteMsg::UpdateConviction { conviction } => {
+            execute_update_conviction(deps, env, info, conviction)
+        }
+        ExecuteMsg::StakeChangeHook(msg) => execute_stake_changed(deps, env, info, msg),
     }
 }
 
@@ -227,6 +235,73 @@ pub fn execute_update_active_threshold(
     Ok(Response::new().add_attribute("action", "update_active_threshold"))
 }
 
+pub fn execute_update_conviction(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    conviction: ConvictionConfig,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Stake age is only tracked while this contract receives the
+    // staking contract's stake change hooks.
+    let staking_contract = STAKING_CONTRACT.load(deps.storage)?;
+    let hooks: cw20_stake::msg::GetHooksResponse = deps
+        .querier
+        .query_wasm_smart(staking_contract, &cw20_stake::msg::QueryMsg::GetHooks {})?;
+    if !hooks
+        .hooks
+        .iter()
+        .any(|hook| hook == env.contract.address.as_str())
+    {
+        return Err(ContractError::NotStakeHook {});
+    }
+
+    CONVICTION.update_config(deps.storage, conviction, env.block.height)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_conviction")
+        .add_attribute("maturity_blocks", conviction.maturity_blocks.to_string())
+        .add_attribute("max_multiplier", conviction.max_multiplier.to_string()))
+}
+
+pub fn execute_stake_changed(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: StakeChangedHookMsg,
+) -> Result<Response, ContractError> {
+    let staking_contract = STAKING_CONTRACT.load(deps.storage)?;
+    if info.sender != staking_contract {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    match msg {
+        StakeChangedHookMsg::Stake { addr, amount } => {
+            CONVICTION.stake(deps.storage, &addr, amount, env.block.height)?;
+        }
+        StakeChangedHookMsg::Unstake { addr, .. } => {
+            // Staked balances at a height do not include changes made
+            // during that height, so query the next one for the
+            // balance remaining after this unstake.
+            let remaining: cw20_stake::msg::StakedBalanceAtHeightResponse =
+                deps.querier.query_wasm_smart(
+                    staking_contract,
+                    &cw20_stake::msg::QueryMsg::StakedBalanceAtHeight {
+                        address: addr.to_string(),
+                        height: Some(env.block.height + 1),
+                    },
+                )?;
+            CONVICTION.unstake(deps.storage, &addr, remaining.balance, env.block.height)?;
+        }
+    }
+
+    Ok(Response::new().add_attribute("action", "stake_change_hook"))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -240,6 +315,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Dao {} => query_dao(deps),
         QueryMsg::IsActive {} => query_is_active(deps),
         QueryMsg::ActiveThreshold {} => query_active_threshold(deps),
+        QueryMsg::Conviction {} => to_json_binary(&CONVICTION.config(deps.storage)?),
     }
 }
 
@@ -268,8 +344,9 @@ pub fn query_voting_power_at_height(
             height,
         },
     )?;
+    let power = CONVICTION.power_at_height(deps.storage, &address, res.balance, res.height)?;
     to_json_binary(&dao_interface::voting::VotingPowerAtHeightResponse {
-        power: res.balance,
+        power,
         height: res.height,
     })
 }
@@ -284,8 +361,9 @@ pub fn query_total_power_at_height(
         staking_contract,
         &cw20_stake::msg::QueryMsg::TotalStakedAtHeight { height },
     )?;
+    let power = CONVICTION.total_power_at_height(deps.storage, res.total, res.height)?;
     to_json_binary(&dao_interface::voting::TotalPowerAtHeightResponse {
-        power: res.total,
+        power,
         height: res.height,
     })
 }
@@ -6,6 +6,9 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error(transparent)]
+    ConvictionError(#[from] dao_voting::conviction::ConvictionError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
@@ -38,4 +41,7 @@ pub enum ContractError {
 
     #[error("Absolute count threshold cannot be greater than the total token supply")]
     InvalidAbsoluteCount {},
+
+    #[error("Conviction requires this contract to be a stake change hook of the staking contract")]
+    NotStakeHook {},
 }
@@ -5,7 +5,11 @@ use cw20_base::msg::InstantiateMarketingInfo;
 use cw_utils::Duration;
 
 use dao_dao_macros::{active_query, cw20_token_query, voting_module_query};
-use dao_voting::threshold::{ActiveThreshold, ActiveThresholdResponse};
+use dao_hooks::stake::StakeChangedHookMsg;
+use dao_voting::{
+    conviction::ConvictionConfig,
+    threshold::{ActiveThreshold, ActiveThresholdResponse},
+};
 
 /// Information about the staking contract to be used with this voting
 /// module.
@@ -68,6 +72,15 @@ pub enum ExecuteMsg {
     UpdateActiveThreshold {
         new_threshold: Option<ActiveThreshold>,
     },
+    /// Enables or updates conviction, which weights voting power by
+    /// how long tokens have been staked. Stake age is tracked through
+    /// the staking contract's stake change hooks, so this contract
+    /// must first be added as a hook of the staking contract. Once
+    /// enabled, only the maximum multiplier may be updated. Only the
+    /// DAO may call this method.
+    UpdateConviction { conviction: ConvictionConfig },
+    /// Called by the staking contract when stake changes.
+    StakeChangeHook(StakeChangedHookMsg),
 }
 
 #[voting_module_query]
@@ -82,6 +95,8 @@ pub enum QueryMsg {
     StakingContract {},
     #[returns(ActiveThresholdResponse)]
     ActiveThreshold {},
+    #[returns(Option<ConvictionConfig>)]
+    Conviction {},
 }
 
 #[cw_serde]
@@ -1,7 +1,7 @@
 use cosmwasm_std::Addr;
 use cw_storage_plus::Item;
 use cw_utils::Duration;
-use dao_voting::threshold::ActiveThreshold;
+use dao_voting::{conviction::Conviction, threshold::ActiveThreshold};
 
 pub const ACTIVE_THRESHOLD: Item<ActiveThreshold> = Item::new("active_threshold");
 pub const TOKEN: Item<Addr> = Item::new("token");
@@ -10,3 +10,6 @@ pub const STAKING_CONTRACT: Item<Addr> = Item::new("staking_contract");
 pub const STAKING_CONTRACT_UNSTAKING_DURATION: Item<Option<Duration>> =
     Item::new("staking_contract_unstaking_duration");
 pub const STAKING_CONTRACT_CODE_ID: Item<u64> = Item::new("staking_contract_code_id");
+/// Tracks stake age for conviction weighted voting power. Updated by
+/// stake change hooks from the staking contract.
+pub const CONVICTION: Conviction = Conviction::new();
@@ -5,12 +5,19 @@ use cosmwasm_std::{
 use cw2::ContractVersion;
 use cw20::{BalanceResponse, Cw20Coin, MinterResponse, TokenInfoResponse};
 use cw_multi_test::{next_block, App, Contract, ContractWrapper, Executor};
-use dao_interface::voting::{InfoResponse, IsActiveResponse, VotingPowerAtHeightResponse};
-use dao_voting::threshold::{ActiveThreshold, ActiveThresholdResponse};
+use dao_hooks::stake::StakeChangedHookMsg;
+use dao_interface::voting::{
+    InfoResponse, IsActiveResponse, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse,
+};
+use dao_voting::{
+    conviction::ConvictionConfig,
+    threshold::{ActiveThreshold, ActiveThresholdResponse},
+};
 
 use crate::{
     contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION},
     msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, StakingInfo},
+    ContractError,
 };
 
 const DAO_ADDR: &str = "dao";
@@ -1325,6 +1332,156 @@ fn test_active_threshold_absolute_count_invalid() {
     );
 }
 
+#[test]
+fn test_conviction() {
+    let mut app = App::default();
+    let cw20_id = app.store_code(cw20_contract());
+    let voting_id = app.store_code(staked_balance_voting_contract());
+    let staking_contract_id = app.store_code(staking_contract());
+
+    let voting_addr = instantiate_voting(
+        &mut app,
+        voting_id,
+        InstantiateMsg {
+            token_info: crate::msg::TokenInfo::New {
+                code_id: cw20_id,
+                label: "DAO DAO voting".to_string(),
+                name: "DAO DAO".to_string(),
+                symbol: "DAO".to_string(),
+                decimals: 6,
+                initial_balances: vec![Cw20Coin {
+                    address: CREATOR_ADDR.to_string(),
+                    amount: Uint128::from(200u64),
+                }],
+                marketing: None,
+                unstaking_duration: None,
+                staking_code_id: staking_contract_id,
+                initial_dao_balance: None,
+            },
+            active_threshold: None,
+        },
+    );
+    let token_addr: Addr = app
+        .wrap()
+        .query_wasm_smart(voting_addr.clone(), &QueryMsg::TokenContract {})
+        .unwrap();
+    let staking_addr: Addr = app
+        .wrap()
+        .query_wasm_smart(voting_addr.clone(), &QueryMsg::StakingContract {})
+        .unwrap();
+
+    let conviction = ExecuteMsg::UpdateConviction {
+        conviction: ConvictionConfig {
+            maturity_blocks: 10,
+            max_multiplier: Decimal::percent(300),
+        },
+    };
+
+    // Stake age is tracked through the staking contract's hooks, so
+    // conviction may not be enabled before this contract is one.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(DAO_ADDR),
+            voting_addr.clone(),
+            &conviction,
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::NotStakeHook {}));
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        staking_addr.clone(),
+        &cw20_stake::msg::ExecuteMsg::AddHook {
+            addr: voting_addr.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            voting_addr.clone(),
+            &conviction,
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        voting_addr.clone(),
+        &conviction,
+        &[],
+    )
+    .unwrap();
+
+    // Only the staking contract may call the hook.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            voting_addr.clone(),
+            &ExecuteMsg::StakeChangeHook(StakeChangedHookMsg::Stake {
+                addr: Addr::unchecked(CREATOR_ADDR),
+                amount: Uint128::new(1000),
+            }),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    let power = |app: &App| -> (Uint128, Uint128) {
+        let voting: VotingPowerAtHeightResponse = app
+            .wrap()
+            .query_wasm_smart(
+                voting_addr.clone(),
+                &QueryMsg::VotingPowerAtHeight {
+                    address: CREATOR_ADDR.to_string(),
+                    height: None,
+                },
+            )
+            .unwrap();
+        let total: TotalPowerAtHeightResponse = app
+            .wrap()
+            .query_wasm_smart(
+                voting_addr.clone(),
+                &QueryMsg::TotalPowerAtHeight { height: None },
+            )
+            .unwrap();
+        (voting.power, total.power)
+    };
+
+    stake_tokens(
+        &mut app,
+        staking_addr.clone(),
+        token_addr,
+        CREATOR_ADDR,
+        100,
+    );
+    app.update_block(|block| block.height += 5);
+    assert_eq!(power(&app), (Uint128::new(200), Uint128::new(200)));
+    app.update_block(|block| block.height += 5);
+    assert_eq!(power(&app), (Uint128::new(300), Uint128::new(300)));
+
+    // Unstaking resets the age of the remaining stake.
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        staking_addr,
+        &cw20_stake::msg::ExecuteMsg::Unstake {
+            amount: Uint128::new(50),
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+    assert_eq!(power(&app), (Uint128::new(60), Uint128::new(60)));
+}
+
 #[test]
 fn test_migrate() {
     let mut app = App::default();
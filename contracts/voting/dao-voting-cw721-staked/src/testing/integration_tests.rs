@@ -15,6 +15,7 @@ use dao_testing::test_tube::{cw721_base::Cw721Base, dao_dao_core::DaoCore};
 use dao_voting::{
     pre_propose::PreProposeInfo,
     threshold::{ActiveThreshold, PercentageThreshold, Threshold},
+    voting::VoteWeighting,
 };
 use osmosis_test_tube::{Account, OsmosisTestApp, RunnerError};
 
@@ -113,6 +114,9 @@ fn test_full_integration_with_factory() {
                 close_proposal_on_execution_failure: false,
                 pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                 veto: None,
+                vote_weighting: VoteWeighting::Linear {},
+                objection_threshold: None,
+                review_period: None,
             })
             .unwrap(),
             admin: Some(Admin::CoreModule {}),
@@ -15,6 +15,7 @@ use dao_interface::{
 };
 use dao_voting::{
     pre_propose::PreProposeInfo, threshold::PercentageThreshold, threshold::Threshold,
+    voting::VoteWeighting,
 };
 
 use cw721_base::msg::{ExecuteMsg as Cw721ExecuteMsg, InstantiateMsg as Cw721InstantiateMsg};
@@ -137,6 +138,9 @@ impl TestEnvBuilder {
                     close_proposal_on_execution_failure: false,
                     pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                     veto: None,
+                    vote_weighting: VoteWeighting::Linear {},
+                    objection_threshold: None,
+                    review_period: None,
                 })
                 .unwrap(),
                 admin: Some(Admin::CoreModule {}),
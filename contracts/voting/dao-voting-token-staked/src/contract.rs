@@ -23,6 +23,7 @@ use dao_interface::{
     },
 };
 use dao_voting::{
+    conviction::ConvictionConfig,
     duration::validate_duration,
     threshold::{
         assert_valid_absolute_count_threshold, assert_valid_percentage_threshold, ActiveThreshold,
@@ -36,8 +37,8 @@ use crate::msg::{
     StakerBalanceResponse, TokenInfo,
 };
 use crate::state::{
-    Config, ACTIVE_THRESHOLD, CLAIMS, CONFIG, DAO, DENOM, HOOKS, MAX_CLAIMS, STAKED_BALANCES,
-    STAKED_TOTAL, TOKEN_INSTANTIATION_INFO, TOKEN_ISSUER_CONTRACT,
+    Config, ACTIVE_THRESHOLD, CLAIMS, CONFIG, CONVICTION, DAO, DENOM, HOOKS, MAX_CLAIMS,
+    STAKED_BALANCES, STAKED_TOTAL, TOKEN_INSTANTIATION_INFO, TOKEN_ISSUER_CONTRACT,
 };
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-voting-token-staked";
@@ -171,6 +172,9 @@ pub fn execute(
         }
         ExecuteMsg::AddHook { addr } => execute_add_hook(deps, env, info, addr),
         ExecuteMsg::RemoveHook { addr } => execute_remove_hook(deps, env, info, addr),
+        ExecuteMsg::UpdateConviction { conviction } => {
+            execute_update_conviction(deps, env, info, conviction)
+        }
     }
 }
 
@@ -188,6 +192,7 @@ pub fn execute_stake(
         env.block.height,
         |balance| -> StdResult<Uint128> { Ok(balance.unwrap_or_default().checked_add(amount)?) },
     )?;
+    CONVICTION.stake(deps.storage, &info.sender, amount, env.block.height)?;
     STAKED_TOTAL.update(
         deps.storage,
         env.block.height,
@@ -214,7 +219,7 @@ pub fn execute_unstake(
         return Err(ContractError::ZeroUnstake {});
     }
 
-    STAKED_BALANCES.update(
+    let remaining = STAKED_BALANCES.update(
         deps.storage,
         &info.sender,
         env.block.height,
@@ -225,6 +230,7 @@ pub fn execute_unstake(
                 .map_err(|_e| ContractError::InvalidUnstakeAmount {})
         },
     )?;
+    CONVICTION.unstake(deps.storage, &info.sender, remaining, env.block.height)?;
     STAKED_TOTAL.update(
         deps.storage,
         env.block.height,
@@ -298,6 +304,26 @@ pub fn execute_update_config(
     Ok(Response::new().add_attribute("action", "update_config"))
 }
 
+pub fn execute_update_conviction(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    conviction: ConvictionConfig,
+) -> Result<Response, ContractError> {
+    // Only the DAO can update the conviction config
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    CONVICTION.update_config(deps.storage, conviction, env.block.height)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_conviction")
+        .add_attribute("maturity_blocks", conviction.maturity_blocks.to_string())
+        .add_attribute("max_multiplier", conviction.max_multiplier.to_string()))
+}
+
 pub fn execute_claim(
     deps: DepsMut,
     env: Env,
@@ -409,6 +435,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::IsActive {} => query_is_active(deps),
         QueryMsg::ActiveThreshold {} => query_active_threshold(deps),
         QueryMsg::GetHooks {} => to_json_binary(&query_hooks(deps)?),
+        QueryMsg::Conviction {} => to_json_binary(&CONVICTION.config(deps.storage)?),
         QueryMsg::TokenContract {} => {
             to_json_binary(&TOKEN_ISSUER_CONTRACT.may_load(deps.storage)?)
         }
@@ -423,9 +450,10 @@ pub fn query_voting_power_at_height(
 ) -> StdResult<VotingPowerAtHeightResponse> {
     let height = height.unwrap_or(env.block.height);
     let address = deps.api.addr_validate(&address)?;
-    let power = STAKED_BALANCES
+    let staked = STAKED_BALANCES
         .may_load_at_height(deps.storage, &address, height)?
         .unwrap_or_default();
+    let power = CONVICTION.power_at_height(deps.storage, &address, staked, height)?;
     Ok(VotingPowerAtHeightResponse { power, height })
 }
 
@@ -435,9 +463,10 @@ pub fn query_total_power_at_height(
     height: Option<u64>,
 ) -> StdResult<TotalPowerAtHeightResponse> {
     let height = height.unwrap_or(env.block.height);
-    let power = STAKED_TOTAL
+    let staked = STAKED_TOTAL
         .may_load_at_height(deps.storage, height)?
         .unwrap_or_default();
+    let power = CONVICTION.total_power_at_height(deps.storage, staked, height)?;
     Ok(TotalPowerAtHeightResponse { power, height })
 }
 
@@ -11,6 +11,9 @@ pub enum ContractError {
     #[error(transparent)]
     ActiveThresholdError(#[from] ActiveThresholdError),
 
+    #[error(transparent)]
+    ConvictionError(#[from] dao_voting::conviction::ConvictionError),
+
     #[error(transparent)]
     HookError(#[from] cw_hooks::HookError),
 
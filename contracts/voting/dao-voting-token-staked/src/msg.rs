@@ -3,7 +3,10 @@ use cosmwasm_std::{Binary, Uint128};
 use cw_utils::Duration;
 use dao_dao_macros::{active_query, native_token_query, voting_module_query};
 use dao_interface::token::NewTokenInfo;
-use dao_voting::threshold::{ActiveThreshold, ActiveThresholdResponse};
+use dao_voting::{
+    conviction::ConvictionConfig,
+    threshold::{ActiveThreshold, ActiveThresholdResponse},
+};
 
 #[cw_serde]
 pub enum TokenInfo {
@@ -55,6 +58,11 @@ pub enum ExecuteMsg {
     AddHook { addr: String },
     /// Removes a hook that fires on staking / unstaking
     RemoveHook { addr: String },
+    /// Enables or updates conviction, which weights voting power by
+    /// how long tokens have been staked. Once enabled, only the
+    /// maximum multiplier may be updated. Only the DAO may call this
+    /// method.
+    UpdateConviction { conviction: ConvictionConfig },
 }
 
 #[native_token_query]
@@ -78,6 +86,8 @@ pub enum QueryMsg {
     GetHooks {},
     #[returns(Option<cosmwasm_std::Addr>)]
     TokenContract {},
+    #[returns(Option<dao_voting::conviction::ConvictionConfig>)]
+    Conviction {},
 }
 
 #[cw_serde]
@@ -4,7 +4,7 @@ use cw_controllers::Claims;
 use cw_hooks::Hooks;
 use cw_storage_plus::{Item, SnapshotItem, SnapshotMap, Strategy};
 use cw_utils::Duration;
-use dao_voting::threshold::ActiveThreshold;
+use dao_voting::{conviction::Conviction, threshold::ActiveThreshold};
 
 use crate::msg::TokenInfo;
 
@@ -38,6 +38,9 @@ pub const STAKED_TOTAL: SnapshotItem<Uint128> = SnapshotItem::new(
     Strategy::EveryBlock,
 );
 
+/// Tracks stake age for conviction weighted voting power
+pub const CONVICTION: Conviction = Conviction::new();
+
 /// The maximum number of claims that may be outstanding.
 pub const MAX_CLAIMS: u64 = 100;
 
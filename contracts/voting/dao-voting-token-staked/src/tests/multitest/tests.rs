@@ -4,6 +4,7 @@ use crate::msg::{
     StakerBalanceResponse, TokenInfo,
 };
 use crate::state::Config;
+use crate::ContractError;
 use cosmwasm_std::testing::{mock_dependencies, mock_env};
 use cosmwasm_std::{coins, Addr, Coin, Decimal, Empty, Uint128};
 use cw_controllers::ClaimsResponse;
@@ -15,7 +16,10 @@ use dao_interface::voting::{
     DenomResponse, InfoResponse, IsActiveResponse, TotalPowerAtHeightResponse,
     VotingPowerAtHeightResponse,
 };
-use dao_voting::threshold::{ActiveThreshold, ActiveThresholdResponse};
+use dao_voting::{
+    conviction::{ConvictionConfig, ConvictionError},
+    threshold::{ActiveThreshold, ActiveThresholdResponse},
+};
 
 const DAO_ADDR: &str = "dao";
 const ADDR1: &str = "addr1";
@@ -1361,6 +1365,111 @@ fn test_staking_hooks() {
     assert_eq!("stake_hook", res.events.last().unwrap().attributes[1].value);
 }
 
+#[test]
+fn test_conviction() {
+    let mut app = mock_app();
+    let staking_id = app.store_code(staking_contract());
+    let addr = instantiate_staking(
+        &mut app,
+        staking_id,
+        InstantiateMsg {
+            token_info: TokenInfo::Existing {
+                denom: DENOM.to_string(),
+            },
+            unstaking_duration: Some(Duration::Height(5)),
+            active_threshold: None,
+        },
+    );
+
+    // Stake from before conviction is enabled is not weighted until
+    // its owner next unstakes.
+    stake_tokens(&mut app, addr.clone(), ADDR1, 100, DENOM).unwrap();
+    app.update_block(next_block);
+
+    let conviction = ConvictionConfig {
+        maturity_blocks: 10,
+        max_multiplier: Decimal::percent(300),
+    };
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ADDR1),
+            addr.clone(),
+            &ExecuteMsg::UpdateConviction { conviction },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        addr.clone(),
+        &ExecuteMsg::UpdateConviction { conviction },
+        &[],
+    )
+    .unwrap();
+    let config: Option<ConvictionConfig> = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::Conviction {})
+        .unwrap();
+    assert_eq!(config, Some(conviction));
+
+    // The maturity period may not be changed.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(DAO_ADDR),
+            addr.clone(),
+            &ExecuteMsg::UpdateConviction {
+                conviction: ConvictionConfig {
+                    maturity_blocks: 20,
+                    ..conviction
+                },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::ConvictionError(ConvictionError::MaturityImmutable {})
+    );
+
+    stake_tokens(&mut app, addr.clone(), ADDR2, 100, DENOM).unwrap();
+
+    app.update_block(|block| block.height += 5);
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR1.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(100));
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR2.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(200));
+    let resp = get_total_power_at_height(&mut app, addr.clone(), None);
+    assert_eq!(resp.power, Uint128::new(300));
+
+    // Power is capped once stake has matured.
+    app.update_block(|block| block.height += 5);
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR2.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(300));
+    let resp = get_total_power_at_height(&mut app, addr.clone(), None);
+    assert_eq!(resp.power, Uint128::new(400));
+
+    // Unstaking resets the age of the remaining stake.
+    let unstaked_at = app.block_info().height;
+    unstake_tokens(&mut app, addr.clone(), ADDR1, 50).unwrap();
+    app.update_block(|block| block.height += 5);
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR1.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(100));
+    let resp = get_total_power_at_height(&mut app, addr.clone(), None);
+    assert_eq!(resp.power, Uint128::new(400));
+
+    // Historical queries are unaffected.
+    let resp =
+        get_voting_power_at_height(&mut app, addr.clone(), ADDR1.to_string(), Some(unstaked_at));
+    assert_eq!(resp.power, Uint128::new(100));
+    let resp = get_total_power_at_height(&mut app, addr, Some(unstaked_at));
+    assert_eq!(resp.power, Uint128::new(400));
+}
+
 #[test]
 pub fn test_migrate_update_version() {
     let mut deps = mock_dependencies();
@@ -16,6 +16,7 @@ use dao_testing::test_tube::{cw_tokenfactory_issuer::TokenfactoryIssuer, dao_dao
 use dao_voting::{
     pre_propose::PreProposeInfo,
     threshold::{ActiveThreshold, ActiveThresholdError, PercentageThreshold, Threshold},
+    voting::VoteWeighting,
 };
 use osmosis_test_tube::{
     osmosis_std::types::cosmos::bank::v1beta1::QueryBalanceRequest, Account, OsmosisTestApp,
@@ -397,6 +398,9 @@ fn test_factory() {
                 close_proposal_on_execution_failure: false,
                 pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                 veto: None,
+                vote_weighting: VoteWeighting::Linear {},
+                objection_threshold: None,
+                review_period: None,
             })
             .unwrap(),
             admin: Some(Admin::CoreModule {}),
@@ -507,6 +511,9 @@ fn test_factory_funds_pass_through() {
                 close_proposal_on_execution_failure: false,
                 pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                 veto: None,
+                vote_weighting: VoteWeighting::Linear {},
+                objection_threshold: None,
+                review_period: None,
             })
             .unwrap(),
             admin: Some(Admin::CoreModule {}),
@@ -633,6 +640,9 @@ fn test_factory_no_callback() {
                 close_proposal_on_execution_failure: false,
                 pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                 veto: None,
+                vote_weighting: VoteWeighting::Linear {},
+                objection_threshold: None,
+                review_period: None,
             })
             .unwrap(),
             admin: Some(Admin::CoreModule {}),
@@ -714,6 +724,9 @@ fn test_factory_wrong_callback() {
                 close_proposal_on_execution_failure: false,
                 pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                 veto: None,
+                vote_weighting: VoteWeighting::Linear {},
+                objection_threshold: None,
+                review_period: None,
             })
             .unwrap(),
             admin: Some(Admin::CoreModule {}),
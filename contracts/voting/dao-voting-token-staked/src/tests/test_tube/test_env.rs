@@ -18,6 +18,7 @@ use dao_interface::{
 };
 use dao_voting::{
     pre_propose::PreProposeInfo, threshold::PercentageThreshold, threshold::Threshold,
+    voting::VoteWeighting,
 };
 
 use dao_testing::test_tube::{
@@ -248,6 +249,9 @@ impl TestEnvBuilder {
                     close_proposal_on_execution_failure: false,
                     pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                     veto: None,
+                    vote_weighting: VoteWeighting::Linear {},
+                    objection_threshold: None,
+                    review_period: None,
                 })
                 .unwrap(),
                 admin: Some(Admin::CoreModule {}),
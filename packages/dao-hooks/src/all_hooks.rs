@@ -1,6 +1,7 @@
 use cosmwasm_schema::cw_serde;
 use cw4::MemberChangedHookMsg;
 
+use crate::dao_core::CoreChangedHookMsg;
 use crate::nft_stake::NftStakeChangedHookMsg;
 use crate::proposal::{PreProposeHookMsg, ProposalHookMsg};
 use crate::stake::StakeChangedHookMsg;
@@ -9,6 +10,9 @@ use crate::vote::VoteHookMsg;
 /// An enum representing all possible DAO hooks.
 #[cw_serde]
 pub enum DaoHooks {
+    /// Called when a DAO's core module changes, for example when its
+    /// config is updated or it receives tokens.
+    CoreChangedHook(CoreChangedHookMsg),
     /// Called when a member is added or removed
     /// to a cw4-groups or cw721-roles contract.
     MemberChangedHook(MemberChangedHookMsg),
@@ -0,0 +1,61 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_json_binary, Addr, StdResult, Storage, SubMsg, Uint128, WasmMsg};
+use cw_hooks::Hooks;
+use cw_utils::Expiration;
+
+/// An enum representing changes to a DAO's core module.
+#[cw_serde]
+pub enum CoreChangedHookMsg {
+    /// The DAO's config was updated.
+    ConfigUpdated {},
+    /// The DAO's voting module was replaced.
+    VotingModuleUpdated {},
+    /// Proposal modules were added or disabled.
+    ProposalModulesUpdated {},
+    /// The DAO's admin changed.
+    AdminChanged { admin: Addr },
+    /// The DAO, or part of it, was paused until `expiration`.
+    Paused {
+        scope: String,
+        expiration: Expiration,
+    },
+    /// A pause was lifted.
+    Unpaused { scope: String },
+    /// The DAO received cw20 tokens.
+    Cw20Received {
+        token: Addr,
+        sender: String,
+        amount: Uint128,
+    },
+    /// The DAO received a cw721 NFT.
+    Cw721Received {
+        token: Addr,
+        sender: String,
+        token_id: String,
+    },
+}
+
+/// Prepares CoreChangedHookMsg hook SubMsgs. These messages reply on
+/// error with `reply_id`, so that a failing hook does not revert the
+/// change it reports.
+pub fn core_changed_hook_msgs(
+    hooks: Hooks,
+    storage: &dyn Storage,
+    msg: CoreChangedHookMsg,
+    reply_id: u64,
+) -> StdResult<Vec<SubMsg>> {
+    let msg = to_json_binary(&CoreChangedExecuteMsg::CoreChangedHook(msg))?;
+    hooks.prepare_hooks(storage, |a| {
+        let execute = WasmMsg::Execute {
+            contract_addr: a.to_string(),
+            msg: msg.clone(),
+            funds: vec![],
+        };
+        Ok(SubMsg::reply_on_error(execute, reply_id))
+    })
+}
+
+#[cw_serde]
+pub enum CoreChangedExecuteMsg {
+    CoreChangedHook(CoreChangedHookMsg),
+}
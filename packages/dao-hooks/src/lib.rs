@@ -1,6 +1,7 @@
 #![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
 
 mod all_hooks;
+pub mod dao_core;
 pub mod nft_stake;
 pub mod proposal;
 pub mod stake;
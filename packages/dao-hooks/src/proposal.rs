@@ -3,13 +3,19 @@ use cosmwasm_std::{to_json_binary, Empty, StdResult, Storage, SubMsg, WasmMsg};
 use cw_hooks::Hooks;
 use dao_voting::{
     pre_propose::ProposalCreationPolicy,
-    reply::{failed_pre_propose_module_hook_id, mask_proposal_hook_index},
+    reply::{
+        failed_pre_propose_module_hook_id, mask_amendment_hook_index, mask_proposal_hook_index,
+    },
     status::Status,
 };
 
 /// An enum representing proposal hook messages.
 /// Either a new propsoal hook, fired when a new proposal is created,
-/// or a proposal status hook, fired when a proposal changes status.
+/// a proposal status hook, fired when a proposal changes status, or a
+/// proposal amended hook, fired when a proposer amends a proposal.
+/// Proposal amended hooks are only sent to consumers which opt in to
+/// them, so that existing consumers are not sent a message they can
+/// not parse.
 #[cw_serde]
 pub enum ProposalHookMsg {
     NewProposal {
@@ -21,6 +27,10 @@ pub enum ProposalHookMsg {
         old_status: String,
         new_status: String,
     },
+    ProposalAmended {
+        id: u64,
+        revision: u64,
+    },
 }
 
 /// Prepares new proposal hook messages. These messages reply on error
@@ -92,6 +102,35 @@ pub fn proposal_status_changed_hooks(
     Ok(messages)
 }
 
+/// Prepares proposal amended hook messages for the consumers of
+/// amendment hooks. These messages reply on error and have their own
+/// reply IDs, so that failing consumers are removed from the
+/// amendment hooks.
+pub fn proposal_amended_hooks(
+    hooks: Hooks,
+    storage: &dyn Storage,
+    id: u64,
+    revision: u64,
+) -> StdResult<Vec<SubMsg>> {
+    let msg = to_json_binary(&ProposalHookExecuteMsg::ProposalHook(
+        ProposalHookMsg::ProposalAmended { id, revision },
+    ))?;
+    let mut index: u64 = 0;
+    let messages = hooks.prepare_hooks(storage, |a| {
+        let execute = WasmMsg::Execute {
+            contract_addr: a.to_string(),
+            msg: msg.clone(),
+            funds: vec![],
+        };
+        let masked_index = mask_amendment_hook_index(index);
+        let tmp = SubMsg::reply_on_error(execute, masked_index);
+        index += 1;
+        Ok(tmp)
+    })?;
+
+    Ok(messages)
+}
+
 /// Message type used for firing hooks to a proposal module's pre-propose
 /// module, if one is installed.
 pub type PreProposeHookMsg = dao_pre_propose_base::msg::ExecuteMsg<Empty, Empty>;
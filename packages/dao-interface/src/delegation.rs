@@ -0,0 +1,36 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Addr;
+
+/// Queries implemented by vote delegation registries. Proposal
+/// modules use these to find the voting power delegated to a voter.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum Query {
+    /// Returns the address `delegator` had delegated their voting
+    /// power to at `height`, or at the current height if `height` is
+    /// `None`.
+    #[returns(DelegationResponse)]
+    Delegation {
+        delegator: String,
+        height: Option<u64>,
+    },
+    /// Returns the addresses which had delegated their voting power
+    /// to `delegate` at `height`, or at the current height if
+    /// `height` is `None`.
+    #[returns(DelegatorsResponse)]
+    Delegators {
+        delegate: String,
+        height: Option<u64>,
+    },
+}
+
+#[cw_serde]
+pub struct DelegationResponse {
+    /// The delegate, if the delegator had delegated.
+    pub delegate: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct DelegatorsResponse {
+    pub delegators: Vec<Addr>,
+}
@@ -1,9 +1,14 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{CosmosMsg, Empty};
-use cw_utils::Duration;
+use cw_denom::UncheckedDenom;
+use cw_utils::{Duration, Expiration};
 
 use crate::state::Config;
-use crate::{migrate_msg::MigrateParams, query::SubDao, state::ModuleInstantiateInfo};
+use crate::{
+    migrate_msg::MigrateParams,
+    query::SubDao,
+    state::{ExecutionPolicy, ItemValueType, ModuleInstantiateInfo, PauseScope, SpendLimit},
+};
 
 /// Information about an item to be stored in the items list.
 #[cw_serde]
@@ -60,9 +65,33 @@ pub enum ExecuteMsg {
     /// Callable by proposal modules. The DAO will execute the
     /// messages in the hook in order.
     ExecuteProposalHook { msgs: Vec<CosmosMsg<Empty>> },
-    /// Pauses the DAO for a set duration.
-    /// When paused the DAO is unable to execute proposals
+    /// Callable by the core contract and the pauser. Pauses the DAO
+    /// for a set duration. When paused the DAO is unable to execute
+    /// proposals. The pauser may not pause for longer than its
+    /// maximum pause duration, nor within its maximum pause duration
+    /// of the end of its previous pause.
     Pause { duration: Duration },
+    /// Callable by the core contract and the pauser. Pauses the
+    /// actions in `scope` for a set duration. The pauser is limited
+    /// as for `Pause`, and may not replace a pause imposed by the
+    /// core contract.
+    PauseScoped {
+        scope: PauseScope,
+        duration: Duration,
+    },
+    /// Callable by the core contract, the admin and the pauser. Lifts
+    /// the pause of `scope` before it expires. The pauser may only
+    /// lift its own pauses. May be executed while the DAO is paused.
+    Unpause { scope: PauseScope },
+    /// Callable by the core contract. Sets the pauser, an address
+    /// which may pause and unpause the DAO but may not execute
+    /// messages. Pauses by the pauser may last at most
+    /// `max_pause_duration`, which is required when setting a
+    /// pauser. A pauser of `None` removes the pauser.
+    UpdatePauser {
+        pauser: Option<String>,
+        max_pause_duration: Option<Duration>,
+    },
     /// Executed when the contract receives a cw20 token. Depending on
     /// the contract's configuration the contract will automatically
     /// add the token to its treasury.
@@ -77,6 +106,29 @@ pub enum ExecuteMsg {
     /// item already exists the existing value is overridden. If the
     /// item does not exist a new item is added.
     SetItem { key: String, value: String },
+    /// Callable by the core contract. Creates the item namespace
+    /// `namespace`, or replaces its writers and value type if it
+    /// exists. Changing the value type does not check the values
+    /// already in the namespace.
+    UpdateItemNamespace {
+        namespace: String,
+        writers: Vec<String>,
+        value_type: Option<ItemValueType>,
+    },
+    /// Callable by the core contract. Removes an item namespace,
+    /// which must not contain any items.
+    RemoveItemNamespace { namespace: String },
+    /// Callable by the core contract and the namespace's writers.
+    /// Sets an item in a namespace, overriding any existing value.
+    /// The value must be of the namespace's value type.
+    SetNamespacedItem {
+        namespace: String,
+        key: String,
+        value: String,
+    },
+    /// Callable by the core contract and the namespace's writers.
+    /// Removes an item from a namespace.
+    RemoveNamespacedItem { namespace: String, key: String },
     /// Callable by the admin of the contract. If ADMIN is None the
     /// admin is set as the contract itself so that it may be updated
     /// later by vote. If ADMIN is Some a new admin is proposed and
@@ -111,6 +163,41 @@ pub enum ExecuteMsg {
         to_add: Vec<String>,
         to_remove: Vec<String>,
     },
+    /// Updates the list of native denoms this contract has
+    /// registered.
+    UpdateNativeList {
+        to_add: Vec<String>,
+        to_remove: Vec<String>,
+    },
+    /// Callable by the core contract. Schedules `msgs` to be executed
+    /// `count` times, first at `start` and then every `interval`.
+    /// `interval` must be set if `count` is greater than one.
+    ScheduleMsgs {
+        msgs: Vec<CosmosMsg<Empty>>,
+        start: Expiration,
+        interval: Option<Duration>,
+        count: u64,
+    },
+    /// Callable by the core contract. Cancels a schedule, removing
+    /// its remaining executions.
+    CancelSchedule { id: u64 },
+    /// Callable by anyone. Executes the messages of every schedule
+    /// which is due. A schedule is executed at most once per crank.
+    /// A schedule whose messages fail remains due, without affecting
+    /// the others.
+    Crank {},
+    /// Callable by the core contract. Executes the messages of a due
+    /// schedule and advances it to its next execution. Sent by
+    /// `Crank` so that each schedule's messages and its advancement
+    /// succeed or fail together.
+    ExecuteSchedule { id: u64 },
+    /// Callable by the core contract. Sets the limits on the amount
+    /// of each token the DAO may send out of its treasury per period,
+    /// and removes the limits of the tokens in `to_remove`.
+    UpdateSpendLimits {
+        to_set: Vec<SpendLimit>,
+        to_remove: Vec<UncheckedDenom>,
+    },
     /// Updates the governance contract's governance modules. Module
     /// instantiate info in `to_add` is used to create new modules and
     /// install them.
@@ -120,15 +207,47 @@ pub enum ExecuteMsg {
         to_add: Vec<ModuleInstantiateInfo>,
         to_disable: Vec<String>,
     },
+    /// Callable by the core contract. Instantiates a new proposal
+    /// module and installs it with `execution_policy`, so that the
+    /// module is never able to execute messages outside of its
+    /// policy.
+    AddRestrictedProposalModule {
+        module: ModuleInstantiateInfo,
+        execution_policy: ExecutionPolicy,
+    },
+    /// Callable by the core contract. Sets the execution policy of an
+    /// installed proposal module. A policy of `None` removes all
+    /// restrictions from the module.
+    UpdateProposalModuleExecutionPolicy {
+        address: String,
+        execution_policy: Option<ExecutionPolicy>,
+    },
     /// Callable by the core contract. Replaces the current
     /// voting module with a new one instantiated by the governance
-    /// contract.
+    /// contract. Voting power queries for heights up to and including
+    /// the height of the replacement continue to be answered by the
+    /// old module, so proposals which are open are unaffected.
     UpdateVotingModule { module: ModuleInstantiateInfo },
-    /// Update the core module to add/remove SubDAOs and their charters
+    /// Update the core module to add/remove SubDAOs and their
+    /// charters. Each SubDAO being added must report this contract as
+    /// its admin.
     UpdateSubDaos {
         to_add: Vec<SubDao>,
         to_remove: Vec<String>,
     },
+    /// Callable by the core contract. Adds a contract which is sent
+    /// a `CoreChangedHook` message when the DAO's config, modules,
+    /// admin or pauses change, or when it receives tokens.
+    AddCoreHook { address: String },
+    /// Callable by the core contract. Removes a core hook.
+    RemoveCoreHook { address: String },
+    /// Callable by the core contract. Executes messages as a
+    /// registered SubDAO by wrapping them in an `ExecuteAdminMsgs`
+    /// message sent to it.
+    ExecuteOnSubDao {
+        subdao: String,
+        msgs: Vec<CosmosMsg<Empty>>,
+    },
 }
 
 #[cw_serde]
@@ -157,6 +276,25 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    /// Gets the balance of each native denom registered with the
+    /// contract.
+    #[returns(Vec<cosmwasm_std::Coin>)]
+    NativeBalances {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Gets the balances of the cw20 tokens and native denoms
+    /// registered with the contract, as they were at the start of
+    /// block `height`. Only balances which changed through the
+    /// contract's own entry points are recorded.
+    #[returns(crate::query::TreasurySnapshotResponse)]
+    TreasurySnapshot { height: u64 },
+    /// Lists the native denoms in this contract's treasury.
+    #[returns(Vec<String>)]
+    NativeTokenList {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     /// Lists the addresses of the cw721 tokens in this contract's
     /// treasury.
     #[returns(Vec<cosmwasm_std::Addr>)]
@@ -181,6 +319,24 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    /// Gets the value of an item in a namespace.
+    #[returns(crate::query::GetItemResponse)]
+    GetNamespacedItem { namespace: String, key: String },
+    /// Lists the item namespaces along with their writers and value
+    /// types.
+    #[returns(Vec<crate::state::ItemNamespace>)]
+    ListItemNamespaces {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Lists the keys and values of the items in `namespace`, or of
+    /// the items outside of any namespace if `namespace` is `None`.
+    #[returns(Vec<(String, String)>)]
+    ListItemsWithValues {
+        namespace: Option<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     /// Returns contract version info
     #[returns(crate::voting::InfoResponse)]
     Info {},
@@ -205,9 +361,29 @@ pub enum QueryMsg {
     /// Returns information about if the contract is currently paused.
     #[returns(crate::query::PauseInfoResponse)]
     PauseInfo {},
+    /// Lists the pauses which are currently in effect, including a
+    /// pause of the whole DAO.
+    #[returns(Vec<crate::query::PauseResponse>)]
+    ListPauses {},
+    /// Lists the DAO's schedules, ordered by ID.
+    #[returns(Vec<crate::state::Schedule>)]
+    ListSchedules {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Gets the address which may pause the DAO, if any.
+    #[returns(Option<cosmwasm_std::Addr>)]
+    Pauser {},
+    /// Gets the longest duration the pauser may pause for, if the DAO
+    /// has a pauser.
+    #[returns(Option<::cw_utils::Duration>)]
+    PauserMaxPauseDuration {},
     /// Gets the contract's voting module.
     #[returns(cosmwasm_std::Addr)]
     VotingModule {},
+    /// Lists the contracts sent core hooks.
+    #[returns(::cw_hooks::HooksResponse)]
+    CoreHooks {},
     /// Returns all SubDAOs with their charters in a vec.
     /// start_after is bound exclusive and asks for a string address.
     #[returns(Vec<crate::query::SubDao>)]
@@ -215,6 +391,15 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    /// Returns all SubDAOs along with their own SubDAOs, walking
+    /// nested SubDAOs up to `depth` levels deep. `depth` defaults to,
+    /// and is capped at, the core contract's `MAX_SUBDAO_TREE_DEPTH`.
+    #[returns(Vec<crate::query::SubDaoTreeNode>)]
+    ListSubDaoTree { depth: Option<u32> },
+    /// Lists the DAO's treasury spend limits, along with the amount
+    /// sent out during their current periods.
+    #[returns(Vec<crate::query::SpendLimitResponse>)]
+    SpendLimits {},
     /// Implements the DAO Star standard: <https://daostar.one/EIP>
     #[returns(crate::query::DaoURIResponse)]
     DaoURI {},
@@ -1,9 +1,10 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Coin, Uint128};
 use cw2::ContractVersion;
-use cw_utils::Expiration;
+use cw_denom::CheckedDenom;
+use cw_utils::{Duration, Expiration};
 
-use crate::state::{Config, ProposalModule};
+use crate::state::{Config, PauseScope, ProposalModule};
 
 /// Relevant state for the governance module. Returned by the
 /// `DumpState` query.
@@ -26,6 +27,21 @@ pub struct DumpStateResponse {
     pub active_proposal_module_count: u32,
     /// The total number of proposal modules.
     pub total_proposal_module_count: u32,
+    /// The balances of the native denoms registered with the
+    /// governance contract.
+    #[serde(default)]
+    pub native_balances: Vec<Coin>,
+}
+
+/// Returned by the `TreasurySnapshot` query.
+#[cw_serde]
+pub struct TreasurySnapshotResponse {
+    /// The height of the snapshot.
+    pub height: u64,
+    /// The balances of the cw20 tokens registered at `height`.
+    pub cw20_balances: Vec<Cw20BalanceResponse>,
+    /// The balances of the native denoms registered at `height`.
+    pub native_balances: Vec<Coin>,
 }
 
 /// Information about if the contract is currently paused.
@@ -35,6 +51,13 @@ pub enum PauseInfoResponse {
     Unpaused {},
 }
 
+/// A pause which is in effect. Returned by the `ListPauses` query.
+#[cw_serde]
+pub struct PauseResponse {
+    pub scope: PauseScope,
+    pub expiration: Expiration,
+}
+
 /// Returned by the `GetItem` query.
 #[cw_serde]
 pub struct GetItemResponse {
@@ -68,6 +91,18 @@ pub struct SubDao {
     pub charter: Option<String>,
 }
 
+/// A SubDAO along with the SubDAOs nested below it.
+#[cw_serde]
+pub struct SubDaoTreeNode {
+    /// The contract address of the SubDAO
+    pub addr: String,
+    /// The purpose/constitution for the SubDAO
+    pub charter: Option<String>,
+    /// The SubDAOs registered with this SubDAO. Empty once the depth
+    /// limit is reached, or if the SubDAO does not list SubDAOs.
+    pub children: Vec<SubDaoTreeNode>,
+}
+
 #[cw_serde]
 pub struct DaoURIResponse {
     pub dao_uri: Option<String>,
@@ -79,4 +114,24 @@ pub struct ProposalModuleCountResponse {
     pub active_proposal_module_count: u32,
     /// The total number of proposal modules.
     pub total_proposal_module_count: u32,
+    /// The balances of the native denoms registered with the
+    /// governance contract.
+    #[serde(default)]
+    pub native_balances: Vec<Coin>,
+}
+
+/// Returned by the `SpendLimits` query.
+#[cw_serde]
+pub struct SpendLimitResponse {
+    /// The native denom or cw20 token being limited.
+    pub denom: CheckedDenom,
+    /// The maximum amount that may be sent out in a single period.
+    pub limit: Uint128,
+    /// The length of a period.
+    pub period: Duration,
+    /// The amount sent out during the current period.
+    pub spent: Uint128,
+    /// When the current period ends. `None` if no period is in
+    /// progress.
+    pub period_expiration: Option<Expiration>,
 }
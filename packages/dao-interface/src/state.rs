@@ -1,5 +1,9 @@
+use std::{fmt, str::FromStr};
+
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Binary, Coin, CosmosMsg, WasmMsg};
+use cosmwasm_std::{Addr, Api, Binary, Coin, CosmosMsg, Decimal, Int128, Uint128, WasmMsg};
+use cw_denom::UncheckedDenom;
+use cw_utils::{Duration, Expiration};
 
 /// Top level config type for core module.
 #[cw_serde]
@@ -31,6 +35,175 @@ pub struct ProposalModule {
     pub prefix: String,
     /// The status of the proposal module, e.g. 'Enabled' or 'Disabled.'
     pub status: ProposalModuleStatus,
+    /// Restrictions on the messages this proposal module may execute
+    /// through the core module. If `None`, the module may execute any
+    /// message.
+    #[serde(default)]
+    pub execution_policy: Option<ExecutionPolicy>,
+}
+
+/// Restrictions on the messages a proposal module may execute. Each
+/// restriction which is set applies to every message executed by the
+/// module, and restrictions which are not set are not enforced.
+#[cw_serde]
+#[derive(Default)]
+pub struct ExecutionPolicy {
+    /// The types of messages the module may execute.
+    pub allowed_msg_types: Option<Vec<MsgType>>,
+    /// The contracts the module may execute, migrate or change the
+    /// admin of. Contracts may still be instantiated, subject to
+    /// `allowed_msg_types`. The module may only message the core
+    /// module if the core module is listed here. As stargate and
+    /// custom messages may execute contracts without naming them in
+    /// a way that can be checked, they are only allowed if listed in
+    /// `allowed_msg_types`.
+    pub allowed_contracts: Option<Vec<String>>,
+    /// The maximum amount of each denom the module may spend in a
+    /// single execution, summed over bank sends and burns, funds
+    /// attached to wasm messages, IBC transfers and cw20 transfers,
+    /// sends, burns and allowances. cw20 tokens are listed under the
+    /// address of their contract. Denoms which are not listed may not
+    /// be spent. Messages whose spending can not be measured, such as
+    /// stargate messages, may not be executed while spend limits are
+    /// set.
+    pub spend_limits: Option<Vec<Coin>>,
+}
+
+/// The type of a `CosmosMsg`, as named by its top level variant.
+#[cw_serde]
+pub enum MsgType {
+    Bank,
+    Custom,
+    Staking,
+    Distribution,
+    Stargate,
+    Ibc,
+    Wasm,
+    Gov,
+}
+
+impl MsgType {
+    /// Returns the type of `msg`, or `None` if it is of a type this
+    /// version does not know of.
+    pub fn of<T>(msg: &CosmosMsg<T>) -> Option<Self> {
+        match msg {
+            CosmosMsg::Bank(_) => Some(MsgType::Bank),
+            CosmosMsg::Custom(_) => Some(MsgType::Custom),
+            CosmosMsg::Staking(_) => Some(MsgType::Staking),
+            CosmosMsg::Distribution(_) => Some(MsgType::Distribution),
+            CosmosMsg::Stargate { .. } => Some(MsgType::Stargate),
+            CosmosMsg::Ibc(_) => Some(MsgType::Ibc),
+            CosmosMsg::Wasm(_) => Some(MsgType::Wasm),
+            CosmosMsg::Gov(_) => Some(MsgType::Gov),
+            _ => None,
+        }
+    }
+
+    /// Returns true if messages of this type may execute arbitrary
+    /// chain messages whose targets can not be inspected.
+    pub fn is_opaque(&self) -> bool {
+        matches!(self, MsgType::Custom | MsgType::Stargate)
+    }
+}
+
+/// A limit on the amount of a token the DAO may send out of its
+/// treasury in each period.
+#[cw_serde]
+pub struct SpendLimit {
+    /// The native denom or cw20 token being limited.
+    pub denom: UncheckedDenom,
+    /// The maximum amount that may be sent out in a single period.
+    pub limit: Uint128,
+    /// The length of a period. Periods are fixed windows: a new
+    /// period starts with the first outflow after the previous period
+    /// has ended, resetting the amount spent.
+    pub period: Duration,
+}
+
+/// A namespace of items, along with the addresses which may write to
+/// it in addition to the core module.
+#[cw_serde]
+pub struct ItemNamespace {
+    /// The name of the namespace.
+    pub namespace: String,
+    /// Addresses which may set and remove items in the namespace.
+    pub writers: Vec<Addr>,
+    /// The type of the namespace's values. `None` if values may be
+    /// any string.
+    pub value_type: Option<ItemValueType>,
+}
+
+/// The type of the values stored in an item namespace.
+#[cw_serde]
+#[derive(Copy)]
+pub enum ItemValueType {
+    /// Any string.
+    String,
+    /// `true` or `false`.
+    Bool,
+    /// An unsigned integer which fits in a `Uint128`.
+    Uint,
+    /// A signed integer which fits in an `Int128`.
+    Int,
+    /// A decimal number which fits in a `Decimal`.
+    Decimal,
+    /// A valid address.
+    Addr,
+}
+
+impl ItemValueType {
+    /// Returns true if `value` is a valid value of this type.
+    pub fn accepts(&self, api: &dyn Api, value: &str) -> bool {
+        match self {
+            ItemValueType::String => true,
+            ItemValueType::Bool => value == "true" || value == "false",
+            ItemValueType::Uint => Uint128::from_str(value).is_ok(),
+            ItemValueType::Int => Int128::from_str(value).is_ok(),
+            ItemValueType::Decimal => Decimal::from_str(value).is_ok(),
+            ItemValueType::Addr => api.addr_validate(value).is_ok(),
+        }
+    }
+}
+
+impl fmt::Display for ItemValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ItemValueType::String => write!(f, "string"),
+            ItemValueType::Bool => write!(f, "bool"),
+            ItemValueType::Uint => write!(f, "uint"),
+            ItemValueType::Int => write!(f, "int"),
+            ItemValueType::Decimal => write!(f, "decimal"),
+            ItemValueType::Addr => write!(f, "addr"),
+        }
+    }
+}
+
+/// A batch of messages scheduled for execution by the core module.
+#[cw_serde]
+pub struct Schedule {
+    /// The ID of the schedule.
+    pub id: u64,
+    /// The messages executed each time the schedule is due.
+    pub msgs: Vec<CosmosMsg>,
+    /// When the messages are next due.
+    pub next: Expiration,
+    /// The time between executions. `None` if the schedule executes
+    /// only once.
+    pub interval: Option<Duration>,
+    /// The number of executions left, including the next one.
+    pub remaining: u64,
+}
+
+/// The actions affected by a pause.
+#[cw_serde]
+pub enum PauseScope {
+    /// All actions on the core module.
+    All {},
+    /// Execution of proposal hooks from the proposal module at
+    /// `address`.
+    ProposalModule { address: String },
+    /// Messages which send funds out of the DAO's treasury.
+    Treasury {},
 }
 
 /// The status of a proposal module.
@@ -91,7 +264,9 @@ pub struct ModuleInstantiateCallback {
 mod tests {
     use super::*;
 
-    use cosmwasm_std::{to_json_binary, Addr, WasmMsg};
+    use cosmwasm_std::{
+        coins, testing::MockApi, to_json_binary, Addr, BankMsg, Empty, GovMsg, VoteOption, WasmMsg,
+    };
 
     #[test]
     fn test_module_instantiate_admin_none() {
@@ -157,4 +332,50 @@ mod tests {
             }
         )
     }
+
+    #[test]
+    fn test_msg_type() {
+        let bank: CosmosMsg<Empty> = BankMsg::Send {
+            to_address: "ekez".to_string(),
+            amount: coins(1, "ujuno"),
+        }
+        .into();
+        assert_eq!(MsgType::of(&bank), Some(MsgType::Bank));
+
+        let wasm: CosmosMsg<Empty> = WasmMsg::ClearAdmin {
+            contract_addr: "core".to_string(),
+        }
+        .into();
+        assert_eq!(MsgType::of(&wasm), Some(MsgType::Wasm));
+
+        let gov: CosmosMsg<Empty> = GovMsg::Vote {
+            proposal_id: 1,
+            vote: VoteOption::Yes,
+        }
+        .into();
+        assert_eq!(MsgType::of(&gov), Some(MsgType::Gov));
+
+        let stargate: CosmosMsg<Empty> = CosmosMsg::Stargate {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: Binary::default(),
+        };
+        assert_eq!(MsgType::of(&stargate), Some(MsgType::Stargate));
+    }
+
+    #[test]
+    fn test_item_value_type() {
+        let api = MockApi::default();
+
+        assert!(ItemValueType::String.accepts(&api, "anything"));
+        assert!(ItemValueType::Bool.accepts(&api, "true"));
+        assert!(!ItemValueType::Bool.accepts(&api, "yes"));
+        assert!(ItemValueType::Uint.accepts(&api, "10"));
+        assert!(!ItemValueType::Uint.accepts(&api, "-10"));
+        assert!(ItemValueType::Int.accepts(&api, "-10"));
+        assert!(!ItemValueType::Int.accepts(&api, "1.5"));
+        assert!(ItemValueType::Decimal.accepts(&api, "1.5"));
+        assert!(!ItemValueType::Decimal.accepts(&api, "one"));
+        assert!(ItemValueType::Addr.accepts(&api, "ekez"));
+        assert!(!ItemValueType::Addr.accepts(&api, "EKEZ"));
+    }
 }
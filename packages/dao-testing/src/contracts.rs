@@ -206,3 +206,12 @@ pub fn dao_test_custom_factory() -> Box<dyn Contract<Empty>> {
     .with_reply(dao_test_custom_factory::contract::reply);
     Box::new(contract)
 }
+
+pub fn dao_vote_delegation_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_vote_delegation::contract::execute,
+        dao_vote_delegation::contract::instantiate,
+        dao_vote_delegation::contract::query,
+    );
+    Box::new(contract)
+}
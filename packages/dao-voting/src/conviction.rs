@@ -0,0 +1,549 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Order, StdError, StdResult, Storage, Uint128, Uint256};
+use cw_storage_plus::{Bound, Map, SnapshotItem, SnapshotMap, Strategy};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ConvictionError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Stake must take at least one block to mature.")]
+    ZeroMaturity {},
+
+    #[error("The maximum conviction multiplier may not be less than one.")]
+    MultiplierBelowOne {},
+
+    #[error("The number of blocks stake takes to mature may not be changed once set.")]
+    MaturityImmutable {},
+}
+
+/// Weights staked voting power by how long it has been staked. The
+/// multiplier applied to stake grows linearly from one when it is
+/// staked to `max_multiplier` once it has been staked for
+/// `maturity_blocks` blocks.
+#[cw_serde]
+#[derive(Copy)]
+pub struct ConvictionConfig {
+    /// The number of blocks stake must remain staked for to reach
+    /// the maximum multiplier.
+    pub maturity_blocks: u64,
+    /// The multiplier applied to mature stake.
+    pub max_multiplier: Decimal,
+}
+
+impl ConvictionConfig {
+    pub fn validate(&self) -> Result<(), ConvictionError> {
+        if self.maturity_blocks == 0 {
+            return Err(ConvictionError::ZeroMaturity {});
+        }
+        if self.max_multiplier < Decimal::one() {
+            return Err(ConvictionError::MultiplierBelowOne {});
+        }
+        Ok(())
+    }
+
+    /// The multiplier applied to stake which has been staked for
+    /// `age` blocks.
+    pub fn multiplier(&self, age: u64) -> Decimal {
+        let progress = Decimal::from_ratio(age.min(self.maturity_blocks), self.maturity_blocks);
+        Decimal::one() + (self.max_multiplier - Decimal::one()) * progress
+    }
+}
+
+/// Stake tracked for conviction. `start` is the height the stake is
+/// considered to have been staked at.
+#[cw_serde]
+pub struct StakeAge {
+    pub amount: Uint128,
+    pub start: u64,
+}
+
+/// Totals of the tracked stake, as of `height`.
+#[cw_serde]
+#[derive(Default)]
+struct ConvictionTotals {
+    /// Stake which has been staked for at least the maturity period.
+    mature: Uint128,
+    /// Stake which has not yet matured.
+    immature: Uint128,
+    /// The sum of the immature stake's amounts multiplied by their
+    /// starts. Used to compute the sum of their ages.
+    weighted_starts: Uint256,
+    height: u64,
+}
+
+/// Immature stake which matures at a given height.
+#[cw_serde]
+#[derive(Default)]
+struct Maturing {
+    amount: Uint128,
+    weighted_starts: Uint256,
+}
+
+/// Tracks the age of stake with snapshots so that conviction
+/// weighted voting power may be queried at historical heights.
+///
+/// Stake is only tracked once a config has been set, and stake which
+/// is not tracked is counted with a multiplier of one. Adding stake
+/// averages its start with the existing stake's start, weighted by
+/// amount. Unstaking resets the start of the remaining stake.
+pub struct Conviction<'a> {
+    config: SnapshotItem<'a, ConvictionConfig>,
+    stakes: SnapshotMap<'a, &'a Addr, StakeAge>,
+    totals: SnapshotItem<'a, ConvictionTotals>,
+    /// Immature stake by the height it matures at. Only modified
+    /// before that height, so safe to read at historical heights.
+    maturing: Map<'a, u64, Maturing>,
+}
+
+impl Conviction<'_> {
+    pub const fn new() -> Self {
+        Self {
+            config: SnapshotItem::new(
+                "conviction_config",
+                "conviction_config__checkpoints",
+                "conviction_config__changelog",
+                Strategy::EveryBlock,
+            ),
+            stakes: SnapshotMap::new(
+                "conviction_stakes",
+                "conviction_stakes__checkpoints",
+                "conviction_stakes__changelog",
+                Strategy::EveryBlock,
+            ),
+            totals: SnapshotItem::new(
+                "conviction_totals",
+                "conviction_totals__checkpoints",
+                "conviction_totals__changelog",
+                Strategy::EveryBlock,
+            ),
+            maturing: Map::new("conviction_maturing"),
+        }
+    }
+
+    pub fn config(&self, storage: &dyn Storage) -> StdResult<Option<ConvictionConfig>> {
+        self.config.may_load(storage)
+    }
+
+    /// Sets the conviction config, enabling stake tracking. Once set,
+    /// only the maximum multiplier may be updated.
+    pub fn update_config(
+        &self,
+        storage: &mut dyn Storage,
+        config: ConvictionConfig,
+        height: u64,
+    ) -> Result<(), ConvictionError> {
+        config.validate()?;
+        if let Some(current) = self.config.may_load(storage)? {
+            if current.maturity_blocks != config.maturity_blocks {
+                return Err(ConvictionError::MaturityImmutable {});
+            }
+        }
+        self.config.save(storage, &config, height)?;
+        Ok(())
+    }
+
+    pub fn stake_age(&self, storage: &dyn Storage, addr: &Addr) -> StdResult<Option<StakeAge>> {
+        self.stakes.may_load(storage, addr)
+    }
+
+    /// Records `amount` being staked by `addr`.
+    pub fn stake(
+        &self,
+        storage: &mut dyn Storage,
+        addr: &Addr,
+        amount: Uint128,
+        height: u64,
+    ) -> StdResult<()> {
+        let Some(config) = self.config.may_load(storage)? else {
+            return Ok(());
+        };
+        let mut totals = self.totals_at(storage, self.totals.may_load(storage)?, height)?;
+        let stake = match self.stakes.may_load(storage, addr)? {
+            Some(stake) => {
+                self.untrack(storage, &config, &mut totals, &stake, height)?;
+                let total = stake.amount.checked_add(amount)?;
+                let weighted = Uint256::from(stake.amount) * Uint256::from(stake.start)
+                    + Uint256::from(amount) * Uint256::from(height);
+                // Round up so that adding stake never ages it.
+                let start = weighted
+                    .checked_add(Uint256::from(total))?
+                    .checked_sub(Uint256::one())?
+                    / Uint256::from(total);
+                StakeAge {
+                    amount: total,
+                    start: Uint128::try_from(start)?.u128() as u64,
+                }
+            }
+            None => StakeAge {
+                amount,
+                start: height,
+            },
+        };
+        if !stake.amount.is_zero() {
+            self.track(storage, &config, &mut totals, &stake, height)?;
+            self.stakes.save(storage, addr, &stake, height)?;
+        }
+        self.totals.save(storage, &totals, height)
+    }
+
+    /// Records `addr` unstaking, leaving `remaining` staked. The
+    /// remaining stake is considered to have been staked at `height`.
+    pub fn unstake(
+        &self,
+        storage: &mut dyn Storage,
+        addr: &Addr,
+        remaining: Uint128,
+        height: u64,
+    ) -> StdResult<()> {
+        let Some(config) = self.config.may_load(storage)? else {
+            return Ok(());
+        };
+        let mut totals = self.totals_at(storage, self.totals.may_load(storage)?, height)?;
+        if let Some(stake) = self.stakes.may_load(storage, addr)? {
+            self.untrack(storage, &config, &mut totals, &stake, height)?;
+        }
+        if remaining.is_zero() {
+            self.stakes.remove(storage, addr, height)?;
+        } else {
+            let stake = StakeAge {
+                amount: remaining,
+                start: height,
+            };
+            self.track(storage, &config, &mut totals, &stake, height)?;
+            self.stakes.save(storage, addr, &stake, height)?;
+        }
+        self.totals.save(storage, &totals, height)
+    }
+
+    /// Returns the conviction weighted voting power of `addr` at
+    /// `height`, given they had `staked` staked at that height.
+    pub fn power_at_height(
+        &self,
+        storage: &dyn Storage,
+        addr: &Addr,
+        staked: Uint128,
+        height: u64,
+    ) -> StdResult<Uint128> {
+        let Some(config) = self.config.may_load_at_height(storage, height)? else {
+            return Ok(staked);
+        };
+        let Some(stake) = self.stakes.may_load_at_height(storage, addr, height)? else {
+            return Ok(staked);
+        };
+        let tracked = stake.amount.min(staked);
+        let age = height.saturating_sub(stake.start);
+        Ok(tracked * config.multiplier(age) + (staked - tracked))
+    }
+
+    /// Returns the total conviction weighted voting power at
+    /// `height`, given `total_staked` was staked at that height.
+    pub fn total_power_at_height(
+        &self,
+        storage: &dyn Storage,
+        total_staked: Uint128,
+        height: u64,
+    ) -> StdResult<Uint128> {
+        let Some(config) = self.config.may_load_at_height(storage, height)? else {
+            return Ok(total_staked);
+        };
+        let Some(totals) = self.totals.may_load_at_height(storage, height)? else {
+            return Ok(total_staked);
+        };
+        let totals = self.totals_at(storage, Some(totals), height)?;
+        let untracked = total_staked.saturating_sub(totals.mature + totals.immature);
+
+        // Immature stake's multiplier grows linearly with its age, so
+        // the growth of all of it follows from the sum of its ages.
+        let ages = Uint256::from(totals.immature) * Uint256::from(height) - totals.weighted_starts;
+        let growth = ages * Uint256::from((config.max_multiplier - Decimal::one()).atomics())
+            / (Uint256::from(config.maturity_blocks)
+                * Uint256::from(10u128.pow(Decimal::DECIMAL_PLACES)));
+
+        Ok(totals.mature * config.max_multiplier
+            + totals.immature
+            + Uint128::try_from(growth)?
+            + untracked)
+    }
+
+    /// Moves stake which has matured by `height` from the immature
+    /// to the mature totals.
+    fn totals_at(
+        &self,
+        storage: &dyn Storage,
+        totals: Option<ConvictionTotals>,
+        height: u64,
+    ) -> StdResult<ConvictionTotals> {
+        let mut totals = totals.unwrap_or_default();
+        if height > totals.height {
+            for maturing in self.maturing.range(
+                storage,
+                Some(Bound::exclusive(totals.height)),
+                Some(Bound::inclusive(height)),
+                Order::Ascending,
+            ) {
+                let (_, maturing) = maturing?;
+                totals.immature = totals.immature.checked_sub(maturing.amount)?;
+                totals.weighted_starts = totals
+                    .weighted_starts
+                    .checked_sub(maturing.weighted_starts)?;
+                totals.mature = totals.mature.checked_add(maturing.amount)?;
+            }
+            totals.height = height;
+        }
+        Ok(totals)
+    }
+
+    fn track(
+        &self,
+        storage: &mut dyn Storage,
+        config: &ConvictionConfig,
+        totals: &mut ConvictionTotals,
+        stake: &StakeAge,
+        height: u64,
+    ) -> StdResult<()> {
+        let matures = stake.start + config.maturity_blocks;
+        if matures <= height {
+            totals.mature = totals.mature.checked_add(stake.amount)?;
+            return Ok(());
+        }
+        let weighted = Uint256::from(stake.amount) * Uint256::from(stake.start);
+        totals.immature = totals.immature.checked_add(stake.amount)?;
+        totals.weighted_starts = totals.weighted_starts.checked_add(weighted)?;
+        let mut maturing = self
+            .maturing
+            .may_load(storage, matures)?
+            .unwrap_or_default();
+        maturing.amount = maturing.amount.checked_add(stake.amount)?;
+        maturing.weighted_starts = maturing.weighted_starts.checked_add(weighted)?;
+        self.maturing.save(storage, matures, &maturing)
+    }
+
+    fn untrack(
+        &self,
+        storage: &mut dyn Storage,
+        config: &ConvictionConfig,
+        totals: &mut ConvictionTotals,
+        stake: &StakeAge,
+        height: u64,
+    ) -> StdResult<()> {
+        let matures = stake.start + config.maturity_blocks;
+        if matures <= height {
+            totals.mature = totals.mature.checked_sub(stake.amount)?;
+            return Ok(());
+        }
+        let weighted = Uint256::from(stake.amount) * Uint256::from(stake.start);
+        totals.immature = totals.immature.checked_sub(stake.amount)?;
+        totals.weighted_starts = totals.weighted_starts.checked_sub(weighted)?;
+        let mut maturing = self.maturing.load(storage, matures)?;
+        maturing.amount = maturing.amount.checked_sub(stake.amount)?;
+        maturing.weighted_starts = maturing.weighted_starts.checked_sub(weighted)?;
+        if maturing.amount.is_zero() {
+            self.maturing.remove(storage, matures);
+            Ok(())
+        } else {
+            self.maturing.save(storage, matures, &maturing)
+        }
+    }
+}
+
+impl Default for Conviction<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockStorage;
+
+    use super::*;
+
+    const CONVICTION: Conviction = Conviction::new();
+
+    fn setup(storage: &mut MockStorage) {
+        CONVICTION
+            .update_config(
+                storage,
+                ConvictionConfig {
+                    maturity_blocks: 100,
+                    max_multiplier: Decimal::percent(300),
+                },
+                1,
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_validate() {
+        let config = ConvictionConfig {
+            maturity_blocks: 0,
+            max_multiplier: Decimal::one(),
+        };
+        assert_eq!(config.validate(), Err(ConvictionError::ZeroMaturity {}));
+        let config = ConvictionConfig {
+            maturity_blocks: 1,
+            max_multiplier: Decimal::percent(99),
+        };
+        assert_eq!(
+            config.validate(),
+            Err(ConvictionError::MultiplierBelowOne {})
+        );
+
+        let mut storage = MockStorage::new();
+        setup(&mut storage);
+        let err = CONVICTION
+            .update_config(
+                &mut storage,
+                ConvictionConfig {
+                    maturity_blocks: 50,
+                    max_multiplier: Decimal::percent(300),
+                },
+                2,
+            )
+            .unwrap_err();
+        assert_eq!(err, ConvictionError::MaturityImmutable {});
+    }
+
+    #[test]
+    fn test_multiplier() {
+        let config = ConvictionConfig {
+            maturity_blocks: 100,
+            max_multiplier: Decimal::percent(300),
+        };
+        assert_eq!(config.multiplier(0), Decimal::one());
+        assert_eq!(config.multiplier(50), Decimal::percent(200));
+        assert_eq!(config.multiplier(100), Decimal::percent(300));
+        assert_eq!(config.multiplier(1000), Decimal::percent(300));
+    }
+
+    #[test]
+    fn test_power_grows_and_resets() {
+        let mut storage = MockStorage::new();
+        let alice = Addr::unchecked("alice");
+        let bob = Addr::unchecked("bob");
+
+        // Stake is not weighted before conviction is enabled.
+        CONVICTION
+            .stake(&mut storage, &alice, Uint128::new(100), 1)
+            .unwrap();
+        assert_eq!(CONVICTION.stake_age(&storage, &alice).unwrap(), None);
+        setup(&mut storage);
+
+        CONVICTION
+            .stake(&mut storage, &alice, Uint128::new(100), 10)
+            .unwrap();
+        CONVICTION
+            .stake(&mut storage, &bob, Uint128::new(100), 60)
+            .unwrap();
+
+        let power = |storage: &MockStorage, addr: &Addr, height: u64| {
+            CONVICTION
+                .power_at_height(storage, addr, Uint128::new(100), height)
+                .unwrap()
+        };
+        let total = |storage: &MockStorage, height: u64| {
+            CONVICTION
+                .total_power_at_height(storage, Uint128::new(200), height)
+                .unwrap()
+        };
+
+        assert_eq!(power(&storage, &alice, 10), Uint128::new(100));
+        assert_eq!(power(&storage, &alice, 60), Uint128::new(200));
+        assert_eq!(power(&storage, &bob, 60), Uint128::new(100));
+        assert_eq!(total(&storage, 60), Uint128::new(300));
+        assert_eq!(power(&storage, &alice, 110), Uint128::new(300));
+        assert_eq!(power(&storage, &bob, 110), Uint128::new(200));
+        assert_eq!(total(&storage, 110), Uint128::new(500));
+        assert_eq!(total(&storage, 500), Uint128::new(600));
+
+        // Unstaking resets the age of the remaining stake. Queries at
+        // earlier heights are unaffected.
+        CONVICTION
+            .unstake(&mut storage, &alice, Uint128::new(50), 120)
+            .unwrap();
+        assert_eq!(power(&storage, &alice, 110), Uint128::new(300));
+        assert_eq!(total(&storage, 110), Uint128::new(500));
+        assert_eq!(
+            CONVICTION
+                .power_at_height(&storage, &alice, Uint128::new(50), 170)
+                .unwrap(),
+            Uint128::new(100)
+        );
+        assert_eq!(
+            CONVICTION
+                .total_power_at_height(&storage, Uint128::new(150), 170)
+                .unwrap(),
+            Uint128::new(400)
+        );
+
+        CONVICTION
+            .unstake(&mut storage, &bob, Uint128::zero(), 180)
+            .unwrap();
+        assert_eq!(CONVICTION.stake_age(&storage, &bob).unwrap(), None);
+        assert_eq!(
+            CONVICTION
+                .total_power_at_height(&storage, Uint128::new(50), 220)
+                .unwrap(),
+            Uint128::new(150)
+        );
+    }
+
+    #[test]
+    fn test_adding_stake_averages_start() {
+        let mut storage = MockStorage::new();
+        let alice = Addr::unchecked("alice");
+        setup(&mut storage);
+
+        CONVICTION
+            .stake(&mut storage, &alice, Uint128::new(100), 10)
+            .unwrap();
+        CONVICTION
+            .stake(&mut storage, &alice, Uint128::new(100), 50)
+            .unwrap();
+        assert_eq!(
+            CONVICTION.stake_age(&storage, &alice).unwrap(),
+            Some(StakeAge {
+                amount: Uint128::new(200),
+                start: 30,
+            })
+        );
+        assert_eq!(
+            CONVICTION
+                .power_at_height(&storage, &alice, Uint128::new(200), 80)
+                .unwrap(),
+            Uint128::new(400)
+        );
+        assert_eq!(
+            CONVICTION
+                .total_power_at_height(&storage, Uint128::new(200), 80)
+                .unwrap(),
+            Uint128::new(400)
+        );
+
+        // Adding stake to mature stake.
+        CONVICTION
+            .stake(&mut storage, &alice, Uint128::new(200), 230)
+            .unwrap();
+        assert_eq!(
+            CONVICTION.stake_age(&storage, &alice).unwrap(),
+            Some(StakeAge {
+                amount: Uint128::new(400),
+                start: 130,
+            })
+        );
+        assert_eq!(
+            CONVICTION
+                .power_at_height(&storage, &alice, Uint128::new(400), 231)
+                .unwrap(),
+            Uint128::new(1200)
+        );
+        assert_eq!(
+            CONVICTION
+                .total_power_at_height(&storage, Uint128::new(400), 231)
+                .unwrap(),
+            Uint128::new(1200)
+        );
+    }
+}
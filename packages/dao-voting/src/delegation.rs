@@ -0,0 +1,125 @@
+use cosmwasm_schema::serde::{de::DeserializeOwned, Serialize};
+use cosmwasm_std::{Addr, Deps, DepsMut, StdError, StdResult, Uint128};
+use cw_storage_plus::Map;
+use dao_interface::delegation::{DelegatorsResponse, Query};
+
+use crate::voting::{get_voting_power, VoteWeighting};
+
+/// Returns the delegators of `delegate` at `height` along with their
+/// voting power at `height`, as registered with the delegation
+/// registry at `registry`. Delegators for whom `has_voted` returns
+/// true are skipped, as their own votes override their delegations.
+/// Delegators without voting power are also skipped.
+pub fn get_delegated_voting_power(
+    deps: Deps,
+    registry: &Addr,
+    dao: &Addr,
+    delegate: &Addr,
+    height: u64,
+    has_voted: impl Fn(&Addr) -> bool,
+) -> StdResult<Vec<(Addr, Uint128)>> {
+    let DelegatorsResponse { delegators } = deps.querier.query_wasm_smart(
+        registry,
+        &Query::Delegators {
+            delegate: delegate.to_string(),
+            height: Some(height),
+        },
+    )?;
+    delegators
+        .into_iter()
+        .filter(|delegator| !has_voted(delegator))
+        .map(|delegator| {
+            let power = get_voting_power(deps, delegator.clone(), dao, Some(height))?;
+            Ok((delegator, power))
+        })
+        .filter(|result| !matches!(result, Ok((_, power)) if power.is_zero()))
+        .collect()
+}
+
+/// The weighted voting power cast by a vote, including the voting
+/// power of the delegators the voter votes for.
+#[derive(Debug, PartialEq)]
+pub struct DelegatedVote {
+    /// The weight of the voter's own voting power.
+    pub own_weight: Uint128,
+    /// The weight of the voter's own voting power and of the voting
+    /// power of their delegators.
+    pub vote_power: Uint128,
+    /// The amount the proposal's total power is reduced by, as these
+    /// voters' weighted votes are counted for the first time.
+    pub total_power_reduction: Uint128,
+    /// The delegate whose ballot carried the voter's voting power
+    /// until now. The voter's vote overrides it, so `own_weight` must
+    /// be removed from that ballot and from the proposal's votes.
+    pub overridden_delegate: Option<Addr>,
+}
+
+/// Tallies the voting power cast by `voter` on proposal `proposal_id`.
+/// Delegators who have not voted themselves have their voting power
+/// cast by their delegate. `ballots` are the proposal module's
+/// ballots, and `delegated_ballots` maps each delegator whose voting
+/// power was cast by a delegate to that delegate. The latter is
+/// updated to record that the voter now casts their delegators'
+/// voting power, and that their own power is no longer cast by their
+/// delegate.
+#[allow(clippy::too_many_arguments)]
+pub fn tally_delegated_vote<B>(
+    deps: DepsMut,
+    ballots: &Map<(u64, &Addr), B>,
+    delegated_ballots: &Map<(u64, &Addr), Addr>,
+    registry: Option<&Addr>,
+    dao: &Addr,
+    voter: &Addr,
+    proposal_id: u64,
+    start_height: u64,
+    weighting: VoteWeighting,
+) -> StdResult<DelegatedVote>
+where
+    B: Serialize + DeserializeOwned,
+{
+    let own_power = get_voting_power(deps.as_ref(), voter.clone(), dao, Some(start_height))?;
+    let delegated = match registry {
+        Some(registry) => get_delegated_voting_power(
+            deps.as_ref(),
+            registry,
+            dao,
+            voter,
+            start_height,
+            |delegator| ballots.has(deps.storage, (proposal_id, delegator)),
+        )?,
+        None => vec![],
+    };
+    let own_weight = weighting.weight(own_power);
+    let vote_power = delegated.iter().try_fold(own_weight, |total, (_, power)| {
+        total.checked_add(weighting.weight(*power))
+    })?;
+
+    // The proposal's total power counts each holder's full voting
+    // power until their weighted vote is first counted.
+    let mut total_power_reduction = Uint128::zero();
+    if !ballots.has(deps.storage, (proposal_id, voter))
+        && !delegated_ballots.has(deps.storage, (proposal_id, voter))
+    {
+        total_power_reduction = weighting.total_power_reduction(own_power);
+    }
+    for (delegator, power) in &delegated {
+        if !delegated_ballots.has(deps.storage, (proposal_id, delegator)) {
+            total_power_reduction = total_power_reduction
+                .checked_add(weighting.total_power_reduction(*power))
+                .map_err(StdError::overflow)?;
+        }
+    }
+
+    let overridden_delegate = delegated_ballots.may_load(deps.storage, (proposal_id, voter))?;
+    delegated_ballots.remove(deps.storage, (proposal_id, voter));
+    for (delegator, _) in &delegated {
+        delegated_ballots.save(deps.storage, (proposal_id, delegator), voter)?;
+    }
+
+    Ok(DelegatedVote {
+        own_weight,
+        vote_power,
+        total_power_reduction,
+        overridden_delegate,
+    })
+}
@@ -1,5 +1,7 @@
 #![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
 
+pub mod conviction;
+pub mod delegation;
 pub mod deposit;
 pub mod duration;
 pub mod error;
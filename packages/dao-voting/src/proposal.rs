@@ -31,4 +31,9 @@ pub struct SingleChoiceProposeMsg {
     /// pre-propose module is attached, this must be Some and will
     /// set the proposer of the proposal it creates.
     pub proposer: Option<String>,
+    /// If true, the proposal may not be amended after its creation.
+    /// Set by pre-propose modules whose proposals are approved before
+    /// they are created, as an amendment would bypass the approval.
+    #[serde(default)]
+    pub immutable: bool,
 }
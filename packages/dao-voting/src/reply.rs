@@ -9,6 +9,7 @@ const FAILED_VOTE_HOOK_MASK: u64 = 0b010;
 /// handler is handling.)
 const PRE_PROPOSE_MODULE_INSTANTIATION_ID: u64 = 0b011;
 const FAILED_PRE_PROPOSE_MODULE_HOOK_ID: u64 = 0b100;
+const FAILED_AMENDMENT_HOOK_MASK: u64 = 0b101;
 
 const BITS_RESERVED_FOR_REPLY_TYPE: u8 = 3;
 const REPLY_TYPE_MASK: u64 = (1 << BITS_RESERVED_FOR_REPLY_TYPE) - 1;
@@ -32,6 +33,8 @@ pub enum TaggedReplyId {
     FailedPreProposeModuleHook,
     /// Fired when a pre-propose module is successfully instantiated.
     PreProposeModuleInstantiation,
+    /// Fired when a proposal amendment hook's execution fails.
+    FailedAmendmentHook(u64),
 }
 
 impl TaggedReplyId {
@@ -50,6 +53,7 @@ impl TaggedReplyId {
             FAILED_VOTE_HOOK_MASK => Ok(TaggedReplyId::FailedVoteHook(id_after_shift)),
             PRE_PROPOSE_MODULE_INSTANTIATION_ID => Ok(TaggedReplyId::PreProposeModuleInstantiation),
             FAILED_PRE_PROPOSE_MODULE_HOOK_ID => Ok(TaggedReplyId::FailedPreProposeModuleHook),
+            FAILED_AMENDMENT_HOOK_MASK => Ok(TaggedReplyId::FailedAmendmentHook(id_after_shift)),
             _ => Err(error::TagError::UnknownReplyId { id }),
         }
     }
@@ -68,6 +72,10 @@ pub const fn mask_vote_hook_index(index: u64) -> u64 {
     FAILED_VOTE_HOOK_MASK | (index << BITS_RESERVED_FOR_REPLY_TYPE)
 }
 
+pub const fn mask_amendment_hook_index(index: u64) -> u64 {
+    FAILED_AMENDMENT_HOOK_MASK | (index << BITS_RESERVED_FOR_REPLY_TYPE)
+}
+
 pub const fn pre_propose_module_instantiation_id() -> u64 {
     PRE_PROPOSE_MODULE_INSTANTIATION_ID
 }
@@ -96,10 +104,12 @@ mod test {
         let proposal_id_max: u64 = 2_u64.pow(61) - 1;
         let proposal_hook_idx = 1234;
         let vote_hook_idx = 4321;
+        let amendment_hook_idx = 2143;
 
         let m_proposal_id = mask_proposal_execution_proposal_id(proposal_id_max);
         let m_proposal_hook_idx = mask_proposal_hook_index(proposal_hook_idx);
         let m_vote_hook_idx = mask_vote_hook_index(vote_hook_idx);
+        let m_amendment_hook_idx = mask_amendment_hook_index(amendment_hook_idx);
 
         assert_eq!(
             TaggedReplyId::new(m_proposal_id).unwrap(),
@@ -113,6 +123,10 @@ mod test {
             TaggedReplyId::new(m_vote_hook_idx).unwrap(),
             TaggedReplyId::FailedVoteHook(vote_hook_idx)
         );
+        assert_eq!(
+            TaggedReplyId::new(m_amendment_hook_idx).unwrap(),
+            TaggedReplyId::FailedAmendmentHook(amendment_hook_idx)
+        );
         assert_eq!(
             TaggedReplyId::new(0b110).unwrap_err(),
             error::TagError::UnknownReplyId { id: 0b110 }
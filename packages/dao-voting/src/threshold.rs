@@ -121,7 +121,7 @@ pub enum Threshold {
 }
 
 /// Asserts that the 0.0 < percent <= 1.0
-fn validate_percentage(percent: &PercentageThreshold) -> Result<(), ThresholdError> {
+pub fn validate_percentage(percent: &PercentageThreshold) -> Result<(), ThresholdError> {
     if let PercentageThreshold::Percent(percent) = percent {
         if percent.is_zero() {
             Err(ThresholdError::ZeroThreshold {})
@@ -189,6 +189,66 @@ impl std::fmt::Display for Vote {
     }
 }
 
+/// How a voter's voting power is weighted when they vote.
+#[cw_serde]
+#[derive(Copy)]
+pub enum VoteWeighting {
+    /// Votes are weighted by voting power.
+    Linear {},
+    /// Votes are weighted by the integer square root of voting
+    /// power, reducing the influence of large holders.
+    Quadratic {},
+}
+
+impl Default for VoteWeighting {
+    fn default() -> Self {
+        Self::Linear {}
+    }
+}
+
+impl VoteWeighting {
+    /// Returns the weight of a vote cast with `power` voting power.
+    pub fn weight(&self, power: Uint128) -> Uint128 {
+        match self {
+            Self::Linear {} => power,
+            Self::Quadratic {} => Uint128::new(isqrt(power.u128())),
+        }
+    }
+
+    /// Returns the amount a proposal's total power should be reduced
+    /// by when a voter with `power` voting power is counted. A
+    /// proposal's total power counts the voting power of those yet to
+    /// vote, which bounds their weight, and the weight of those who
+    /// have voted. This keeps votes from exceeding the total power.
+    pub fn total_power_reduction(&self, power: Uint128) -> Uint128 {
+        power - self.weight(power)
+    }
+}
+
+impl std::fmt::Display for VoteWeighting {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VoteWeighting::Linear {} => write!(f, "linear"),
+            VoteWeighting::Quadratic {} => write!(f, "quadratic"),
+        }
+    }
+}
+
+/// Integer square root, rounded down.
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's method, starting from an overestimate.
+    let mut x = n;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
 /// A height of None will query for the current block height.
 pub fn get_voting_power(
     deps: Deps,
@@ -242,6 +302,30 @@ pub fn validate_voting_period(
 mod test {
     use super::*;
 
+    #[test]
+    fn vote_weighting() {
+        let quadratic = VoteWeighting::Quadratic {};
+        assert_eq!(quadratic.weight(Uint128::zero()), Uint128::zero());
+        assert_eq!(quadratic.weight(Uint128::new(1)), Uint128::new(1));
+        assert_eq!(quadratic.weight(Uint128::new(99)), Uint128::new(9));
+        assert_eq!(quadratic.weight(Uint128::new(100)), Uint128::new(10));
+        assert_eq!(
+            quadratic.weight(Uint128::MAX),
+            Uint128::new(u64::MAX as u128)
+        );
+        assert_eq!(
+            quadratic.total_power_reduction(Uint128::new(100)),
+            Uint128::new(90)
+        );
+
+        let linear = VoteWeighting::default();
+        assert_eq!(linear.weight(Uint128::new(100)), Uint128::new(100));
+        assert_eq!(
+            linear.total_power_reduction(Uint128::new(100)),
+            Uint128::zero()
+        );
+    }
+
     #[test]
     fn count_votes() {
         let mut votes = Votes::with_yes(Uint128::new(5));